@@ -0,0 +1,369 @@
+//! Gas and fee accounting of the transactions sent during a daemon session.
+//!
+//! Contract actions (upload, instantiate, execute, migrate) are accounted per code id and contract address,
+//! which are resolved to the contract id registered in the state when the report is created.
+//! Transactions that don't act on a single contract (bank sends, staking, governance, raw messages)
+//! are accounted without contract id.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use cosmwasm_std::Addr;
+use cw_orch_core::environment::StateInterface;
+use serde::Serialize;
+
+use crate::{CosmTxResponse, DaemonError};
+
+/// Contract id of the report entries that don't act on a single contract
+const NO_CONTRACT_ID: &str = "-";
+
+/// Kind of transaction
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TxAction {
+    Upload,
+    Instantiate,
+    Execute,
+    Migrate,
+    /// Bank send
+    Send,
+    /// Delegation, undelegation and redelegation
+    Staking,
+    /// Rewards withdrawal and withdraw address update
+    Distribution,
+    /// Governance vote
+    Gov,
+    /// Raw messages committed with `Stargate::commit_any`
+    Other,
+}
+
+impl Display for TxAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let action = match self {
+            TxAction::Upload => "upload",
+            TxAction::Instantiate => "instantiate",
+            TxAction::Execute => "execute",
+            TxAction::Migrate => "migrate",
+            TxAction::Send => "send",
+            TxAction::Staking => "staking",
+            TxAction::Distribution => "distribution",
+            TxAction::Gov => "gov",
+            TxAction::Other => "other",
+        };
+        write!(f, "{action}")
+    }
+}
+
+/// Gas and fees of a set of transactions
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct TxStats {
+    pub tx_count: u64,
+    pub gas_wanted: u64,
+    pub gas_used: u64,
+    /// Fees paid per denom
+    pub fees: BTreeMap<String, u128>,
+}
+
+impl TxStats {
+    fn add_tx(&mut self, response: &CosmTxResponse) {
+        self.tx_count += 1;
+        self.gas_wanted += response.gas_wanted;
+        self.gas_used += response.gas_used;
+        for fee in response.fee() {
+            *self.fees.entry(fee.denom).or_default() += fee.amount.u128();
+        }
+    }
+
+    fn merge(&mut self, other: &TxStats) {
+        self.tx_count += other.tx_count;
+        self.gas_wanted += other.gas_wanted;
+        self.gas_used += other.gas_used;
+        for (denom, amount) in &other.fees {
+            *self.fees.entry(denom.clone()).or_default() += amount;
+        }
+    }
+
+    /// Fees formatted as `{amount}{denom}` separated by `sep`
+    fn fees_string(&self, sep: &str) -> String {
+        self.fees
+            .iter()
+            .map(|(denom, amount)| format!("{amount}{denom}"))
+            .collect::<Vec<_>>()
+            .join(sep)
+    }
+}
+
+/// Stats of an action on a contract
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ReportEntry {
+    pub contract_id: String,
+    pub action: TxAction,
+    #[serde(flatten)]
+    pub stats: TxStats,
+}
+
+/// Gas and fee report of a daemon session, sorted by contract id and action
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SessionReport {
+    pub entries: Vec<ReportEntry>,
+    pub total: TxStats,
+}
+
+impl SessionReport {
+    /// CSV with one line per contract action, fees are separated by `;`
+    pub fn to_csv(&self) -> String {
+        let mut csv = "contract_id,action,tx_count,gas_wanted,gas_used,fees\n".to_string();
+        for ReportEntry {
+            contract_id,
+            action,
+            stats,
+        } in &self.entries
+        {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{}\n",
+                contract_id,
+                action,
+                stats.tx_count,
+                stats.gas_wanted,
+                stats.gas_used,
+                stats.fees_string(";")
+            ));
+        }
+        csv
+    }
+
+    pub fn to_json(&self) -> Result<String, DaemonError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn write_csv(&self, path: impl AsRef<Path>) -> Result<(), DaemonError> {
+        Ok(std::fs::write(path, self.to_csv())?)
+    }
+
+    pub fn write_json(&self, path: impl AsRef<Path>) -> Result<(), DaemonError> {
+        Ok(std::fs::write(path, self.to_json()?)?)
+    }
+}
+
+impl Display for SessionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<30} {:<12} {:>5} {:>14} {:>14}  fees",
+            "contract", "action", "txs", "gas wanted", "gas used"
+        )?;
+        let rows = self
+            .entries
+            .iter()
+            .map(|entry| {
+                (
+                    entry.contract_id.as_str(),
+                    entry.action.to_string(),
+                    &entry.stats,
+                )
+            })
+            .chain(std::iter::once(("total", String::new(), &self.total)));
+        for (contract_id, action, stats) in rows {
+            writeln!(
+                f,
+                "{:<30} {:<12} {:>5} {:>14} {:>14}  {}",
+                contract_id,
+                action,
+                stats.tx_count,
+                stats.gas_wanted,
+                stats.gas_used,
+                stats.fees_string(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// What an accounted transaction acts on
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum TxTarget {
+    /// Code uploaded by the transaction
+    Code(u64),
+    /// Contract instantiated, executed or migrated by the transaction
+    Contract(Addr),
+    /// The transaction doesn't act on a single contract
+    None,
+}
+
+impl TxTarget {
+    /// Contract id registered for the target, the code id or address if there is none
+    fn contract_id(
+        &self,
+        code_ids: &BTreeMap<u64, String>,
+        addresses: &BTreeMap<Addr, String>,
+    ) -> String {
+        match self {
+            TxTarget::Code(code_id) => code_ids
+                .get(code_id)
+                .cloned()
+                .unwrap_or_else(|| format!("code {code_id}")),
+            TxTarget::Contract(address) => addresses
+                .get(address)
+                .cloned()
+                .unwrap_or_else(|| address.to_string()),
+            TxTarget::None => NO_CONTRACT_ID.to_string(),
+        }
+    }
+}
+
+/// Accumulates the gas and fees of the transactions of a daemon, shared between clones of the daemon
+#[derive(Clone, Default)]
+pub struct SessionAccounting {
+    stats: Arc<Mutex<BTreeMap<(TxTarget, TxAction), TxStats>>>,
+}
+
+impl SessionAccounting {
+    pub(crate) fn record(&self, target: TxTarget, action: TxAction, response: &CosmTxResponse) {
+        self.stats
+            .lock()
+            .unwrap()
+            .entry((target, action))
+            .or_default()
+            .add_tx(response);
+    }
+
+    /// Report of the transactions accounted so far.
+    /// Code ids and contract addresses are reported under the contract id registered for them in `state`.
+    pub fn report(&self, state: &impl StateInterface) -> SessionReport {
+        // When several contract ids share a code id, the first one in alphabetical order is used
+        let mut code_ids = BTreeMap::new();
+        for (contract_id, code_id) in state
+            .get_all_code_ids()
+            .unwrap_or_default()
+            .into_iter()
+            .collect::<BTreeMap<_, _>>()
+        {
+            code_ids.entry(code_id).or_insert(contract_id);
+        }
+        let addresses = state
+            .get_all_addresses()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(contract_id, address)| (address, contract_id))
+            .collect();
+
+        let mut merged: BTreeMap<(String, TxAction), TxStats> = BTreeMap::new();
+        for ((target, action), stats) in self.stats.lock().unwrap().iter() {
+            merged
+                .entry((target.contract_id(&code_ids, &addresses), *action))
+                .or_default()
+                .merge(stats);
+        }
+
+        let mut total = TxStats::default();
+        let entries = merged
+            .into_iter()
+            .map(|((contract_id, action), stats)| {
+                total.merge(&stats);
+                ReportEntry {
+                    contract_id,
+                    action,
+                    stats,
+                }
+            })
+            .collect();
+        SessionReport { entries, total }
+    }
+
+    /// Clears the accounted transactions
+    pub fn reset(&self) {
+        self.stats.lock().unwrap().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cw_orch::mock::MockState;
+
+    use crate::cosmos_modules::tendermint_abci::{Event, EventAttribute};
+
+    use super::*;
+
+    fn tx(gas_wanted: u64, gas_used: u64, fee: &str) -> CosmTxResponse {
+        CosmTxResponse {
+            gas_wanted,
+            gas_used,
+            events: vec![Event {
+                r#type: "tx".to_string(),
+                attributes: vec![EventAttribute {
+                    key: "fee".into(),
+                    value: fee.to_string().into(),
+                    index: true,
+                }],
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn report() -> anyhow::Result<()> {
+        let counter = Addr::unchecked("juno1counter");
+        let mut state = MockState::new();
+        state.set_code_id("counter", 1);
+        state.set_address("counter", &counter);
+
+        let accounting = SessionAccounting::default();
+        accounting.record(
+            TxTarget::Contract(counter.clone()),
+            TxAction::Execute,
+            &tx(200, 150, "10ujuno"),
+        );
+        accounting.clone().record(
+            TxTarget::Contract(counter),
+            TxAction::Execute,
+            &tx(300, 250, "15ujuno,2uatom"),
+        );
+        accounting.record(
+            TxTarget::Code(1),
+            TxAction::Upload,
+            &tx(1000, 900, "100ujuno"),
+        );
+        accounting.record(TxTarget::None, TxAction::Send, &tx(100, 80, "1ujuno"));
+
+        let report = accounting.report(&state);
+        assert_eq!(report.entries.len(), 3);
+        assert_eq!(report.entries[0].contract_id, "-");
+        assert_eq!(report.entries[1].contract_id, "counter");
+        assert_eq!(report.entries[1].action, TxAction::Upload);
+        assert_eq!(
+            report.entries[2].stats,
+            TxStats {
+                tx_count: 2,
+                gas_wanted: 500,
+                gas_used: 400,
+                fees: BTreeMap::from([("uatom".to_string(), 2), ("ujuno".to_string(), 25)]),
+            }
+        );
+        assert_eq!(report.total.tx_count, 4);
+        assert_eq!(report.total.fees["ujuno"], 126);
+
+        assert_eq!(
+            report.to_csv(),
+            "contract_id,action,tx_count,gas_wanted,gas_used,fees\n\
+             -,send,1,100,80,1ujuno\n\
+             counter,upload,1,1000,900,100ujuno\n\
+             counter,execute,2,500,400,2uatom;25ujuno\n"
+        );
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()?)?;
+        assert_eq!(json["entries"][2]["gas_used"], 400);
+        assert_eq!(json["total"]["fees"]["ujuno"], 126);
+
+        // Unknown code ids and addresses are reported as is
+        let report = accounting.report(&MockState::new());
+        assert_eq!(report.entries[1].contract_id, "code 1");
+        assert_eq!(report.entries[2].contract_id, "juno1counter");
+
+        accounting.reset();
+        assert_eq!(accounting.report(&state), SessionReport::default());
+        Ok(())
+    }
+}
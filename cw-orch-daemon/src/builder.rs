@@ -4,6 +4,7 @@ use crate::{
     log::print_if_log_disabled,
     network_config,
     senders::{builder::SenderBuilder, CosmosOptions, CosmosWalletKey},
+    tx_broadcaster::RetryPolicy,
     DaemonAsyncBase, DaemonBuilder, DaemonStateFile, TxSender, Wallet,
 };
 
@@ -35,8 +36,11 @@ pub struct DaemonAsyncBuilder {
     pub(crate) write_on_change: Option<bool>,
     pub(crate) is_test: bool,
     pub(crate) load_network: bool,
+    pub(crate) network_overrides: Option<ChainInfoOwned>,
+    pub(crate) registry_name: Option<String>,
 
     pub(crate) mnemonic: Option<String>,
+    pub(crate) retry_policy: Option<RetryPolicy>,
 }
 
 impl DaemonAsyncBuilder {
@@ -48,8 +52,11 @@ impl DaemonAsyncBuilder {
             state: None,
             write_on_change: None,
             mnemonic: None,
+            retry_policy: None,
             is_test: false,
             load_network: true,
+            network_overrides: None,
+            registry_name: None,
         }
     }
 
@@ -82,12 +89,25 @@ impl DaemonAsyncBuilder {
         self
     }
 
+    /// Set the strategies used to retry failed transaction broadcasts of the default Cosmos wallet
+    pub fn retry_policy(&mut self, retry_policy: RetryPolicy) -> &mut Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
     /// Overwrite the chain info
     pub fn chain(&mut self, chain: impl Into<ChainInfoOwned>) -> &mut Self {
         self.chain = chain.into();
         self
     }
 
+    /// Load the chain from the chain registry at `CW_ORCH_CHAIN_REGISTRY` by its registry name (ex. `osmosis`).
+    /// The builder chain info overwrites the registry chain info. Requires the `chain-registry` feature
+    pub fn chain_registry_name(&mut self, chain_name: impl Into<String>) -> &mut Self {
+        self.registry_name = Some(chain_name.into());
+        self
+    }
+
     /// Set daemon as testing daemon
     /// when set to `true` will use temporary file for state
     pub fn is_test(&mut self, is_test: bool) -> &mut Self {
@@ -95,13 +115,20 @@ impl DaemonAsyncBuilder {
         self
     }
 
-    /// Load network from `$HOME/.cw-orchestrator/networks.toml`
+    /// Load network from the layered `networks.toml` files, see [`network_config`](crate::network_config)
     /// Defaults to `true`
     pub fn load_network(&mut self, load_network: bool) -> &mut Self {
         self.load_network = load_network;
         self
     }
 
+    /// Overrides applied on top of the network config files.
+    /// Only the fields set in `overrides` are overwritten, see [`ChainInfoOwned::overwrite_with`]
+    pub fn network_overrides(&mut self, overrides: ChainInfoOwned) -> &mut Self {
+        self.network_overrides = Some(overrides);
+        self
+    }
+
     /// Specifies path to the daemon state file
     /// Defaults to env variable.
     ///
@@ -114,15 +141,7 @@ impl DaemonAsyncBuilder {
 
     /// Build a daemon with provided mnemonic or env-var mnemonic
     pub async fn build(&self) -> Result<DaemonAsyncBase<Wallet>, DaemonError> {
-        let mut chain_info = self.chain.clone();
-        if self.load_network {
-            // try to load network
-            if let Some(network_config) = network_config::read_network_config(&chain_info.chain_id)
-            {
-                chain_info = chain_info.overwrite_with(network_config)
-            }
-        }
-        let chain_info = Arc::new(chain_info);
+        let chain_info = Arc::new(self.chain_info()?);
 
         let state = self.build_state(chain_info.clone())?;
         // if mnemonic provided, use it. Else use env variables to retrieve mnemonic
@@ -131,6 +150,7 @@ impl DaemonAsyncBuilder {
             key: self.mnemonic.as_ref().map_or(CosmosWalletKey::Env, |m| {
                 CosmosWalletKey::Mnemonic(m.clone())
             }),
+            retry_policy: self.retry_policy.clone().unwrap_or_default(),
             ..Default::default()
         };
         let sender = options.build(&chain_info).await?;
@@ -151,15 +171,7 @@ impl DaemonAsyncBuilder {
         &self,
         sender_options: T,
     ) -> Result<DaemonAsyncBase<T::Sender>, DaemonError> {
-        let mut chain_info = self.chain.clone();
-        if self.load_network {
-            // try to load network
-            if let Some(network_config) = network_config::read_network_config(&chain_info.chain_id)
-            {
-                chain_info = chain_info.overwrite_with(network_config)
-            }
-        }
-        let chain_info = Arc::new(chain_info);
+        let chain_info = Arc::new(self.chain_info()?);
 
         let state = self.build_state(chain_info.clone())?;
 
@@ -174,6 +186,26 @@ impl DaemonAsyncBuilder {
         Ok(daemon)
     }
 
+    /// Chain info of the daemon.
+    /// With a registry name, the chain is loaded from the chain registry and the builder chain info overwrites it.
+    /// The network config files and the network overrides are then applied.
+    fn chain_info(&self) -> Result<ChainInfoOwned, DaemonError> {
+        let mut chain_info = match &self.registry_name {
+            Some(chain_name) => registry_chain_info(chain_name)?.overwrite_with(self.chain.clone()),
+            None => self.chain.clone(),
+        };
+        if self.load_network {
+            if let Some(network_config) = network_config::read_network_config(&chain_info.chain_id)?
+            {
+                chain_info = chain_info.overwrite_with(network_config)
+            }
+        }
+        if let Some(overrides) = &self.network_overrides {
+            chain_info = chain_info.overwrite_with(overrides.clone())
+        }
+        Ok(chain_info)
+    }
+
     /// Returns a built state
     fn build_state(&self, chain_info: Arc<ChainInfoOwned>) -> Result<DaemonState, DaemonError> {
         let deployment_id = self
@@ -227,6 +259,21 @@ impl DaemonAsyncBuilder {
     }
 }
 
+/// Loads a chain from the chain registry at `CW_ORCH_CHAIN_REGISTRY`
+#[cfg(feature = "chain-registry")]
+fn registry_chain_info(chain_name: &str) -> Result<ChainInfoOwned, DaemonError> {
+    use cw_orch_networks::registry::ChainRegistry;
+
+    Ok(ChainRegistry::env()?.chain_info(chain_name)?)
+}
+
+#[cfg(not(feature = "chain-registry"))]
+fn registry_chain_info(chain_name: &str) -> Result<ChainInfoOwned, DaemonError> {
+    Err(DaemonError::StdErr(format!(
+        "Loading {chain_name} from the chain registry requires the `chain-registry` feature"
+    )))
+}
+
 impl From<DaemonBuilder> for DaemonAsyncBuilder {
     fn from(value: DaemonBuilder) -> Self {
         DaemonAsyncBuilder {
@@ -236,8 +283,43 @@ impl From<DaemonBuilder> for DaemonAsyncBuilder {
             state_path: value.state_path,
             write_on_change: value.write_on_change,
             mnemonic: value.mnemonic,
+            retry_policy: value.retry_policy,
             is_test: value.is_test,
             load_network: value.load_network,
+            network_overrides: value.network_overrides,
+            registry_name: value.registry_name,
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use cw_orch_core::environment::ChainInfoOwned;
+
+    use super::DaemonAsyncBuilder;
+    use crate::{senders::builder::SenderBuilder, CosmosOptions, DaemonError};
+
+    #[tokio::test]
+    async fn missing_gas_price() {
+        let chain = ChainInfoOwned {
+            chain_id: "no-gas-price-1".to_string(),
+            gas_denom: "ustake".to_string(),
+            ..Default::default()
+        };
+        let mut builder = DaemonAsyncBuilder::new(chain);
+        builder.load_network(false);
+        // Only the senders of transactions need a gas price
+        let chain_info = Arc::new(builder.chain_info().unwrap());
+        assert!(matches!(
+            CosmosOptions::default().build(&chain_info).await,
+            Err(DaemonError::GasPriceError(denom)) if denom == "ustake"
+        ));
+
+        let mut overrides = ChainInfoOwned::config(String::new());
+        overrides.gas_price = 0.025;
+        builder.network_overrides(overrides);
+        assert_eq!(builder.chain_info().unwrap().gas_price, 0.025);
+    }
+}
@@ -6,12 +6,27 @@ use http::Uri;
 use tonic::transport::{Channel, ClientTlsConfig, Endpoint};
 
 use super::error::DaemonError;
+#[cfg(feature = "rest")]
+use super::{env::DaemonEnvVars, rest::RestChannel};
 
 /// A helper for constructing a gRPC channel
 pub struct GrpcChannel {}
 
 impl GrpcChannel {
+    /// Endpoint of a gRPC url, TLS is used for `https` urls
+    pub(crate) fn endpoint(address: &str) -> Result<Endpoint, DaemonError> {
+        let uri = Uri::try_from(address)
+            .map_err(|e| DaemonError::StdErr(format!("Invalid gRPC url {address}: {e}")))?;
+        Ok(Endpoint::from(uri).tls_config(
+            ClientTlsConfig::new()
+                .with_enabled_roots()
+                // grpcs are http/2 by spec
+                .assume_http2(true),
+        )?)
+    }
+
     /// Connect to any of the provided gRPC endpoints
+    /// Use [`diagnose_network`](crate::diagnostics::diagnose_network) to find out why endpoints are unreachable
     pub async fn connect(grpc: &[String], chain_id: &str) -> Result<Channel, DaemonError> {
         if grpc.is_empty() {
             return Err(DaemonError::GRPCListIsEmpty);
@@ -22,41 +37,46 @@ impl GrpcChannel {
         for address in grpc.iter() {
             log::debug!(target: &connectivity_target(), "Trying to connect to endpoint: {}", address);
 
-            let uri = Uri::from_maybe_shared(address.clone()).expect("Invalid URI");
-
-            let maybe_channel = Endpoint::from(uri)
-                .tls_config(
-                    ClientTlsConfig::new()
-                        .with_enabled_roots()
-                        // grpcs are http/2 by spec
-                        .assume_http2(true),
-                )
-                .unwrap()
-                .connect()
-                .await;
-
-            if maybe_channel.is_err() {
-                log::warn!(
-                    "Cannot connect to gRPC endpoint: {}, {:?}",
-                    address,
-                    maybe_channel.unwrap_err()
-                );
-                continue;
+            let maybe_channel = match Self::endpoint(address) {
+                Ok(endpoint) => endpoint.connect().await.map_err(Into::into),
+                Err(e) => Err(e),
+            };
+
+            let channel = match maybe_channel {
+                Ok(channel) => channel,
+                Err(e) => {
+                    log::warn!(
+                        "Cannot connect to gRPC endpoint: {}, {}",
+                        address,
+                        error_chain(&e)
+                    );
+                    continue;
+                }
             };
-            let channel = maybe_channel.unwrap();
 
             let mut client = ServiceClient::new(channel.clone());
 
             // Verify that node is the expected network
-            let node_info = client
-                .get_node_info(GetNodeInfoRequest {})
-                .await?
-                .into_inner();
+            let node_info = match client.get_node_info(GetNodeInfoRequest {}).await {
+                Ok(node_info) => node_info.into_inner(),
+                Err(status) => {
+                    log::warn!(
+                        "Cannot query the node info of gRPC endpoint: {}, {}",
+                        address,
+                        status
+                    );
+                    continue;
+                }
+            };
+            let network = node_info
+                .default_node_info
+                .map(|info| info.network)
+                .unwrap_or_default();
 
-            if node_info.default_node_info.as_ref().unwrap().network != chain_id {
+            if network != chain_id {
                 log::error!(
                     "Network mismatch: connection:{} != config:{}",
-                    node_info.default_node_info.as_ref().unwrap().network,
+                    network,
                     chain_id
                 );
                 continue;
@@ -75,11 +95,29 @@ impl GrpcChannel {
     }
 
     /// Create a gRPC channel from the chain info
+    /// With the `rest` feature, the REST endpoint of the chain is used instead when no gRPC endpoint is provided or when [`DaemonEnvVars::rest_transport`](crate::env::DaemonEnvVars::rest_transport) is set
     pub async fn from_chain_info(chain_info: &ChainInfoOwned) -> Result<Channel, DaemonError> {
+        #[cfg(feature = "rest")]
+        if let Some(lcd_url) = &chain_info.lcd_url {
+            if chain_info.grpc_urls.is_empty() || DaemonEnvVars::rest_transport() {
+                return RestChannel::connect(lcd_url, &chain_info.chain_id).await;
+            }
+        }
         GrpcChannel::connect(&chain_info.grpc_urls, &chain_info.chain_id).await
     }
 }
 
+/// Error message with all its sources, transport errors only display their kind
+pub(crate) fn error_chain(error: &dyn std::error::Error) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+    while let Some(error) = source {
+        message.push_str(&format!(": {error}"));
+        source = error.source();
+    }
+    message
+}
+
 #[cfg(test)]
 mod tests {
     /*
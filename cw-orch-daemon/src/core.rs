@@ -2,17 +2,22 @@ use super::{
     cosmos_modules, error::DaemonError, queriers::Node, senders::Wallet, tx_resp::CosmTxResponse,
 };
 use crate::{
-    queriers::CosmWasm,
+    accounting::{SessionAccounting, SessionReport, TxAction, TxTarget},
+    env::DaemonEnvVars,
+    queriers::{request_at_height, CosmWasm, Distribution},
     senders::{builder::SenderBuilder, query::QuerySender, tx::TxSender},
     DaemonAsyncBuilder, DaemonState,
 };
 use cosmrs::{
     cosmwasm::{MsgExecuteContract, MsgInstantiateContract, MsgMigrateContract},
+    distribution::{MsgSetWithdrawAddress, MsgWithdrawDelegatorReward},
     proto::cosmwasm::wasm::v1::MsgInstantiateContract2,
+    staking::{MsgBeginRedelegate, MsgDelegate, MsgUndelegate},
     tendermint::Time,
+    tx::Msg,
     AccountId, Any, Denom,
 };
-use cosmwasm_std::{Addr, Binary, Coin};
+use cosmwasm_std::{Addr, Binary, Coin, VoteOption};
 use cw_orch_core::{
     contract::{interface_traits::Uploadable, WasmPath},
     environment::{
@@ -20,20 +25,22 @@ use cw_orch_core::{
     },
     log::transaction_target,
 };
-use flate2::{write, Compression};
+use flate2::{read, write, Compression};
 use prost::Message;
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::from_str;
 use std::{
     fmt::Debug,
-    io::Write,
+    io::{Read, Write},
     ops::Deref,
+    path::Path,
     str::{from_utf8, FromStr},
     time::Duration,
 };
 use tonic::transport::Channel;
 
 pub const INSTANTIATE_2_TYPE_URL: &str = "/cosmwasm.wasm.v1.MsgInstantiateContract2";
+pub const VOTE_TYPE_URL: &str = "/cosmos.gov.v1beta1.MsgVote";
 
 #[derive(Clone)]
 /**
@@ -64,19 +71,29 @@ pub const INSTANTIATE_2_TYPE_URL: &str = "/cosmwasm.wasm.v1.MsgInstantiateContra
     This daemon is thread safe and can be used between threads.
     However, please make sure that you are not trying to broadcast multiple transactions at once when using this Daemon on different threads.
     If you do so, you will get account sequence errors and your transactions won't get broadcasted.
+    To submit concurrent transactions from the same account, enable the sequence manager with [`CosmosOptions::manage_sequence`](crate::CosmosOptions::manage_sequence).
 */
 pub struct DaemonAsyncBase<Sender = Wallet> {
     /// Sender to send transactions to the chain
     sender: Sender,
     /// State of the daemon
     pub(crate) state: DaemonState,
+    /// Gas and fees of the transactions of this session
+    pub(crate) accounting: SessionAccounting,
+    /// Block height the queries are resolved at, latest if `None`
+    pub(crate) query_height: Option<u64>,
 }
 
 pub type DaemonAsync = DaemonAsyncBase<Wallet>;
 
 impl<Sender> DaemonAsyncBase<Sender> {
     pub(crate) fn new(sender: Sender, state: DaemonState) -> Self {
-        Self { sender, state }
+        Self {
+            sender,
+            state,
+            accounting: SessionAccounting::default(),
+            query_height: None,
+        }
     }
 
     pub fn chain_info(&self) -> &ChainInfoOwned {
@@ -101,6 +118,8 @@ impl<Sender> DaemonAsyncBase<Sender> {
         DaemonAsyncBase {
             sender,
             state: self.state,
+            accounting: self.accounting,
+            query_height: self.query_height,
         }
     }
 
@@ -120,6 +139,32 @@ impl<Sender> DaemonAsyncBase<Sender> {
         self.state.flush()
     }
 
+    /// Returns a copy of this daemon whose queries are resolved at the given block height.
+    /// Transactions are not affected. The node needs to have the state at that height available (i.e. not pruned).
+    pub fn at_height(&self, height: u64) -> Self
+    where
+        Sender: Clone,
+    {
+        let mut daemon = self.clone();
+        daemon.query_height = Some(height);
+        daemon
+    }
+
+    /// Block height the queries of this daemon are resolved at, `None` for the latest height
+    pub fn query_height(&self) -> Option<u64> {
+        self.query_height
+    }
+
+    /// Gas and fee accounting of the transactions sent during this session
+    pub fn accounting(&self) -> &SessionAccounting {
+        &self.accounting
+    }
+
+    /// Gas and fee report of the transactions sent during this session
+    pub fn tx_report(&self) -> SessionReport {
+        self.accounting.report(&self.state)
+    }
+
     /// Returns a new [`DaemonAsyncBuilder`] with the current configuration.
     /// Does not consume the original [`DaemonAsync`].
     pub fn rebuild(&self) -> DaemonAsyncBuilder {
@@ -130,10 +175,13 @@ impl<Sender> DaemonAsyncBase<Sender> {
             state_path: None,
             write_on_change: None,
             mnemonic: None,
+            retry_policy: None,
             // If it was test it will just use same tempfile as state
             is_test: false,
             // Uses same ChainInfo
             load_network: false,
+            network_overrides: None,
+            registry_name: None,
         }
     }
 }
@@ -152,15 +200,32 @@ impl<Sender: QuerySender> DaemonAsyncBase<Sender> {
     ) -> Result<T, DaemonError> {
         let mut client = cosmos_modules::cosmwasm::query_client::QueryClient::new(self.channel());
         let resp = client
-            .smart_contract_state(cosmos_modules::cosmwasm::QuerySmartContractStateRequest {
-                address: contract_address.to_string(),
-                query_data: serde_json::to_vec(&query_msg)?,
-            })
+            .smart_contract_state(request_at_height(
+                cosmos_modules::cosmwasm::QuerySmartContractStateRequest {
+                    address: contract_address.to_string(),
+                    query_data: serde_json::to_vec(&query_msg)?,
+                },
+                self.query_height,
+            ))
             .await?;
 
         Ok(from_str(from_utf8(&resp.into_inner().data).unwrap())?)
     }
 
+    /// Subscribe to the CometBFT events matching `query`, for example `tm.event='Tx' AND wasm._contract_address='juno1...'`
+    /// Uses the websocket url configured on the sender, see [`QuerySender::websocket_url`]
+    #[cfg(feature = "websocket")]
+    pub async fn subscribe_events(
+        &self,
+        query: impl Into<String>,
+    ) -> Result<crate::websocket::EventSubscription, DaemonError> {
+        let websocket_url = self
+            .sender()
+            .websocket_url()
+            .ok_or(DaemonError::MissingWebsocketUrl)?;
+        crate::websocket::EventSubscription::connect(&websocket_url, query).await
+    }
+
     /// Wait for a given amount of blocks.
     pub async fn wait_blocks(&self, amount: u64) -> Result<(), DaemonError> {
         let mut last_height = Node::new_async(self.channel())._block_height().await?;
@@ -200,7 +265,9 @@ impl<Sender: QuerySender> DaemonAsyncBase<Sender> {
 
     /// Get the current block info.
     pub async fn block_info(&self) -> Result<cosmwasm_std::BlockInfo, DaemonError> {
-        let block = Node::new_async(self.channel())._latest_block().await?;
+        let mut node = Node::new_async(self.channel());
+        node.height = self.query_height;
+        let block = node._latest_block().await?;
         let since_epoch = block.header.time.duration_since(Time::unix_epoch())?;
         let time = cosmwasm_std::Timestamp::from_nanos(since_epoch.as_nanos() as u64);
         Ok(cosmwasm_std::BlockInfo {
@@ -245,6 +312,11 @@ impl<Sender: TxSender> DaemonAsyncBase<Sender> {
             .await
             .map_err(Into::into)?;
         log::info!(target: &transaction_target(), "Execution done: {:?}", result.txhash);
+        self.accounting.record(
+            TxTarget::Contract(contract_address.clone()),
+            TxAction::Execute,
+            &result,
+        );
 
         Ok(result)
     }
@@ -274,6 +346,12 @@ impl<Sender: TxSender> DaemonAsyncBase<Sender> {
             .map_err(Into::into)?;
 
         log::info!(target: &transaction_target(), "Instantiation done: {:?}", result.txhash);
+        let target = result
+            .instantiated_contract_address()
+            .map(TxTarget::Contract)
+            .unwrap_or(TxTarget::Code(code_id));
+        self.accounting
+            .record(target, TxAction::Instantiate, &result);
 
         Ok(result)
     }
@@ -312,6 +390,12 @@ impl<Sender: TxSender> DaemonAsyncBase<Sender> {
             .map_err(Into::into)?;
 
         log::info!(target: &transaction_target(), "Instantiation done: {:?}", result.txhash);
+        let target = result
+            .instantiated_contract_address()
+            .map(TxTarget::Contract)
+            .unwrap_or(TxTarget::Code(code_id));
+        self.accounting
+            .record(target, TxAction::Instantiate, &result);
 
         Ok(result)
     }
@@ -334,6 +418,11 @@ impl<Sender: TxSender> DaemonAsyncBase<Sender> {
             .commit_tx(vec![exec_msg], None)
             .await
             .map_err(Into::into)?;
+        self.accounting.record(
+            TxTarget::Contract(contract_address.clone()),
+            TxAction::Migrate,
+            &result,
+        );
         Ok(result)
     }
 
@@ -360,6 +449,8 @@ impl<Sender: TxSender> DaemonAsyncBase<Sender> {
         log::info!(target: &transaction_target(), "Uploading done: {:?}", result.txhash);
 
         let code_id = result.uploaded_code_id().unwrap();
+        self.accounting
+            .record(TxTarget::Code(code_id), TxAction::Upload, &result);
 
         // wait for the node to return the contract information for this upload
         let wasm = CosmWasm::new_async(self.channel());
@@ -370,15 +461,165 @@ impl<Sender: TxSender> DaemonAsyncBase<Sender> {
     }
 }
 
+/// Staking, distribution and gov transactions of the sender
+impl<Sender: TxSender> DaemonAsyncBase<Sender> {
+    /// Delegate `amount` to `validator`
+    pub async fn delegate(
+        &self,
+        validator: &str,
+        amount: &cosmwasm_std::Coin,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let msg = MsgDelegate {
+            delegator_address: self.sender().msg_sender().map_err(Into::into)?,
+            validator_address: AccountId::from_str(validator)?,
+            amount: parse_cw_coin(amount)?,
+        };
+        self.commit_msgs(vec![msg.into_any()?], "Delegation", TxAction::Staking)
+            .await
+    }
+
+    /// Undelegate `amount` from `validator`
+    pub async fn undelegate(
+        &self,
+        validator: &str,
+        amount: &cosmwasm_std::Coin,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let msg = MsgUndelegate {
+            delegator_address: self.sender().msg_sender().map_err(Into::into)?,
+            validator_address: AccountId::from_str(validator)?,
+            amount: parse_cw_coin(amount)?,
+        };
+        self.commit_msgs(vec![msg.into_any()?], "Undelegation", TxAction::Staking)
+            .await
+    }
+
+    /// Move `amount` of the delegation from `src_validator` to `dst_validator`
+    pub async fn redelegate(
+        &self,
+        src_validator: &str,
+        dst_validator: &str,
+        amount: &cosmwasm_std::Coin,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let msg = MsgBeginRedelegate {
+            delegator_address: self.sender().msg_sender().map_err(Into::into)?,
+            validator_src_address: AccountId::from_str(src_validator)?,
+            validator_dst_address: AccountId::from_str(dst_validator)?,
+            amount: parse_cw_coin(amount)?,
+        };
+        self.commit_msgs(vec![msg.into_any()?], "Redelegation", TxAction::Staking)
+            .await
+    }
+
+    /// Withdraw the rewards of the delegation to `validator`
+    pub async fn withdraw_rewards(&self, validator: &str) -> Result<CosmTxResponse, DaemonError> {
+        let msg = MsgWithdrawDelegatorReward {
+            delegator_address: self.sender().msg_sender().map_err(Into::into)?,
+            validator_address: AccountId::from_str(validator)?,
+        };
+        self.commit_msgs(
+            vec![msg.into_any()?],
+            "Rewards withdrawal",
+            TxAction::Distribution,
+        )
+        .await
+    }
+
+    /// Withdraw the rewards of the delegations to all validators, in a single transaction
+    pub async fn withdraw_all_rewards(&self) -> Result<CosmTxResponse, DaemonError> {
+        let delegator_address = self.sender().msg_sender().map_err(Into::into)?;
+        let validators = Distribution::new_async(self.channel())
+            ._delegator_validators(&Addr::unchecked(delegator_address.to_string()))
+            .await?;
+        if validators.is_empty() {
+            return Err(DaemonError::StdErr(format!(
+                "{delegator_address} has no delegations to withdraw rewards from"
+            )));
+        }
+        let msgs = validators
+            .iter()
+            .map(|validator| {
+                MsgWithdrawDelegatorReward {
+                    delegator_address: delegator_address.clone(),
+                    validator_address: AccountId::from_str(validator)?,
+                }
+                .into_any()
+                .map_err(Into::into)
+            })
+            .collect::<Result<Vec<_>, DaemonError>>()?;
+        self.commit_msgs(msgs, "Rewards withdrawal", TxAction::Distribution)
+            .await
+    }
+
+    /// Set the address the staking rewards are withdrawn to
+    pub async fn set_withdraw_address(
+        &self,
+        address: &Addr,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let msg = MsgSetWithdrawAddress {
+            delegator_address: self.sender().msg_sender().map_err(Into::into)?,
+            withdraw_address: AccountId::from_str(address.as_str())?,
+        };
+        self.commit_msgs(
+            vec![msg.into_any()?],
+            "Withdraw address update",
+            TxAction::Distribution,
+        )
+        .await
+    }
+
+    /// Vote `option` on the proposal `proposal_id`
+    pub async fn vote(
+        &self,
+        proposal_id: u64,
+        option: VoteOption,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let option = match option {
+            VoteOption::Yes => cosmos_modules::gov::VoteOption::Yes,
+            VoteOption::No => cosmos_modules::gov::VoteOption::No,
+            VoteOption::Abstain => cosmos_modules::gov::VoteOption::Abstain,
+            VoteOption::NoWithVeto => cosmos_modules::gov::VoteOption::NoWithVeto,
+        };
+        let msg = cosmos_modules::gov::MsgVote {
+            proposal_id,
+            voter: self.sender().msg_sender().map_err(Into::into)?.to_string(),
+            option: option.into(),
+        };
+        let msg = Any {
+            type_url: VOTE_TYPE_URL.to_string(),
+            value: msg.encode_to_vec(),
+        };
+        self.commit_msgs(vec![msg], "Vote", TxAction::Gov).await
+    }
+
+    async fn commit_msgs(
+        &self,
+        msgs: Vec<Any>,
+        description: &str,
+        action: TxAction,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let result = self
+            .sender()
+            .commit_tx_any(msgs, None)
+            .await
+            .map_err(Into::into)?;
+        log::info!(target: &transaction_target(), "{description} done: {:?}", result.txhash);
+        self.accounting.record(TxTarget::None, action, &result);
+        Ok(result)
+    }
+}
+
 pub async fn upload_wasm<T: TxSender>(
     sender: &T,
     wasm_path: WasmPath,
     access: Option<AccessConfig>,
 ) -> Result<CosmTxResponse, DaemonError> {
     let file_contents = std::fs::read(wasm_path.path())?;
-    let mut e = write::GzEncoder::new(Vec::new(), Compression::default());
-    e.write_all(&file_contents)?;
-    let wasm_byte_code = e.finish()?;
+    let wasm_byte_code = wasm_byte_code(
+        wasm_path.path(),
+        file_contents,
+        DaemonEnvVars::gzip_wasm(),
+        DaemonEnvVars::max_wasm_size(),
+    )?;
     let store_msg = cosmrs::cosmwasm::MsgStoreCode {
         sender: sender.msg_sender().map_err(Into::into)?,
         wasm_byte_code,
@@ -391,6 +632,40 @@ pub async fn upload_wasm<T: TxSender>(
         .map_err(Into::into)
 }
 
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Byte code sent in `MsgStoreCode`, gzipped if `gzip` is set and the file isn't already gzipped.
+/// wasmd limits the uncompressed wasm size, the limit isn't exposed in the module params anymore so it's passed as `max_size`.
+fn wasm_byte_code(
+    wasm_path: &Path,
+    file_contents: Vec<u8>,
+    gzip: bool,
+    max_size: usize,
+) -> Result<Vec<u8>, DaemonError> {
+    let is_gzip = file_contents.starts_with(&GZIP_MAGIC);
+    let size = if is_gzip {
+        let mut wasm = vec![];
+        read::GzDecoder::new(file_contents.as_slice()).read_to_end(&mut wasm)?;
+        wasm.len()
+    } else {
+        file_contents.len()
+    };
+    if size > max_size {
+        return Err(DaemonError::WasmTooLarge {
+            path: wasm_path.display().to_string(),
+            size,
+            max: max_size,
+        });
+    }
+
+    if is_gzip || !gzip {
+        return Ok(file_contents);
+    }
+    let mut e = write::GzEncoder::new(Vec::new(), Compression::default());
+    e.write_all(&file_contents)?;
+    Ok(e.finish()?)
+}
+
 pub(crate) fn access_config_to_cosmrs(
     access_config: AccessConfig,
 ) -> Result<cosmrs::cosmwasm::AccessConfig, DaemonError> {
@@ -434,10 +709,13 @@ impl AsyncWasmQuerier for DaemonAsync {
             let mut client =
                 cosmos_modules::cosmwasm::query_client::QueryClient::new(self.channel());
             let resp = client
-                .smart_contract_state(cosmos_modules::cosmwasm::QuerySmartContractStateRequest {
-                    address: address.into(),
-                    query_data,
-                })
+                .smart_contract_state(request_at_height(
+                    cosmos_modules::cosmwasm::QuerySmartContractStateRequest {
+                        address: address.into(),
+                        query_data,
+                    },
+                    self.query_height,
+                ))
                 .await?;
             Ok(from_str(from_utf8(&resp.into_inner().data).unwrap())?)
         }
@@ -456,6 +734,10 @@ pub fn parse_cw_coins(coins: &[cosmwasm_std::Coin]) -> Result<Vec<cosmrs::Coin>,
         .collect::<Result<Vec<_>, DaemonError>>()
 }
 
+pub(crate) fn parse_cw_coin(coin: &cosmwasm_std::Coin) -> Result<cosmrs::Coin, DaemonError> {
+    Ok(parse_cw_coins(std::slice::from_ref(coin))?.remove(0))
+}
+
 pub(crate) fn proto_parse_cw_coins(
     coins: &[cosmwasm_std::Coin],
 ) -> Result<Vec<cosmrs::proto::cosmos::base::v1beta1::Coin>, DaemonError> {
@@ -469,3 +751,50 @@ pub(crate) fn proto_parse_cw_coins(
         })
         .collect::<Result<Vec<_>, DaemonError>>()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WASM: &[u8] = b"\0asm\x01\0\0\0 some contract code";
+
+    fn gunzip(byte_code: &[u8]) -> Vec<u8> {
+        let mut wasm = vec![];
+        read::GzDecoder::new(byte_code)
+            .read_to_end(&mut wasm)
+            .unwrap();
+        wasm
+    }
+
+    #[test]
+    fn wasm_byte_code_compression() -> anyhow::Result<()> {
+        let path = Path::new("contract.wasm");
+
+        let gzipped = wasm_byte_code(path, WASM.to_vec(), true, 1_000)?;
+        assert!(gzipped.starts_with(&GZIP_MAGIC));
+        assert_eq!(gunzip(&gzipped), WASM);
+
+        // Already gzipped files aren't compressed twice
+        assert_eq!(wasm_byte_code(path, gzipped.clone(), true, 1_000)?, gzipped);
+        assert_eq!(wasm_byte_code(path, WASM.to_vec(), false, 1_000)?, WASM);
+        Ok(())
+    }
+
+    #[test]
+    fn wasm_byte_code_size_check() -> anyhow::Result<()> {
+        let path = Path::new("contract.wasm");
+        let gzipped = wasm_byte_code(path, WASM.to_vec(), true, 1_000)?;
+
+        // The uncompressed size is checked, compressed or not
+        for file_contents in [WASM.to_vec(), gzipped] {
+            match wasm_byte_code(path, file_contents, true, WASM.len() - 1) {
+                Err(DaemonError::WasmTooLarge { size, max, .. }) => {
+                    assert_eq!(size, WASM.len());
+                    assert_eq!(max, WASM.len() - 1);
+                }
+                other => panic!("expected a size error, got {other:?}"),
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,405 @@
+//! Network diagnostics, probes every gRPC endpoint of a chain.
+//!
+//! For each endpoint the report contains the connectivity, the latency of a node info query,
+//! the chain id and syncing status of the node, its app and cosmos-sdk versions and whether it has the wasm module.
+//!
+//! ## Example
+//! ```no_run
+//! use cw_orch_daemon::{diagnostics::diagnose_network, networks};
+//! # tokio_test::block_on(async {
+//! let report = diagnose_network(&networks::JUNO_1.into()).await;
+//! println!("{report}");
+//! # })
+//! ```
+
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+use cw_orch_core::environment::ChainInfoOwned;
+use serde::Serialize;
+use tokio::task::JoinSet;
+use tonic::{transport::Channel, Code};
+
+use crate::{
+    channel::{error_chain, GrpcChannel},
+    queriers::{CosmWasm, Node},
+    DaemonError, RUNTIME,
+};
+
+/// Time given to an endpoint to connect and to answer each query
+const PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Diagnostics of a gRPC endpoint.
+/// The node fields are `None` when the endpoint is unreachable or the query failed.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct EndpointReport {
+    pub url: String,
+    /// Whether the endpoint is reached over TLS (`https` url)
+    pub tls: bool,
+    pub connected: bool,
+    /// First error encountered while probing the endpoint
+    pub error: Option<String>,
+    /// Round trip of the node info query
+    pub latency_ms: Option<u128>,
+    /// Chain id of the node
+    pub chain_id: Option<String>,
+    pub chain_id_matches: bool,
+    pub syncing: Option<bool>,
+    pub app_version: Option<String>,
+    pub cosmos_sdk_version: Option<String>,
+    /// Whether the node serves the CosmWasm module
+    pub wasm_module: Option<bool>,
+}
+
+impl EndpointReport {
+    /// Whether the daemon can use this endpoint
+    pub fn is_healthy(&self) -> bool {
+        self.connected && self.chain_id_matches && self.error.is_none()
+    }
+}
+
+/// Diagnostics of all the gRPC endpoints of a chain
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct NetworkReport {
+    /// Chain id of the chain info
+    pub chain_id: String,
+    pub endpoints: Vec<EndpointReport>,
+}
+
+impl NetworkReport {
+    /// Whether at least one endpoint can be used by the daemon
+    pub fn is_healthy(&self) -> bool {
+        self.endpoints.iter().any(EndpointReport::is_healthy)
+    }
+
+    pub fn to_json(&self) -> Result<String, DaemonError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+fn display_option<T: Display>(value: &Option<T>) -> String {
+    value
+        .as_ref()
+        .map_or_else(|| "-".to_string(), ToString::to_string)
+}
+
+impl Display for NetworkReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "gRPC endpoints of {}", self.chain_id)?;
+        writeln!(
+            f,
+            "{:<45} {:<4} {:<9} {:>8} {:<20} {:<8} {:<12} {:<12} {:<5}  error",
+            "url",
+            "tls",
+            "connected",
+            "latency",
+            "chain id",
+            "syncing",
+            "app",
+            "cosmos-sdk",
+            "wasm"
+        )?;
+        for endpoint in &self.endpoints {
+            let chain_id = match &endpoint.chain_id {
+                Some(chain_id) if !endpoint.chain_id_matches => format!("{chain_id} (!)"),
+                chain_id => display_option(chain_id),
+            };
+            writeln!(
+                f,
+                "{:<45} {:<4} {:<9} {:>8} {:<20} {:<8} {:<12} {:<12} {:<5}  {}",
+                endpoint.url,
+                endpoint.tls,
+                endpoint.connected,
+                display_option(&endpoint.latency_ms.map(|latency| format!("{latency}ms"))),
+                chain_id,
+                display_option(&endpoint.syncing),
+                display_option(&endpoint.app_version),
+                display_option(&endpoint.cosmos_sdk_version),
+                display_option(&endpoint.wasm_module),
+                endpoint.error.as_deref().unwrap_or_default()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Probes every gRPC url of the chain info
+pub async fn diagnose_network(chain_info: &ChainInfoOwned) -> NetworkReport {
+    let mut probes = JoinSet::new();
+    for (index, url) in chain_info.grpc_urls.iter().cloned().enumerate() {
+        let chain_id = chain_info.chain_id.clone();
+        probes.spawn(async move { (index, diagnose_endpoint(&url, &chain_id).await) });
+    }
+    let mut endpoints = vec![];
+    while let Some(probe) = probes.join_next().await {
+        endpoints.push(probe.expect("endpoint probes don't panic"));
+    }
+    endpoints.sort_by_key(|(index, _)| *index);
+    let endpoints = endpoints.into_iter().map(|(_, report)| report).collect();
+
+    NetworkReport {
+        chain_id: chain_info.chain_id.clone(),
+        endpoints,
+    }
+}
+
+/// Blocking version of [`diagnose_network`], can't be called from an async context
+pub fn diagnose_network_blocking(chain_info: &ChainInfoOwned) -> NetworkReport {
+    RUNTIME.block_on(diagnose_network(chain_info))
+}
+
+/// Probes a gRPC endpoint, expecting it to serve `chain_id`
+pub async fn diagnose_endpoint(url: &str, chain_id: &str) -> EndpointReport {
+    let mut report = EndpointReport {
+        url: url.to_string(),
+        tls: url.starts_with("https://"),
+        ..Default::default()
+    };
+
+    let channel = match connect(url).await {
+        Ok(channel) => channel,
+        Err(e) => {
+            report.error = Some(e);
+            return report;
+        }
+    };
+    report.connected = true;
+
+    let node = Node::new_async(channel.clone());
+    let start = Instant::now();
+    let node_info = match with_timeout(node._info()).await {
+        Ok(node_info) => node_info,
+        Err(e) => {
+            report.error = Some(e);
+            return report;
+        }
+    };
+    report.latency_ms = Some(start.elapsed().as_millis());
+
+    let network = node_info
+        .default_node_info
+        .map(|info| info.network)
+        .unwrap_or_default();
+    report.chain_id_matches = network == chain_id;
+    if !report.chain_id_matches {
+        report.error = Some(format!("expected chain id {chain_id}, found {network}"));
+    }
+    report.chain_id = Some(network);
+    if let Some(version) = node_info.application_version {
+        report.app_version = Some(version.version);
+        report.cosmos_sdk_version = Some(version.cosmos_sdk_version);
+    }
+
+    match with_timeout(node._syncing()).await {
+        Ok(syncing) => report.syncing = Some(syncing),
+        Err(e) => {
+            report.error.get_or_insert(e);
+        }
+    }
+
+    match CosmWasm::new_async(channel)._params().await {
+        Ok(_) => report.wasm_module = Some(true),
+        Err(DaemonError::Status(status)) if status.code() == Code::Unimplemented => {
+            report.wasm_module = Some(false)
+        }
+        Err(e) => {
+            report.error.get_or_insert(error_chain(&e));
+        }
+    }
+
+    report
+}
+
+async fn connect(url: &str) -> Result<Channel, String> {
+    let endpoint = GrpcChannel::endpoint(url).map_err(|e| error_chain(&e))?;
+    endpoint
+        .connect_timeout(PROBE_TIMEOUT)
+        .timeout(PROBE_TIMEOUT)
+        .connect()
+        .await
+        .map_err(|e| error_chain(&e))
+}
+
+async fn with_timeout<T>(
+    query: impl std::future::Future<Output = Result<T, DaemonError>>,
+) -> Result<T, String> {
+    match tokio::time::timeout(PROBE_TIMEOUT, query).await {
+        Ok(result) => result.map_err(|e| error_chain(&e)),
+        Err(_) => Err(format!("no answer after {}s", PROBE_TIMEOUT.as_secs())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cosmos_modules::{
+        cosmwasm::{Params, QueryParamsRequest, QueryParamsResponse},
+        tendermint::{
+            GetNodeInfoRequest, GetNodeInfoResponse, GetSyncingRequest, GetSyncingResponse,
+            VersionInfo,
+        },
+    };
+    use cosmrs::proto::tendermint::v0_34::p2p::DefaultNodeInfo;
+    use hyper::body::Incoming;
+    use hyper_util::rt::{TokioExecutor, TokioIo};
+    use std::{convert::Infallible, future::Future};
+    use tokio::net::TcpListener;
+    use tonic::{body::BoxBody, codec::ProstCodec, server::Grpc, Status};
+
+    /// Node serving the node info, syncing and wasm params queries on a local port
+    #[derive(Clone)]
+    struct MockNode {
+        network: &'static str,
+        wasm: bool,
+    }
+
+    impl MockNode {
+        /// Serves the node and returns its url
+        async fn spawn(self) -> anyhow::Result<String> {
+            let listener = TcpListener::bind("127.0.0.1:0").await?;
+            let url = format!("http://{}", listener.local_addr()?);
+            tokio::spawn(async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    let node = self.clone();
+                    let service = hyper::service::service_fn(move |request| {
+                        let node = node.clone();
+                        async move { Ok::<_, Infallible>(node.handle(request).await) }
+                    });
+                    tokio::spawn(
+                        hyper::server::conn::http2::Builder::new(TokioExecutor::new())
+                            .serve_connection(TokioIo::new(stream), service),
+                    );
+                }
+            });
+            Ok(url)
+        }
+
+        async fn handle(self, request: http::Request<Incoming>) -> http::Response<BoxBody> {
+            let path = request.uri().path().to_string();
+            match path.as_str() {
+                "/cosmos.base.tendermint.v1beta1.Service/GetNodeInfo" => {
+                    unary(request, move |_: GetNodeInfoRequest| async move {
+                        Ok(GetNodeInfoResponse {
+                            default_node_info: Some(DefaultNodeInfo {
+                                network: self.network.to_string(),
+                                ..Default::default()
+                            }),
+                            application_version: Some(VersionInfo {
+                                version: "v21.0.0".to_string(),
+                                cosmos_sdk_version: "v0.47.13".to_string(),
+                                ..Default::default()
+                            }),
+                        })
+                    })
+                    .await
+                }
+                "/cosmos.base.tendermint.v1beta1.Service/GetSyncing" => {
+                    unary(request, |_: GetSyncingRequest| async {
+                        Ok(GetSyncingResponse { syncing: false })
+                    })
+                    .await
+                }
+                "/cosmwasm.wasm.v1.Query/Params" if self.wasm => {
+                    unary(request, |_: QueryParamsRequest| async {
+                        Ok(QueryParamsResponse {
+                            params: Some(Params::default()),
+                        })
+                    })
+                    .await
+                }
+                _ => Status::unimplemented(path).into_http(),
+            }
+        }
+    }
+
+    async fn unary<Req, Resp, F, Fut>(
+        request: http::Request<Incoming>,
+        handler: F,
+    ) -> http::Response<BoxBody>
+    where
+        Req: prost::Message + Default + Send + 'static,
+        Resp: prost::Message + Send + 'static,
+        F: Fn(Req) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Resp, Status>> + Send + 'static,
+    {
+        let service = tower::service_fn(move |request: tonic::Request<Req>| {
+            let response = handler(request.into_inner());
+            async move { response.await.map(tonic::Response::new) }
+        });
+        Grpc::new(ProstCodec::<Resp, Req>::default())
+            .unary(service, request)
+            .await
+    }
+
+    #[tokio::test]
+    async fn reachable_endpoints() -> anyhow::Result<()> {
+        let mut chain_info: ChainInfoOwned = crate::networks::JUNO_1.into();
+        chain_info.grpc_urls = vec![
+            MockNode {
+                network: "juno-1",
+                wasm: true,
+            }
+            .spawn()
+            .await?,
+            MockNode {
+                network: "osmosis-1",
+                wasm: false,
+            }
+            .spawn()
+            .await?,
+        ];
+
+        let report = diagnose_network(&chain_info).await;
+        assert!(report.is_healthy());
+        assert_eq!(report.endpoints.len(), 2);
+
+        let healthy = &report.endpoints[0];
+        assert_eq!(healthy.url, chain_info.grpc_urls[0]);
+        assert!(healthy.is_healthy(), "{:?}", healthy.error);
+        assert!(!healthy.tls);
+        assert!(healthy.latency_ms.is_some());
+        assert_eq!(healthy.chain_id.as_deref(), Some("juno-1"));
+        assert_eq!(healthy.syncing, Some(false));
+        assert_eq!(healthy.app_version.as_deref(), Some("v21.0.0"));
+        assert_eq!(healthy.cosmos_sdk_version.as_deref(), Some("v0.47.13"));
+        assert_eq!(healthy.wasm_module, Some(true));
+
+        let other_chain = &report.endpoints[1];
+        assert!(other_chain.connected);
+        assert!(!other_chain.is_healthy());
+        assert!(!other_chain.chain_id_matches);
+        assert_eq!(
+            other_chain.error.as_deref(),
+            Some("expected chain id juno-1, found osmosis-1")
+        );
+        assert_eq!(other_chain.wasm_module, Some(false));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unreachable_endpoints() -> anyhow::Result<()> {
+        let mut chain_info: ChainInfoOwned = crate::networks::LOCAL_JUNO.into();
+        chain_info.grpc_urls = vec!["http://127.0.0.1:1".to_string(), "not a url".to_string()];
+
+        let report = diagnose_network(&chain_info).await;
+        assert!(!report.is_healthy());
+        assert_eq!(report.endpoints.len(), 2);
+        for endpoint in &report.endpoints {
+            assert!(!endpoint.connected);
+            assert!(endpoint.error.is_some());
+            assert_eq!(endpoint.latency_ms, None);
+        }
+        assert!(report.endpoints[1]
+            .error
+            .as_ref()
+            .unwrap()
+            .contains("Invalid gRPC url"));
+
+        assert!(report.to_string().contains("http://127.0.0.1:1"));
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()?)?;
+        assert_eq!(json["endpoints"][0]["connected"], false);
+        Ok(())
+    }
+}
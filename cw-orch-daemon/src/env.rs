@@ -14,6 +14,8 @@ use cosmwasm_std::StdError;
 use std::time::Duration;
 
 const DEFAULT_TX_QUERY_RETRIES: usize = 50;
+/// Default `MaxWasmSize` of wasmd
+pub const DEFAULT_MAX_WASM_SIZE: usize = 819_200;
 
 #[deprecated(since = "0.24.0", note = "Please use BLOCK_TIME_MIN_ENV_NAME instead")]
 pub const MIN_BLOCK_SPEED_ENV_NAME: &str = "CW_ORCH_MIN_BLOCK_SPEED";
@@ -26,6 +28,11 @@ pub const MIN_GAS_ENV_NAME: &str = "CW_ORCH_MIN_GAS";
 pub const MAX_TX_QUERIES_RETRY_ENV_NAME: &str = "CW_ORCH_MAX_TX_QUERY_RETRIES";
 pub const WALLET_BALANCE_ASSERTION_ENV_NAME: &str = "CW_ORCH_WALLET_BALANCE_ASSERTION";
 pub const LOGS_ACTIVATION_MESSAGE_ENV_NAME: &str = "CW_ORCH_LOGS_ACTIVATION_MESSAGE";
+pub const REST_TRANSPORT_ENV_NAME: &str = "CW_ORCH_REST_TRANSPORT";
+pub const KEYSTORE_PASSPHRASE_ENV_NAME: &str = "CW_ORCH_KEYSTORE_PASSPHRASE";
+pub const NETWORKS_CONFIG_ENV_NAME: &str = "CW_ORCH_NETWORKS_CONFIG";
+pub const GZIP_WASM_ENV_NAME: &str = "CW_ORCH_GZIP_WASM";
+pub const MAX_WASM_SIZE_ENV_NAME: &str = "CW_ORCH_MAX_WASM_SIZE";
 
 pub const MAIN_MNEMONIC_ENV_NAME: &str = "MAIN_MNEMONIC";
 pub const TEST_MNEMONIC_ENV_NAME: &str = "TEST_MNEMONIC";
@@ -77,6 +84,29 @@ impl DaemonEnvVars {
         }
     }
 
+    /// Optional - Boolean
+    /// Defaults to true
+    /// Whether the wasm byte code is gzipped before upload. Already gzipped files are uploaded as-is
+    pub fn gzip_wasm() -> bool {
+        if let Ok(str_value) = env::var(GZIP_WASM_ENV_NAME) {
+            parse_with_log(str_value, GZIP_WASM_ENV_NAME)
+        } else {
+            true
+        }
+    }
+
+    /// Optional - Integer
+    /// Defaults to [`DEFAULT_MAX_WASM_SIZE`]
+    /// Maximum size in bytes of the uncompressed wasm byte code accepted by the chain, checked before uploading
+    /// The chain doesn't expose this limit, it's a build-time setting of wasmd
+    pub fn max_wasm_size() -> usize {
+        if let Ok(str_value) = env::var(MAX_WASM_SIZE_ENV_NAME) {
+            parse_with_log(str_value, MAX_WASM_SIZE_ENV_NAME)
+        } else {
+            DEFAULT_MAX_WASM_SIZE
+        }
+    }
+
     /// Optional - Block time
     /// Defaults to 1s
     /// Minimum block time in `Duration`. Useful when the block speeds are varying a lot
@@ -126,6 +156,31 @@ impl DaemonEnvVars {
         }
     }
 
+    /// Optional - boolean
+    /// Defaults to "false"
+    /// Use the REST endpoint (`lcd_url`) of the chain instead of its gRPC endpoints.
+    /// The REST endpoint is always used when no gRPC endpoint is configured. Requires the `rest` feature
+    pub fn rest_transport() -> bool {
+        if let Ok(str_value) = env::var(REST_TRANSPORT_ENV_NAME) {
+            parse_with_log(str_value, REST_TRANSPORT_ENV_NAME)
+        } else {
+            false
+        }
+    }
+
+    /// Optional - String
+    /// Passphrase of the encrypted keystore of the wallet.
+    /// Required when the keystore is loaded without a passphrase
+    pub fn keystore_passphrase() -> Option<String> {
+        env::var(KEYSTORE_PASSPHRASE_ENV_NAME).ok()
+    }
+
+    /// Optional - Path
+    /// Network config file applied on top of the global and project-local `networks.toml` files
+    pub fn networks_config() -> Option<PathBuf> {
+        env::var(NETWORKS_CONFIG_ENV_NAME).ok().map(PathBuf::from)
+    }
+
     /// Optional - String
     /// Mandatory when interacting with a daemon on mainnet
     /// Mnemonic of the address interacting with a mainnet
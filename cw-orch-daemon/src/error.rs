@@ -30,6 +30,9 @@ pub enum DaemonError {
     TransportError(#[from] ::tonic::transport::Error),
     #[error(transparent)]
     TendermintError(#[from] ::cosmrs::tendermint::Error),
+    #[cfg(feature = "websocket")]
+    #[error(transparent)]
+    WebsocketError(#[from] ::tokio_tungstenite::tungstenite::Error),
     #[error(transparent)]
     CwEnvError(#[from] ::cw_orch_core::CwEnvError),
     #[error(transparent)]
@@ -44,6 +47,8 @@ pub enum DaemonError {
     Phrasing,
     #[error("Mnemonic - Missing Phrase")]
     MissingPhrase,
+    #[error("Keystore - {0}")]
+    Keystore(String),
     #[error("Bad Implementation. Missing Component")]
     Implementation,
     #[error("Unable to convert into public key `{key}`: {source}")]
@@ -101,6 +106,8 @@ pub enum DaemonError {
     NewNetwork(String),
     #[error("Can not connect to any grpc endpoint that was provided.")]
     CannotConnectGRPC,
+    #[error("Can not connect to the REST endpoint {0}.")]
+    CannotConnectREST(String),
     #[error("tx failed: {reason} with code {code}")]
     TxFailed { code: usize, reason: String },
     #[error("The list of grpc endpoints is empty")]
@@ -115,6 +122,11 @@ pub enum DaemonError {
     InsufficientFee(String),
     #[error("Not enough balance, expected {expected}, found {current}")]
     NotEnoughBalance { expected: Coin, current: Coin },
+    #[cfg(feature = "websocket")]
+    #[error(
+        "No websocket url configured for this sender, set one with `CosmosOptions::websocket_url`"
+    )]
+    MissingWebsocketUrl,
     #[error("Can't set the daemon state, it's read-only {0}")]
     StateReadOnly(String),
     #[error("You need to pass a runtime to the querier object to do synchronous queries. Use daemon.querier instead")]
@@ -127,6 +139,14 @@ pub enum DaemonError {
     OpenFile(String, String),
     #[error("State file {0} already locked, use another state file, clone daemon which holds the lock, or use `state` method of Builder")]
     StateAlreadyLocked(String),
+    #[error("Wasm byte code of {path} is {size} bytes, more than the {max} bytes accepted by the chain. Optimize the contract or set CW_ORCH_MAX_WASM_SIZE to the chain limit")]
+    WasmTooLarge {
+        path: String,
+        size: usize,
+        max: usize,
+    },
+    #[error("Invalid network config {path}: {reason}")]
+    NetworkConfig { path: String, reason: String },
 }
 
 impl DaemonError {
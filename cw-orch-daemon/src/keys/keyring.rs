@@ -0,0 +1,230 @@
+//! Items of the Cosmos SDK `keyring-backend file`.
+//!
+//! Each item is stored in its own file as a JWE token (`PBES2-HS256+A128KW` / `A256GCM`) encrypting
+//! the JSON encoded keyring item.
+
+use std::{
+    fs,
+    io::Read,
+    num::NonZeroU32,
+    path::{Path, PathBuf},
+};
+
+use aes_kw::KekAes128;
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+    Engine,
+};
+use ring::{
+    aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN},
+    pbkdf2,
+    rand::{SecureRandom, SystemRandom},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::DaemonError;
+
+const KEY_ALGORITHM: &str = "PBES2-HS256+A128KW";
+const CONTENT_ENCRYPTION: &str = "A256GCM";
+const PBKDF2_ITERATIONS: u32 = 8192;
+const SALT_LEN: usize = 16;
+const CEK_LEN: usize = 32;
+const KEK_LEN: usize = 16;
+const TAG_LEN: usize = 16;
+
+#[derive(Serialize, Deserialize)]
+struct JweHeader {
+    alg: String,
+    enc: String,
+    p2c: u32,
+    p2s: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    zip: Option<String>,
+}
+
+/// Item of the keyring, as serialized by `99designs/keyring`
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct KeyringItem {
+    key: String,
+    /// Base64 encoded data
+    data: String,
+    #[serde(default)]
+    label: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    keychain_not_trust_application: bool,
+    #[serde(default)]
+    keychain_not_synchronizable: bool,
+}
+
+/// Reads and decrypts the data of the item `key` of the keyring in `dir`
+pub(crate) fn read_item(dir: &Path, key: &str, passphrase: &str) -> Result<Vec<u8>, DaemonError> {
+    let token = fs::read_to_string(dir.join(key))?;
+    let item: KeyringItem = serde_json::from_slice(&decrypt(token.trim(), passphrase)?)?;
+    Ok(STANDARD.decode(item.data)?)
+}
+
+/// Encrypts and writes `data` as the item `key` of the keyring in `dir`
+pub(crate) fn write_item(
+    dir: &Path,
+    key: &str,
+    data: &[u8],
+    passphrase: &str,
+) -> Result<PathBuf, DaemonError> {
+    let item = KeyringItem {
+        key: key.to_string(),
+        data: STANDARD.encode(data),
+        label: String::new(),
+        description: String::new(),
+        keychain_not_trust_application: false,
+        keychain_not_synchronizable: false,
+    };
+    let token = encrypt(&serde_json::to_vec(&item)?, passphrase)?;
+
+    fs::create_dir_all(dir)?;
+    let path = dir.join(key);
+    fs::write(&path, token)?;
+    Ok(path)
+}
+
+fn decrypt(token: &str, passphrase: &str) -> Result<Vec<u8>, DaemonError> {
+    let [header_b64, encrypted_key, iv, ciphertext, tag]: [&str; 5] = token
+        .split('.')
+        .collect::<Vec<_>>()
+        .try_into()
+        .map_err(|_| keystore_error("invalid JWE token"))?;
+
+    let header: JweHeader = serde_json::from_slice(&URL_SAFE_NO_PAD.decode(header_b64)?)?;
+    if header.alg != KEY_ALGORITHM || header.enc != CONTENT_ENCRYPTION {
+        return Err(keystore_error(format!(
+            "unsupported JWE algorithm {}/{}",
+            header.alg, header.enc
+        )));
+    }
+
+    let kek = derive_kek(
+        passphrase,
+        &URL_SAFE_NO_PAD.decode(&header.p2s)?,
+        header.p2c,
+    )?;
+    let cek = kek
+        .unwrap_vec(&URL_SAFE_NO_PAD.decode(encrypted_key)?)
+        .map_err(|_| keystore_error("wrong passphrase"))?;
+
+    let mut in_out = URL_SAFE_NO_PAD.decode(ciphertext)?;
+    in_out.extend(URL_SAFE_NO_PAD.decode(tag)?);
+    let nonce = Nonce::try_assume_unique_for_key(&URL_SAFE_NO_PAD.decode(iv)?)
+        .map_err(|_| keystore_error("invalid JWE iv"))?;
+    let plaintext = content_key(&cek)?
+        .open_in_place(nonce, Aad::from(header_b64.as_bytes()), &mut in_out)
+        .map_err(|_| keystore_error("wrong passphrase"))?;
+
+    match header.zip.as_deref() {
+        None => Ok(plaintext.to_vec()),
+        Some("DEF") => {
+            let mut inflated = vec![];
+            flate2::read::DeflateDecoder::new(&plaintext[..]).read_to_end(&mut inflated)?;
+            Ok(inflated)
+        }
+        Some(zip) => Err(keystore_error(format!("unsupported JWE compression {zip}"))),
+    }
+}
+
+fn encrypt(plaintext: &[u8], passphrase: &str) -> Result<String, DaemonError> {
+    let rng = SystemRandom::new();
+    let mut salt = [0u8; SALT_LEN];
+    let mut cek = [0u8; CEK_LEN];
+    let mut iv = [0u8; NONCE_LEN];
+    for bytes in [&mut salt[..], &mut cek[..], &mut iv[..]] {
+        rng.fill(bytes)
+            .map_err(|_| keystore_error("failed to generate random bytes"))?;
+    }
+
+    let header = JweHeader {
+        alg: KEY_ALGORITHM.to_string(),
+        enc: CONTENT_ENCRYPTION.to_string(),
+        p2c: PBKDF2_ITERATIONS,
+        p2s: URL_SAFE_NO_PAD.encode(salt),
+        zip: None,
+    };
+    let header_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header)?);
+
+    let encrypted_key = derive_kek(passphrase, &salt, PBKDF2_ITERATIONS)?
+        .wrap_vec(&cek)
+        .map_err(|e| keystore_error(e.to_string()))?;
+
+    let mut in_out = plaintext.to_vec();
+    content_key(&cek)?
+        .seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(iv),
+            Aad::from(header_b64.as_bytes()),
+            &mut in_out,
+        )
+        .map_err(|_| keystore_error("encryption failed"))?;
+    let (ciphertext, tag) = in_out.split_at(in_out.len() - TAG_LEN);
+
+    Ok([
+        header_b64,
+        URL_SAFE_NO_PAD.encode(encrypted_key),
+        URL_SAFE_NO_PAD.encode(iv),
+        URL_SAFE_NO_PAD.encode(ciphertext),
+        URL_SAFE_NO_PAD.encode(tag),
+    ]
+    .join("."))
+}
+
+/// PBES2 key derivation, the salt is prefixed with the algorithm name (RFC 7518 4.8.1.1)
+fn derive_kek(passphrase: &str, salt: &[u8], iterations: u32) -> Result<KekAes128, DaemonError> {
+    let iterations =
+        NonZeroU32::new(iterations).ok_or_else(|| keystore_error("invalid PBES2 iterations"))?;
+    let salt = [KEY_ALGORITHM.as_bytes(), &[0], salt].concat();
+
+    let mut kek = [0u8; KEK_LEN];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        &salt,
+        passphrase.as_bytes(),
+        &mut kek,
+    );
+    Ok(KekAes128::from(kek))
+}
+
+fn content_key(cek: &[u8]) -> Result<LessSafeKey, DaemonError> {
+    let key = UnboundKey::new(&AES_256_GCM, cek)
+        .map_err(|_| keystore_error("invalid content encryption key"))?;
+    Ok(LessSafeKey::new(key))
+}
+
+fn keystore_error(reason: impl Into<String>) -> DaemonError {
+    DaemonError::Keystore(reason.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jwe_roundtrip() -> anyhow::Result<()> {
+        let token = encrypt(b"keyring item", "passphrase")?;
+        assert_eq!(token.split('.').count(), 5);
+        assert_eq!(decrypt(&token, "passphrase")?, b"keyring item");
+        assert!(matches!(
+            decrypt(&token, "wrong"),
+            Err(DaemonError::Keystore(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn item_roundtrip() -> anyhow::Result<()> {
+        let dir = crate::state::gen_temp_file_path();
+        let path = write_item(&dir, "test.info", b"record", "passphrase")?;
+        assert_eq!(path, dir.join("test.info"));
+        assert_eq!(read_item(&dir, "test.info", "passphrase")?, b"record");
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
 #![allow(unused)]
+#[cfg(feature = "keystore")]
+mod keyring;
 pub mod private;
 pub mod public;
 pub mod signature;
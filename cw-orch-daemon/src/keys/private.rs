@@ -1,21 +1,34 @@
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "keystore")]
+use super::keyring;
 use super::public::PublicKey;
-use crate::proto::injective::{InjectivePubKey, ETHEREUM_COIN_TYPE};
+#[cfg(feature = "keystore")]
+use crate::proto::keyring::{Local, Record};
 use crate::DaemonError;
 #[cfg(feature = "eth")]
 use ::ethers_core::k256::ecdsa::SigningKey;
 use base64::Engine;
 use bitcoin::secp256k1::{self, Secp256k1};
 use bitcoin::{
-    bip32::{IntoDerivationPath, Xpriv},
-    Network,
+    bip32::{ChainCode, ChildNumber, Fingerprint, IntoDerivationPath, Xpriv},
+    Network, NetworkKind,
 };
+use cosmrs::proto::cosmos::crypto::secp256k1 as secp256k1_proto;
 use cosmrs::tx::SignerPublicKey;
-use cw_orch_core::log::local_target;
+use cw_orch_core::{
+    environment::{KeyAlgorithmOwned, INJECTIVE_PUB_KEY_TYPE_URL},
+    log::local_target,
+};
+use prost::Message;
 use prost_types::Any;
 use rand_core::{OsRng, RngCore};
 
 pub const DEFAULT_MNEMONIC_WORD_COUNT: usize = 24;
 
+const SECP256K1_PUBKEY_TYPE_URL: &str = "/cosmos.crypto.secp256k1.PubKey";
+const SECP256K1_PRIVKEY_TYPE_URL: &str = "/cosmos.crypto.secp256k1.PrivKey";
+
 /// The Private key structure that is used to generate signatures and public keys
 /// WARNING: No Security Audit has been performed
 #[derive(Clone)]
@@ -26,6 +39,8 @@ pub struct PrivateKey {
     pub index: u32,
     #[allow(missing_docs)]
     pub coin_type: u32,
+    /// Algorithm used for address derivation and signing, set from the network by the senders
+    pub key_algorithm: KeyAlgorithmOwned,
     /// The mnemonic (12, 15, 18, 21 or 24 words) used to generate this private key
     mnemonic: Option<bip39::Mnemonic>,
     #[allow(dead_code)]
@@ -96,22 +111,157 @@ impl PrivateKey {
         Self::gen_private_key_raw(secp, raw_key, account, index, coin_type)
     }
 
+    /// Private key that is not derived from a seed, as stored in keystores
+    pub fn from_secret_key(secret_key: &[u8], coin_type: u32) -> Result<PrivateKey, DaemonError> {
+        let private_key = Xpriv {
+            network: NetworkKind::Main,
+            depth: 0,
+            parent_fingerprint: Fingerprint::default(),
+            child_number: ChildNumber::Normal { index: 0 },
+            private_key: secp256k1::SecretKey::from_slice(secret_key)?,
+            chain_code: ChainCode::from([0u8; 32]),
+        };
+        Ok(PrivateKey {
+            account: 0,
+            index: 0,
+            coin_type,
+            key_algorithm: KeyAlgorithmOwned::Secp256k1,
+            mnemonic: None,
+            root_private_key: private_key,
+            private_key,
+        })
+    }
+
+    /// Sets the algorithm of the key, secp256k1 by default
+    pub fn with_key_algorithm(mut self, key_algorithm: KeyAlgorithmOwned) -> Self {
+        self.key_algorithm = key_algorithm;
+        self
+    }
+
+    /// Imports the key `name` from a Cosmos SDK `keyring-backend file` directory (`~/.<app>/keyring-file`).
+    /// Only keys created with Cosmos SDK v0.46+ are supported.
+    #[cfg(feature = "keystore")]
+    pub fn from_keyring_file<C: secp256k1::Signing + secp256k1::Context>(
+        secp: &Secp256k1<C>,
+        dir: impl AsRef<Path>,
+        name: &str,
+        passphrase: &str,
+        coin_type: u32,
+    ) -> Result<PrivateKey, DaemonError> {
+        let record = Record::decode(
+            keyring::read_item(dir.as_ref(), &format!("{name}.info"), passphrase)?.as_slice(),
+        )?;
+        let priv_key = record
+            .local
+            .and_then(|local| local.priv_key)
+            .ok_or_else(|| DaemonError::Keystore(format!("{name} is not a local key")))?;
+        // secp256k1 and eth_secp256k1 private keys share the same encoding
+        let priv_key = secp256k1_proto::PrivKey::decode(priv_key.value.as_slice())?;
+        Self::from_secret_key(&priv_key.key, coin_type)
+    }
+
+    /// Imports a key from an Ethereum JSON keystore file (scrypt or pbkdf2)
+    #[cfg(feature = "keystore")]
+    pub fn from_eth_keystore(
+        path: impl AsRef<Path>,
+        passphrase: &str,
+        coin_type: u32,
+    ) -> Result<PrivateKey, DaemonError> {
+        let secret_key = eth_keystore::decrypt_key(path, passphrase)
+            .map_err(|e| DaemonError::Keystore(e.to_string()))?;
+        Self::from_secret_key(&secret_key, coin_type)
+    }
+
+    /// Exports this key as `name` in a Cosmos SDK `keyring-backend file` directory.
+    /// Returns the path of the key file.
+    #[cfg(feature = "keystore")]
+    pub fn export_keyring_file<C: secp256k1::Signing + secp256k1::Context>(
+        &self,
+        secp: &Secp256k1<C>,
+        dir: impl AsRef<Path>,
+        name: &str,
+        passphrase: &str,
+    ) -> Result<PathBuf, DaemonError> {
+        let (pub_key_type_url, priv_key_type_url) = match &self.key_algorithm {
+            KeyAlgorithmOwned::Secp256k1 => (
+                SECP256K1_PUBKEY_TYPE_URL.to_string(),
+                SECP256K1_PRIVKEY_TYPE_URL.to_string(),
+            ),
+            // Private keys live in the same package as their public key
+            KeyAlgorithmOwned::EthSecp256k1 { pub_key_type_url } => (
+                pub_key_type_url.clone(),
+                pub_key_type_url.replace(".PubKey", ".PrivKey"),
+            ),
+        };
+        let pub_key = self.private_key.private_key.public_key(secp).serialize();
+        let record = Record {
+            name: name.to_string(),
+            pub_key: Some(cosmrs::Any {
+                type_url: pub_key_type_url,
+                value: secp256k1_proto::PubKey {
+                    key: pub_key.to_vec(),
+                }
+                .encode_to_vec(),
+            }),
+            local: Some(Local {
+                priv_key: Some(cosmrs::Any {
+                    type_url: priv_key_type_url,
+                    value: secp256k1_proto::PrivKey {
+                        key: self.raw_key().to_vec(),
+                    }
+                    .encode_to_vec(),
+                }),
+            }),
+        };
+
+        let dir = dir.as_ref();
+        let info_key = format!("{name}.info");
+        let path = keyring::write_item(dir, &info_key, &record.encode_to_vec(), passphrase)?;
+
+        // The keyring indexes keys by address as well
+        let address = self
+            .public_key(secp)
+            .raw_address
+            .ok_or(DaemonError::Implementation)?;
+        keyring::write_item(
+            dir,
+            &format!("{}.address", hex::encode(address)),
+            info_key.as_bytes(),
+            passphrase,
+        )?;
+        Ok(path)
+    }
+
+    /// Exports this key as `name` in an Ethereum JSON keystore (scrypt) in `dir`.
+    /// Returns the path of the keystore file.
+    #[cfg(feature = "keystore")]
+    pub fn export_eth_keystore(
+        &self,
+        dir: impl AsRef<Path>,
+        name: &str,
+        passphrase: &str,
+    ) -> Result<PathBuf, DaemonError> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+        // Returns the uuid of the keystore, the file is named `name`
+        eth_keystore::encrypt_key(dir, &mut OsRng, self.raw_key(), passphrase, Some(name))
+            .map_err(|e| DaemonError::Keystore(e.to_string()))?;
+        Ok(dir.join(name))
+    }
+
     /// generate the public key for this private key
     pub fn public_key<C: secp256k1::Signing + secp256k1::Context>(
         &self,
         secp: &Secp256k1<C>,
     ) -> PublicKey {
-        if self.coin_type == ETHEREUM_COIN_TYPE {
+        if self.key_algorithm.is_eth() {
             #[cfg(feature = "eth")]
             return PublicKey::from_ethers_address_bytes(
                 ethers_core::utils::secret_key_to_address(
                     &SigningKey::from_slice(self.raw_key().as_slice()).unwrap(),
                 ),
             );
-            panic!(
-                "Coin Type {} not supported without eth feature",
-                ETHEREUM_COIN_TYPE
-            );
+            panic!("eth_secp256k1 keys are not supported without the eth feature");
         }
 
         let x = self.private_key.private_key.public_key(secp);
@@ -121,34 +271,43 @@ impl PrivateKey {
     pub fn get_injective_public_key<C: secp256k1::Signing + secp256k1::Context>(
         &self,
         secp: &Secp256k1<C>,
+    ) -> SignerPublicKey {
+        self.get_eth_public_key(secp, INJECTIVE_PUB_KEY_TYPE_URL)
+    }
+
+    /// eth_secp256k1 public key, `pub_key_type_url` depends on the chain
+    pub fn get_eth_public_key<C: secp256k1::Signing + secp256k1::Context>(
+        &self,
+        secp: &Secp256k1<C>,
+        pub_key_type_url: &str,
     ) -> SignerPublicKey {
         use base64::engine::general_purpose;
-        use cosmrs::tx::MessageExt;
-        use secp256k1::SecretKey;
 
-        let secret_key = SecretKey::from_slice(self.raw_key().as_slice()).unwrap();
+        let secret_key = secp256k1::SecretKey::from_slice(self.raw_key().as_slice()).unwrap();
         let public_key = secp256k1::PublicKey::from_secret_key(secp, &secret_key);
 
         let vec_pk = public_key.serialize();
 
         log::debug!(target: &local_target(), "{:?}, public key", general_purpose::STANDARD.encode(vec_pk));
 
-        let inj_key = InjectivePubKey { key: vec_pk.into() };
-
-        cosmrs::Any::from_msg(&inj_key).unwrap().try_into().unwrap()
+        // eth_secp256k1 public keys share the encoding of secp256k1 public keys
+        cosmrs::Any {
+            type_url: pub_key_type_url.to_string(),
+            value: secp256k1_proto::PubKey { key: vec_pk.into() }.encode_to_vec(),
+        }
+        .try_into()
+        .unwrap()
     }
 
     pub fn get_signer_public_key<C: secp256k1::Signing + secp256k1::Context>(
         &self,
         secp: &Secp256k1<C>,
     ) -> Option<SignerPublicKey> {
-        if self.coin_type == ETHEREUM_COIN_TYPE {
+        if let KeyAlgorithmOwned::EthSecp256k1 { pub_key_type_url } = &self.key_algorithm {
             #[cfg(feature = "eth")]
-            return Some(self.get_injective_public_key(secp));
-            panic!(
-                "Coin Type {} not supported without eth feature",
-                ETHEREUM_COIN_TYPE
-            );
+            return Some(self.get_eth_public_key(secp, pub_key_type_url));
+            #[cfg(not(feature = "eth"))]
+            panic!("{pub_key_type_url} keys are not supported without the eth feature");
         }
 
         Some(
@@ -196,6 +355,7 @@ impl PrivateKey {
             account,
             index,
             coin_type,
+            key_algorithm: KeyAlgorithmOwned::Secp256k1,
             mnemonic: None,
             root_private_key,
             private_key,
@@ -221,6 +381,9 @@ mod tst {
     use ethers_core::k256::ecdsa::SigningKey;
     use ethers_signers::{coins_bip39::English, MnemonicBuilder, Signer};
 
+    #[cfg(feature = "eth")]
+    use cw_orch_core::environment::KeyAlgorithm;
+
     use super::*;
 
     #[test]
@@ -297,6 +460,44 @@ mod tst {
         Ok(())
     }
 
+    #[cfg(feature = "keystore")]
+    #[test]
+    pub fn tst_keystores() -> anyhow::Result<()> {
+        let str_1 = "wonder caution square unveil april art add hover spend smile proud admit modify old copper throw crew happy nature luggage reopen exhibit ordinary napkin";
+        let secp = Secp256k1::new();
+        let pk = PrivateKey::from_words(&secp, str_1, 0, 0, 330)?;
+        let dir = crate::state::gen_temp_file_path();
+
+        pk.export_keyring_file(&secp, dir.join("keyring-file"), "test", "passphrase")?;
+        let imported = PrivateKey::from_keyring_file(
+            &secp,
+            dir.join("keyring-file"),
+            "test",
+            "passphrase",
+            330,
+        )?;
+        assert_eq!(imported.raw_key(), pk.raw_key());
+        assert_eq!(
+            imported.public_key(&secp).account("terra")?,
+            "terra1jnzv225hwl3uxc5wtnlgr8mwy6nlt0vztv3qqm"
+        );
+        assert!(PrivateKey::from_keyring_file(
+            &secp,
+            dir.join("keyring-file"),
+            "test",
+            "wrong",
+            330
+        )
+        .is_err());
+
+        let path = pk.export_eth_keystore(dir.join("eth"), "test", "passphrase")?;
+        let imported = PrivateKey::from_eth_keystore(path, "passphrase", 330)?;
+        assert_eq!(imported.raw_key(), pk.raw_key());
+
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
     #[cfg(feature = "eth")]
     #[test]
     pub fn inj() -> anyhow::Result<()> {
@@ -311,16 +512,29 @@ mod tst {
         assert_eq!(&account, "juno1jdpunqljj5xypxk6f7dnpga6cjfatwu6vfuyrq");
         // juno1jdpunqljj5xypxk6f7dnpga6cjfatwu6vfuyrq
 
-        // Coin type 60 is a bit peculiar, because of how injective derives addresses : https://docs.injective.network/learn/basic-concepts/accounts/
+        // eth_secp256k1 keys derive addresses like Ethereum : https://docs.injective.network/learn/basic-concepts/accounts/
         let coin_type: u32 = 60;
         let prefix = "inj";
-        let pk = PrivateKey::from_words(&secp, str_1, 0, 0, coin_type)?;
+        let pk = PrivateKey::from_words(&secp, str_1, 0, 0, coin_type)?
+            .with_key_algorithm(KeyAlgorithm::INJECTIVE.into());
         let pub_k = pk.public_key(&secp);
 
         let account = pub_k.account(prefix)?;
         assert_eq!(&account, "inj1u4f9tvhkltksfr5ezz5cfe8fcsl9k5t5ycjhat");
         // inj1u4f9tvhkltksfr5ezz5cfe8fcsl9k5t5ycjhat
 
+        // Ethermint chains derive the same key and address
+        let pk = pk.with_key_algorithm(KeyAlgorithm::ETHERMINT.into());
+        assert_eq!(
+            pk.public_key(&secp).account("evmos")?,
+            "evmos1u4f9tvhkltksfr5ezz5cfe8fcsl9k5t5vs5a4m"
+        );
+        let signer_public_key = pk.get_signer_public_key(&secp).unwrap();
+        assert_eq!(
+            cosmrs::Any::from(signer_public_key).type_url,
+            cw_orch_core::environment::ETHERMINT_PUB_KEY_TYPE_URL
+        );
+
         Ok(())
     }
 
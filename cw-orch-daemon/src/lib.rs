@@ -7,27 +7,37 @@ pub mod json_lock;
 /// Proto types for different blockchains
 pub mod proto;
 // expose these as mods as they can grow
+pub mod accounting;
+pub mod diagnostics;
 pub mod env;
 pub mod keys;
 pub mod live_mock;
+pub mod network_config;
 pub mod queriers;
 pub mod senders;
+#[cfg(feature = "tracing")]
+pub mod telemetry;
 pub mod tx_broadcaster;
 pub mod tx_builder;
+#[cfg(feature = "websocket")]
+pub mod websocket;
 
 mod builder;
 mod channel;
 mod core;
 mod error;
 mod log;
-mod network_config;
+#[cfg(feature = "rest")]
+mod rest;
 mod state;
 mod sync;
 mod tx_resp;
 
 pub use self::{builder::*, channel::*, core::*, error::*, state::*, sync::*, tx_resp::*};
 pub use cw_orch_networks::networks;
-pub use network_config::read_network_config;
+pub use network_config::{dump_network_config, effective_network_config, read_network_config};
+#[cfg(feature = "rest")]
+pub use rest::RestChannel;
 pub use senders::{query::QuerySender, tx::TxSender, CosmosOptions, Wallet};
 pub use tx_builder::TxBuilder;
 
@@ -38,10 +48,14 @@ pub(crate) mod cosmos_modules {
             authz::v1beta1 as authz,
             bank::v1beta1 as bank,
             base::{abci::v1beta1 as abci, tendermint::v1beta1 as tendermint},
+            distribution::v1beta1 as distribution,
             feegrant::v1beta1 as feegrant,
             gov::v1beta1 as gov,
+            mint::v1beta1 as mint,
+            slashing::v1beta1 as slashing,
             staking::v1beta1 as staking,
             tx::v1beta1 as tx,
+            upgrade::v1beta1 as upgrade,
             vesting::v1beta1 as vesting,
         },
         cosmwasm::wasm::v1 as cosmwasm,
@@ -1,29 +1,34 @@
 //! Live mock is a mock that uses a live chain to query for data.
 //! It can be used to do chain-backed unit-testing. It can't be used for state-changing operations.
 
-use crate::queriers::Bank;
-use crate::queriers::CosmWasm;
-use crate::queriers::Staking;
-use crate::RUNTIME;
+use crate::cosmos_modules;
+use crate::cosmos_query;
+use crate::queriers::{
+    cosmrs_to_cosmwasm_dec_coins, cosmrs_to_cosmwasm_validator, Bank, CosmWasm, Distribution, Ibc,
+    Staking,
+};
+use crate::{DaemonError, RUNTIME};
+use cosmrs::proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
 use cosmwasm_std::testing::{MockApi, MockStorage};
-use cosmwasm_std::Addr;
-use cosmwasm_std::AllBalanceResponse;
-use cosmwasm_std::BalanceResponse;
-use cosmwasm_std::BankQuery;
-use cosmwasm_std::Binary;
-use cosmwasm_std::Delegation;
-use cosmwasm_std::Empty;
-use cosmwasm_std::StakingQuery;
 use cosmwasm_std::{
-    from_json, to_json_binary, Coin, ContractResult, OwnedDeps, Querier, QuerierResult,
-    QueryRequest, SystemError, SystemResult, Uint128, WasmQuery,
+    from_json, to_json_binary, Addr, AllBalanceResponse, AllDelegationsResponse,
+    AllDenomMetadataResponse, AllValidatorsResponse, BalanceResponse, BankQuery, Binary,
+    BondedDenomResponse, ChannelResponse, Coin, ContractResult, DecCoin, Delegation,
+    DelegationResponse, DelegationRewardsResponse, DelegationTotalRewardsResponse, DelegatorReward,
+    DelegatorValidatorsResponse, DelegatorWithdrawAddressResponse, DenomMetadata,
+    DenomMetadataResponse, DenomUnit, DistributionQuery, Empty, FullDelegation, IbcChannel,
+    IbcEndpoint, IbcOrder, IbcQuery, ListChannelsResponse, OwnedDeps, PortIdResponse, Querier,
+    QuerierResult, QueryRequest, StakingQuery, StdError, SupplyResponse, SystemError, SystemResult,
+    Uint128, ValidatorResponse, WasmQuery,
 };
-use cosmwasm_std::{AllDelegationsResponse, BondedDenomResponse};
-use cw_orch_core::environment::BankQuerier;
 use cw_orch_core::environment::ChainInfoOwned;
-use cw_orch_core::environment::WasmQuerier;
+use prost::bytes::{Buf, BufMut};
+use serde::Serialize;
+use std::future::Future;
 use std::marker::PhantomData;
 use std::str::FromStr;
+use tonic::codec::{Codec, DecodeBuf, Decoder, EncodeBuf, Encoder};
+use tonic::codegen::http::uri::PathAndQuery;
 use tonic::transport::Channel;
 
 use crate::channel::GrpcChannel;
@@ -35,8 +40,15 @@ fn to_cosmwasm_coin(c: cosmrs::proto::cosmos::base::v1beta1::Coin) -> Coin {
     }
 }
 
-const QUERIER_ERROR: &str =
-    "Only Bank balances and Wasm (raw + smart) and Some staking queries are covered for now";
+/// Rewards are paid out as whole coins, the decimal part is truncated
+fn dec_coin_to_coin(c: DecCoin) -> Result<Coin, DaemonError> {
+    Ok(Coin {
+        amount: Uint128::try_from(c.amount.to_uint_floor()).map_err(StdError::from)?,
+        denom: c.denom,
+    })
+}
+
+const QUERIER_ERROR: &str = "This query is not supported by the live mock querier";
 
 /// mock_dependencies is a drop-in replacement for cosmwasm_std::testing::mock_dependencies
 /// this uses our CustomQuerier.
@@ -56,6 +68,8 @@ pub fn mock_dependencies(
 /// Querier struct that fetches queries on-chain directly
 pub struct WasmMockQuerier {
     channel: Channel,
+    /// Contract the queries are made from, used for the IBC queries relative to the contract's port
+    contract_address: Option<Addr>,
 }
 
 impl Querier for WasmMockQuerier {
@@ -76,115 +90,385 @@ impl Querier for WasmMockQuerier {
 
 impl WasmMockQuerier {
     /// Function used to handle a query and customize the query behavior
-    /// This implements some queries by querying an actual node for the responses
+    /// This implements the queries by querying an actual node for the responses
     pub fn handle_query(&self, request: &QueryRequest<Empty>) -> QuerierResult {
-        let handle = RUNTIME.handle();
-        match &request {
-            QueryRequest::Wasm(x) => {
-                let querier = CosmWasm::new_sync(self.channel.clone(), handle);
-                match x {
-                    WasmQuery::Smart { contract_addr, msg } => {
-                        // We forward the request to the cosmwasm querier
-
-                        let query_result: Result<Binary, _> = handle
-                            .block_on(
-                                querier
-                                    ._contract_state(&Addr::unchecked(contract_addr), msg.to_vec()),
-                            )
-                            .map(|query_result| query_result.into());
-                        SystemResult::Ok(ContractResult::from(query_result))
-                    }
-                    WasmQuery::Raw { contract_addr, key } => {
-                        // We forward the request to the cosmwasm querie
-                        let query_result = querier
-                            .raw_query(&Addr::unchecked(contract_addr), key.to_vec())
-                            .map(|query_result| query_result.into());
+        let result = match request {
+            QueryRequest::Wasm(query) => self.wasm_query(query),
+            QueryRequest::Bank(query) => self.bank_query(query),
+            QueryRequest::Staking(query) => self.staking_query(query),
+            QueryRequest::Distribution(query) => self.distribution_query(query),
+            QueryRequest::Ibc(query) => self.ibc_query(query),
+            QueryRequest::Grpc(query) => Some(block_on(self.grpc_query(&query.path, &query.data))),
+            #[allow(deprecated)]
+            QueryRequest::Stargate { path, data } => Some(block_on(self.grpc_query(path, data))),
+            _ => None,
+        };
+        match result {
+            Some(result) => SystemResult::Ok(ContractResult::from(result)),
+            None => SystemResult::Err(SystemError::InvalidRequest {
+                error: QUERIER_ERROR.to_string(),
+                request: to_json_binary(&request).unwrap(),
+            }),
+        }
+    }
 
-                        SystemResult::Ok(ContractResult::from(query_result))
-                    }
-                    _ => SystemResult::Err(SystemError::InvalidRequest {
-                        error: QUERIER_ERROR.to_string(),
-                        request: to_json_binary(&request).unwrap(),
-                    }),
-                }
+    fn wasm_query(&self, query: &WasmQuery) -> Option<Result<Binary, DaemonError>> {
+        let querier = CosmWasm::new_async(self.channel.clone());
+        let result = match query {
+            WasmQuery::Smart { contract_addr, msg } => {
+                block_on(querier._contract_state(&Addr::unchecked(contract_addr), msg.to_vec()))
+                    .map(Binary::from)
             }
-            QueryRequest::Bank(x) => {
-                let querier = Bank {
-                    channel: self.channel.clone(),
-                    rt_handle: Some(handle.clone()),
-                };
-                match x {
-                    BankQuery::Balance { address, denom } => {
-                        let query_result = querier
-                            .balance(&Addr::unchecked(address), Some(denom.clone()))
-                            .map(|result| {
-                                to_json_binary(&BalanceResponse::new(result[0].clone())).unwrap()
-                            });
-                        SystemResult::Ok(ContractResult::from(query_result))
-                    }
-                    BankQuery::AllBalances { address } => {
-                        let query_result = querier
-                            .balance(&Addr::unchecked(address), None)
-                            .map(AllBalanceResponse::new)
-                            .map(|query_result| to_json_binary(&query_result))
-                            .unwrap();
-                        SystemResult::Ok(ContractResult::from(query_result))
-                    }
-                    _ => SystemResult::Err(SystemError::InvalidRequest {
-                        error: QUERIER_ERROR.to_string(),
-                        request: to_json_binary(&request).unwrap(),
-                    }),
-                }
+            WasmQuery::Raw { contract_addr, key } => {
+                block_on(querier._contract_raw_state(&Addr::unchecked(contract_addr), key.to_vec()))
+                    .map(|response| response.data.into())
             }
-            QueryRequest::Staking(x) => {
-                let querier = Staking::new_async(self.channel.clone());
-                match x {
-                    StakingQuery::BondedDenom {} => {
-                        let query_result = handle
-                            .block_on(querier._params())
-                            .map(|result| {
-                                BondedDenomResponse::new(result.params.unwrap().bond_denom)
-                            })
-                            .map(|query_result| to_json_binary(&query_result))
-                            .unwrap();
-                        SystemResult::Ok(ContractResult::from(query_result))
+            WasmQuery::ContractInfo { contract_addr } => {
+                block_on(querier._contract_info(&Addr::unchecked(contract_addr)))
+                    .and_then(to_binary)
+            }
+            WasmQuery::CodeInfo { code_id } => {
+                block_on(querier._code(*code_id)).and_then(to_binary)
+            }
+            _ => return None,
+        };
+        Some(result)
+    }
+
+    fn bank_query(&self, query: &BankQuery) -> Option<Result<Binary, DaemonError>> {
+        let querier = Bank::new_async(self.channel.clone());
+        let result = match query {
+            BankQuery::Balance { address, denom } => {
+                block_on(querier._balance(&Addr::unchecked(address), Some(denom.clone())))
+                    .and_then(|balance| to_binary(BalanceResponse::new(balance[0].clone())))
+            }
+            #[allow(deprecated)]
+            BankQuery::AllBalances { address } => {
+                block_on(querier._balance(&Addr::unchecked(address), None))
+                    .and_then(|balances| to_binary(AllBalanceResponse::new(balances)))
+            }
+            BankQuery::Supply { denom } => block_on(querier._supply_of(denom))
+                .and_then(|supply| to_binary(SupplyResponse::new(supply))),
+            BankQuery::DenomMetadata { denom } => block_on(querier._denom_metadata(denom))
+                .and_then(|metadata| {
+                    to_binary(DenomMetadataResponse::new(to_cosmwasm_metadata(metadata)))
+                }),
+            BankQuery::AllDenomMetadata { pagination } => {
+                let pagination = pagination.as_ref().map(|pagination| PageRequest {
+                    key: pagination.key.clone().map(Into::into).unwrap_or_default(),
+                    limit: pagination.limit.into(),
+                    reverse: pagination.reverse,
+                    ..Default::default()
+                });
+                block_on(async {
+                    let response: cosmos_modules::bank::QueryDenomsMetadataResponse = cosmos_query!(
+                        querier,
+                        bank,
+                        denoms_metadata,
+                        QueryDenomsMetadataRequest {
+                            pagination: pagination
+                        }
+                    );
+                    Ok(response)
+                })
+                .and_then(|response| {
+                    to_binary(AllDenomMetadataResponse::new(
+                        response
+                            .metadatas
+                            .into_iter()
+                            .map(to_cosmwasm_metadata)
+                            .collect(),
+                        response
+                            .pagination
+                            .map(|pagination| pagination.next_key)
+                            .filter(|next_key| !next_key.is_empty())
+                            .map(Binary::from),
+                    ))
+                })
+            }
+            _ => return None,
+        };
+        Some(result)
+    }
+
+    fn staking_query(&self, query: &StakingQuery) -> Option<Result<Binary, DaemonError>> {
+        let querier = Staking::new_async(self.channel.clone());
+        let result = match query {
+            StakingQuery::BondedDenom {} => block_on(querier._params()).and_then(|result| {
+                to_binary(BondedDenomResponse::new(result.params.unwrap().bond_denom))
+            }),
+            StakingQuery::AllDelegations { delegator } => {
+                let delegator = Addr::unchecked(delegator);
+                block_on(paginate(|pagination| {
+                    let (querier, delegator) = (&querier, &delegator);
+                    async move {
+                        let response = querier
+                            ._delegator_delegations(delegator, pagination)
+                            .await?;
+                        Ok((response.delegation_responses, response.pagination))
                     }
-                    // This query is not perfect. I guess that on_chain you should be able to get ALL delegations and not a paginated result
-                    // TODO, do better here
-                    StakingQuery::AllDelegations { delegator } => {
-                        let query_result = handle
-                            .block_on(
-                                querier._delegator_delegations(&Addr::unchecked(delegator), None),
-                            )
-                            .map(|result| {
-                                AllDelegationsResponse::new(
-                                    result
-                                        .delegation_responses
-                                        .into_iter()
-                                        .filter_map(|delegation| {
-                                            delegation.delegation.map(|d| {
-                                                Delegation::new(
-                                                    Addr::unchecked(d.delegator_address),
-                                                    d.validator_address,
-                                                    to_cosmwasm_coin(delegation.balance.unwrap()),
-                                                )
-                                            })
-                                        })
-                                        .collect(),
-                                )
+                }))
+                .and_then(|delegations| {
+                    to_binary(AllDelegationsResponse::new(
+                        delegations
+                            .into_iter()
+                            .filter_map(|delegation| {
+                                delegation.delegation.map(|d| {
+                                    Delegation::new(
+                                        Addr::unchecked(d.delegator_address),
+                                        d.validator_address,
+                                        to_cosmwasm_coin(delegation.balance.unwrap()),
+                                    )
+                                })
                             })
-                            .map(|query_result| to_json_binary(&query_result))
-                            .unwrap();
-                        SystemResult::Ok(ContractResult::from(query_result))
-                    }
-                    _ => todo!(),
-                }
+                            .collect(),
+                    ))
+                })
             }
-            _ => SystemResult::Err(SystemError::InvalidRequest {
-                error: QUERIER_ERROR.to_string(),
-                request: to_json_binary(&request).unwrap(),
+            StakingQuery::Delegation {
+                delegator,
+                validator,
+            } => block_on(self.full_delegation(delegator, validator))
+                .and_then(|delegation| to_binary(DelegationResponse::new(delegation))),
+            StakingQuery::AllValidators {} => block_on(paginate(|pagination| {
+                let querier = &querier;
+                async move {
+                    let response: cosmos_modules::staking::QueryValidatorsResponse = cosmos_query!(
+                        querier,
+                        staking,
+                        validators,
+                        QueryValidatorsRequest {
+                            status: crate::queriers::StakingBondStatus::Bonded.to_string(),
+                            pagination: pagination,
+                        }
+                    );
+                    Ok((response.validators, response.pagination))
+                }
+            }))
+            .and_then(|validators| {
+                to_binary(AllValidatorsResponse::new(
+                    validators
+                        .into_iter()
+                        .map(cosmrs_to_cosmwasm_validator)
+                        .collect::<Result<_, _>>()?,
+                ))
             }),
-        }
+            StakingQuery::Validator { address } => block_on(not_found_as_none(
+                querier._validator(&Addr::unchecked(address)),
+            ))
+            .and_then(|validator| to_binary(ValidatorResponse::new(validator))),
+            _ => return None,
+        };
+        Some(result)
+    }
+
+    /// Delegation with its redelegation availability and accumulated rewards, like wasmd computes it
+    async fn full_delegation(
+        &self,
+        delegator: &str,
+        validator: &str,
+    ) -> Result<Option<FullDelegation>, DaemonError> {
+        let staking = Staking::new_async(self.channel.clone());
+        let distribution = Distribution::new_async(self.channel.clone());
+        let (delegator, validator) = (Addr::unchecked(delegator), Addr::unchecked(validator));
+
+        let Some(delegation) =
+            not_found_as_none(staking._delegation(&validator, &delegator)).await?
+        else {
+            return Ok(None);
+        };
+        // Funds redelegated to this validator can't be redelegated again until the redelegation completes
+        let empty = Addr::unchecked("");
+        let redelegations = paginate(|pagination| {
+            let (staking, delegator, empty) = (&staking, &delegator, &empty);
+            async move {
+                let response =
+                    not_found_as_none(staking._redelegations(delegator, empty, empty, pagination))
+                        .await?
+                        .unwrap_or_default();
+                Ok((response.redelegation_responses, response.pagination))
+            }
+        })
+        .await?;
+        let has_incoming_redelegation = redelegations.iter().any(|redelegation| {
+            redelegation
+                .redelegation
+                .as_ref()
+                .is_some_and(|r| r.validator_dst_address == validator.as_str())
+        });
+        let can_redelegate = if has_incoming_redelegation {
+            Coin::new(0u128, delegation.amount.denom.clone())
+        } else {
+            delegation.amount.clone()
+        };
+        let accumulated_rewards = distribution
+            ._delegation_rewards(&delegator, &validator)
+            .await?
+            .into_iter()
+            .map(dec_coin_to_coin)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Some(FullDelegation::new(
+            delegation.delegator,
+            delegation.validator,
+            delegation.amount,
+            can_redelegate,
+            accumulated_rewards,
+        )))
+    }
+
+    fn distribution_query(&self, query: &DistributionQuery) -> Option<Result<Binary, DaemonError>> {
+        let querier = Distribution::new_async(self.channel.clone());
+        let result = match query {
+            DistributionQuery::DelegatorWithdrawAddress { delegator_address } => {
+                block_on(querier._delegator_withdraw_address(&Addr::unchecked(delegator_address)))
+                    .and_then(|address| to_binary(DelegatorWithdrawAddressResponse::new(address)))
+            }
+            DistributionQuery::DelegationRewards {
+                delegator_address,
+                validator_address,
+            } => block_on(querier._delegation_rewards(
+                &Addr::unchecked(delegator_address),
+                &Addr::unchecked(validator_address),
+            ))
+            .and_then(|rewards| to_binary(DelegationRewardsResponse::new(rewards))),
+            DistributionQuery::DelegationTotalRewards { delegator_address } => {
+                block_on(querier._delegation_total_rewards(&Addr::unchecked(delegator_address)))
+                    .and_then(|response| {
+                        let rewards = response
+                            .rewards
+                            .into_iter()
+                            .map(|reward| {
+                                Ok(DelegatorReward::new(
+                                    reward.validator_address,
+                                    cosmrs_to_cosmwasm_dec_coins(reward.reward)?,
+                                ))
+                            })
+                            .collect::<Result<_, DaemonError>>()?;
+                        to_binary(DelegationTotalRewardsResponse::new(
+                            rewards,
+                            cosmrs_to_cosmwasm_dec_coins(response.total)?,
+                        ))
+                    })
+            }
+            DistributionQuery::DelegatorValidators { delegator_address } => {
+                block_on(querier._delegator_validators(&Addr::unchecked(delegator_address)))
+                    .and_then(|validators| to_binary(DelegatorValidatorsResponse::new(validators)))
+            }
+            _ => return None,
+        };
+        Some(result)
+    }
+
+    fn ibc_query(&self, query: &IbcQuery) -> Option<Result<Binary, DaemonError>> {
+        let result = match query {
+            IbcQuery::PortId {} => {
+                block_on(self.contract_port()).and_then(|port| to_binary(PortIdResponse::new(port)))
+            }
+            #[allow(deprecated)]
+            IbcQuery::ListChannels { port_id } => block_on(self.channels(port_id.clone()))
+                .and_then(|channels| to_binary(ListChannelsResponse::new(channels))),
+            IbcQuery::Channel {
+                channel_id,
+                port_id,
+            } => block_on(self.channel(channel_id, port_id.clone()))
+                .and_then(|channel| to_binary(ChannelResponse::new(channel))),
+            _ => return None,
+        };
+        Some(result)
+    }
+
+    /// IBC port of the contract set with [`WasmMockQuerier::set_contract_address`]
+    async fn contract_port(&self) -> Result<String, DaemonError> {
+        let contract_address = self.contract_address.as_ref().ok_or_else(|| {
+            DaemonError::ibc_err(
+                "IBC queries relative to the contract need a contract address, see `WasmMockQuerier::set_contract_address`",
+            )
+        })?;
+        CosmWasm::new_async(self.channel.clone())
+            ._contract_info(contract_address)
+            .await?
+            .ibc_port
+            .ok_or_else(|| DaemonError::ibc_err(format!("{contract_address} has no IBC port")))
+    }
+
+    /// Open channels bound to `port_id`, the contract's port by default
+    async fn channels(&self, port_id: Option<String>) -> Result<Vec<IbcChannel>, DaemonError> {
+        let port_id = match port_id {
+            Some(port_id) => port_id,
+            None => self.contract_port().await?,
+        };
+        let querier = Ibc::new_async(self.channel.clone());
+        // Channels are stored, and paginated, by port id. The pages after the port's channels aren't queried
+        let port_key = format!("{port_id}/");
+        let channels = paginate_while(
+            |pagination| {
+                let querier = &querier;
+                async move {
+                    let response: cosmos_modules::ibc_channel::QueryChannelsResponse = cosmos_query!(
+                        querier,
+                        ibc_channel,
+                        channels,
+                        QueryChannelsRequest {
+                            pagination: pagination
+                        }
+                    );
+                    Ok((response.channels, response.pagination))
+                }
+            },
+            |channel: &cosmos_modules::ibc_channel::IdentifiedChannel| {
+                format!("{}/", channel.port_id) <= port_key
+            },
+        )
+        .await?;
+        Ok(channels
+            .into_iter()
+            .filter(|channel| channel.port_id == port_id)
+            .filter_map(|channel| {
+                to_cosmwasm_channel(
+                    channel.port_id,
+                    channel.channel_id,
+                    cosmos_modules::ibc_channel::Channel {
+                        state: channel.state,
+                        ordering: channel.ordering,
+                        counterparty: channel.counterparty,
+                        connection_hops: channel.connection_hops,
+                        version: channel.version,
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect())
+    }
+
+    /// Open channel `channel_id` on `port_id`, the contract's port by default
+    async fn channel(
+        &self,
+        channel_id: &str,
+        port_id: Option<String>,
+    ) -> Result<Option<IbcChannel>, DaemonError> {
+        let port_id = match port_id {
+            Some(port_id) => port_id,
+            None => self.contract_port().await?,
+        };
+        let channel = not_found_as_none(
+            Ibc::new_async(self.channel.clone())._channel(port_id.clone(), channel_id),
+        )
+        .await?;
+        Ok(channel
+            .and_then(|channel| to_cosmwasm_channel(port_id, channel_id.to_string(), channel)))
+    }
+
+    /// Forwards a protobuf encoded query to the node and returns the protobuf encoded response
+    async fn grpc_query(&self, path: &str, data: &Binary) -> Result<Binary, DaemonError> {
+        let path = PathAndQuery::from_str(path)
+            .map_err(|e| DaemonError::StdErr(format!("invalid gRPC path {path}: {e}")))?;
+        let mut client = tonic::client::Grpc::new(self.channel.clone());
+        client.ready().await?;
+        let response = client
+            .unary(tonic::Request::new(data.to_vec()), path, RawCodec)
+            .await?;
+        Ok(response.into_inner().into())
     }
 }
 
@@ -198,18 +482,512 @@ impl WasmMockQuerier {
             ))
             .unwrap();
 
-        WasmMockQuerier { channel }
+        WasmMockQuerier {
+            channel,
+            contract_address: None,
+        }
+    }
+
+    /// Sets the contract the queries are made from.
+    /// IBC `PortId` queries and `ListChannels`/`Channel` queries without port id resolve to the port of this contract.
+    pub fn set_contract_address(&mut self, contract_address: impl Into<String>) {
+        self.contract_address = Some(Addr::unchecked(contract_address));
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    RUNTIME.handle().block_on(future)
+}
+
+fn to_binary(value: impl Serialize) -> Result<Binary, DaemonError> {
+    Ok(to_json_binary(&value)?)
+}
+
+/// Maps a `NotFound` gRPC error to `None`
+async fn not_found_as_none<T>(
+    query: impl Future<Output = Result<T, DaemonError>>,
+) -> Result<Option<T>, DaemonError> {
+    match query.await {
+        Ok(response) => Ok(Some(response)),
+        Err(DaemonError::Status(status)) if status.code() == tonic::Code::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Queries all the pages of a paginated query
+async fn paginate<T, F, Fut>(query: F) -> Result<Vec<T>, DaemonError>
+where
+    F: FnMut(Option<PageRequest>) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, Option<PageResponse>), DaemonError>>,
+{
+    paginate_while(query, |_| true).await
+}
+
+/// Queries the pages of a paginated query until the last item of a page doesn't satisfy `more`
+async fn paginate_while<T, F, Fut>(
+    mut query: F,
+    more: impl Fn(&T) -> bool,
+) -> Result<Vec<T>, DaemonError>
+where
+    F: FnMut(Option<PageRequest>) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, Option<PageResponse>), DaemonError>>,
+{
+    let mut items = vec![];
+    let mut next_key = None;
+    loop {
+        let pagination = next_key.map(|key| PageRequest {
+            key,
+            ..Default::default()
+        });
+        let (page, pagination) = query(pagination).await?;
+        items.extend(page);
+        if items.last().is_some_and(|item| !more(item)) {
+            return Ok(items);
+        }
+        match pagination {
+            Some(pagination) if !pagination.next_key.is_empty() => {
+                next_key = Some(pagination.next_key)
+            }
+            _ => return Ok(items),
+        }
+    }
+}
+
+fn to_cosmwasm_metadata(metadata: cosmos_modules::bank::Metadata) -> DenomMetadata {
+    DenomMetadata {
+        description: metadata.description,
+        denom_units: metadata
+            .denom_units
+            .into_iter()
+            .map(|unit| DenomUnit {
+                denom: unit.denom,
+                exponent: unit.exponent,
+                aliases: unit.aliases,
+            })
+            .collect(),
+        base: metadata.base,
+        display: metadata.display,
+        name: metadata.name,
+        symbol: metadata.symbol,
+        uri: metadata.uri,
+        uri_hash: metadata.uri_hash,
+    }
+}
+
+/// Converts an open channel, like wasmd only open channels are returned to contracts
+fn to_cosmwasm_channel(
+    port_id: String,
+    channel_id: String,
+    channel: cosmos_modules::ibc_channel::Channel,
+) -> Option<IbcChannel> {
+    use cosmos_modules::ibc_channel::{Order, State};
+
+    if channel.state() != State::Open {
+        return None;
+    }
+    let order = match channel.ordering() {
+        Order::Ordered => IbcOrder::Ordered,
+        _ => IbcOrder::Unordered,
+    };
+    let counterparty = channel.counterparty.unwrap_or_default();
+    Some(IbcChannel::new(
+        IbcEndpoint {
+            port_id,
+            channel_id,
+        },
+        IbcEndpoint {
+            port_id: counterparty.port_id,
+            channel_id: counterparty.channel_id,
+        },
+        order,
+        channel.version,
+        channel.connection_hops.first().cloned().unwrap_or_default(),
+    ))
+}
+
+/// Codec passing protobuf encoded messages through unchanged
+struct RawCodec;
+
+impl Codec for RawCodec {
+    type Encode = Vec<u8>;
+    type Decode = Vec<u8>;
+    type Encoder = RawCodec;
+    type Decoder = RawCodec;
+
+    fn encoder(&mut self) -> Self::Encoder {
+        RawCodec
+    }
+
+    fn decoder(&mut self) -> Self::Decoder {
+        RawCodec
+    }
+}
+
+impl Encoder for RawCodec {
+    type Item = Vec<u8>;
+    type Error = tonic::Status;
+
+    fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> Result<(), Self::Error> {
+        dst.put_slice(&item);
+        Ok(())
+    }
+}
+
+impl Decoder for RawCodec {
+    type Item = Vec<u8>;
+    type Error = tonic::Status;
+
+    fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(Some(src.copy_to_bytes(src.remaining()).to_vec()))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::{
+        convert::Infallible,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
 
     use super::*;
     use crate::networks::JUNO_1;
+    use cosmos_modules::{
+        cosmwasm::{ContractInfo, QueryContractInfoRequest, QueryContractInfoResponse},
+        ibc_channel::{
+            Counterparty, IdentifiedChannel, Order, QueryChannelRequest, QueryChannelResponse,
+            QueryChannelsRequest, QueryChannelsResponse, State,
+        },
+    };
+    use cosmwasm_std::{GrpcQuery, QuerierWrapper};
+    use hyper::body::Incoming;
+    use hyper_util::rt::{TokioExecutor, TokioIo};
+    use prost::Message;
+    use tonic::{body::BoxBody, codec::ProstCodec, server::Grpc, transport::Endpoint, Status};
 
     use super::mock_dependencies;
 
+    const CONTRACT: &str = "juno1contract";
+    const CONTRACT_PORT: &str = "wasm.juno1contract";
+
+    fn identified_channel(port_id: &str, channel_id: &str, state: State) -> IdentifiedChannel {
+        IdentifiedChannel {
+            state: state.into(),
+            ordering: Order::Unordered.into(),
+            counterparty: Some(Counterparty {
+                port_id: "transfer".to_string(),
+                channel_id: "channel-9".to_string(),
+            }),
+            connection_hops: vec!["connection-0".to_string()],
+            version: "ics20-1".to_string(),
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn channel_end(channel: &IdentifiedChannel) -> cosmos_modules::ibc_channel::Channel {
+        cosmos_modules::ibc_channel::Channel {
+            state: channel.state,
+            ordering: channel.ordering,
+            counterparty: channel.counterparty.clone(),
+            connection_hops: channel.connection_hops.clone(),
+            version: channel.version.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Node serving the contract info of [`CONTRACT`] and the IBC channel queries, one channel per page
+    #[derive(Clone)]
+    struct MockNode {
+        channels: Arc<Vec<IdentifiedChannel>>,
+        channel_pages: Arc<AtomicUsize>,
+    }
+
+    impl MockNode {
+        fn new(channels: Vec<IdentifiedChannel>) -> Self {
+            Self {
+                channels: Arc::new(channels),
+                channel_pages: Arc::default(),
+            }
+        }
+
+        fn querier(&self) -> WasmMockQuerier {
+            let node = self.clone();
+            let channel = RUNTIME
+                .block_on(
+                    Endpoint::from_static("http://mock.node").connect_with_connector(
+                        tower::service_fn(move |_: http::Uri| {
+                            let node = node.clone();
+                            async move {
+                                let (client, server) = tokio::io::duplex(1024 * 1024);
+                                tokio::spawn(node.serve(server));
+                                Ok::<_, std::io::Error>(TokioIo::new(client))
+                            }
+                        }),
+                    ),
+                )
+                .unwrap();
+            WasmMockQuerier {
+                channel,
+                contract_address: Some(Addr::unchecked(CONTRACT)),
+            }
+        }
+
+        async fn serve(self, io: tokio::io::DuplexStream) {
+            let service = hyper::service::service_fn(move |request: http::Request<Incoming>| {
+                let node = self.clone();
+                async move { Ok::<_, Infallible>(node.handle(request).await) }
+            });
+            let _ = hyper::server::conn::http2::Builder::new(TokioExecutor::new())
+                .serve_connection(TokioIo::new(io), service)
+                .await;
+        }
+
+        async fn handle(self, request: http::Request<Incoming>) -> http::Response<BoxBody> {
+            let path = request.uri().path().to_string();
+            match path.as_str() {
+                "/cosmwasm.wasm.v1.Query/ContractInfo" => {
+                    unary(request, |_: QueryContractInfoRequest| async {
+                        Ok::<_, Status>(QueryContractInfoResponse {
+                            address: CONTRACT.to_string(),
+                            contract_info: Some(ContractInfo {
+                                code_id: 1,
+                                creator: "juno1creator".to_string(),
+                                ibc_port_id: CONTRACT_PORT.to_string(),
+                                ..Default::default()
+                            }),
+                        })
+                    })
+                    .await
+                }
+                "/ibc.core.channel.v1.Query/Channels" => {
+                    unary(request, move |request: QueryChannelsRequest| {
+                        let response = self.channels_page(request);
+                        async move { Ok::<_, Status>(response) }
+                    })
+                    .await
+                }
+                "/ibc.core.channel.v1.Query/Channel" => {
+                    unary(request, move |request: QueryChannelRequest| {
+                        let response = self.channel(request);
+                        async move { response }
+                    })
+                    .await
+                }
+                _ => Status::unimplemented(path).into_http(),
+            }
+        }
+
+        fn channels_page(&self, request: QueryChannelsRequest) -> QueryChannelsResponse {
+            self.channel_pages.fetch_add(1, Ordering::SeqCst);
+            let index = request
+                .pagination
+                .map(|pagination| String::from_utf8(pagination.key).unwrap().parse().unwrap())
+                .unwrap_or(0usize);
+            let next_key = if index + 1 < self.channels.len() {
+                (index + 1).to_string().into_bytes()
+            } else {
+                vec![]
+            };
+            QueryChannelsResponse {
+                channels: self.channels[index..=index].to_vec(),
+                pagination: Some(PageResponse { next_key, total: 0 }),
+                ..Default::default()
+            }
+        }
+
+        fn channel(&self, request: QueryChannelRequest) -> Result<QueryChannelResponse, Status> {
+            self.channels
+                .iter()
+                .find(|channel| {
+                    channel.port_id == request.port_id && channel.channel_id == request.channel_id
+                })
+                .map(|channel| QueryChannelResponse {
+                    channel: Some(channel_end(channel)),
+                    ..Default::default()
+                })
+                .ok_or_else(|| Status::not_found("channel not found"))
+        }
+    }
+
+    async fn unary<Req, Resp, F, Fut>(
+        request: http::Request<Incoming>,
+        handler: F,
+    ) -> http::Response<BoxBody>
+    where
+        Req: prost::Message + Default + Send + 'static,
+        Resp: prost::Message + Send + 'static,
+        F: Fn(Req) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Resp, Status>> + Send + 'static,
+    {
+        let service = tower::service_fn(move |request: tonic::Request<Req>| {
+            let response = handler(request.into_inner());
+            async move { response.await.map(tonic::Response::new) }
+        });
+        Grpc::new(ProstCodec::<Resp, Req>::default())
+            .unary(service, request)
+            .await
+    }
+
+    /// Channels sorted by port like the IBC store, with the channels of the contract port in between
+    fn node() -> MockNode {
+        MockNode::new(vec![
+            identified_channel("transfer", "channel-0", State::Open),
+            identified_channel(CONTRACT_PORT, "channel-1", State::Open),
+            identified_channel(CONTRACT_PORT, "channel-2", State::Closed),
+            identified_channel(CONTRACT_PORT, "channel-3", State::Open),
+            identified_channel("wasm.juno1other", "channel-4", State::Open),
+            identified_channel("wasm.juno1zzz", "channel-5", State::Open),
+        ])
+    }
+
+    #[test]
+    fn ibc_queries() -> anyhow::Result<()> {
+        let node = node();
+        let querier = node.querier();
+        let querier = QuerierWrapper::<Empty>::new(&querier);
+
+        let port: PortIdResponse = querier.query(&QueryRequest::Ibc(IbcQuery::PortId {}))?;
+        assert_eq!(port.port_id, CONTRACT_PORT);
+
+        #[allow(deprecated)]
+        let channels: ListChannelsResponse =
+            querier.query(&QueryRequest::Ibc(IbcQuery::ListChannels { port_id: None }))?;
+        let channel_ids = channels
+            .channels
+            .iter()
+            .map(|channel| channel.endpoint.channel_id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(channel_ids, ["channel-1", "channel-3"]);
+        // The page after the first channel of a following port isn't queried
+        assert_eq!(node.channel_pages.load(Ordering::SeqCst), 5);
+
+        let channel: ChannelResponse = querier.query(&QueryRequest::Ibc(IbcQuery::Channel {
+            channel_id: "channel-1".to_string(),
+            port_id: None,
+        }))?;
+        assert_eq!(
+            channel.channel.unwrap().counterparty_endpoint.port_id,
+            "transfer"
+        );
+        for channel_id in ["channel-2", "channel-9"] {
+            let channel: ChannelResponse =
+                querier.query(&QueryRequest::Ibc(IbcQuery::Channel {
+                    channel_id: channel_id.to_string(),
+                    port_id: Some(CONTRACT_PORT.to_string()),
+                }))?;
+            assert_eq!(channel.channel, None);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn grpc_query_forwards_raw_bytes() -> anyhow::Result<()> {
+        let querier = node().querier();
+        let request = QueryChannelRequest {
+            port_id: "transfer".to_string(),
+            channel_id: "channel-0".to_string(),
+        };
+
+        let response = QuerierWrapper::<Empty>::new(&querier).query_grpc(
+            "/ibc.core.channel.v1.Query/Channel".to_string(),
+            request.encode_to_vec().into(),
+        )?;
+        let response = QueryChannelResponse::decode(response.as_slice())?;
+        assert_eq!(
+            response.channel,
+            Some(channel_end(&identified_channel(
+                "transfer",
+                "channel-0",
+                State::Open
+            )))
+        );
+
+        let result = querier.handle_query(&QueryRequest::Grpc(GrpcQuery {
+            path: "/ibc.core.channel.v1.Query/Unknown".to_string(),
+            data: Binary::default(),
+        }));
+        assert!(matches!(result, SystemResult::Ok(ContractResult::Err(_))));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn paginate_queries_every_page() -> anyhow::Result<()> {
+        let pages = [vec![1, 2], vec![3], vec![4, 5]];
+        let query = |pagination: Option<PageRequest>| {
+            let index = pagination.map_or(0, |pagination| pagination.key[0] as usize);
+            let next_key = if index + 1 < pages.len() {
+                vec![index as u8 + 1]
+            } else {
+                vec![]
+            };
+            let page = pages[index].clone();
+            async move { Ok::<_, DaemonError>((page, Some(PageResponse { next_key, total: 0 }))) }
+        };
+
+        assert_eq!(paginate(query).await?, [1, 2, 3, 4, 5]);
+        // Stops after the page whose last item doesn't satisfy the condition
+        assert_eq!(paginate_while(query, |item| *item < 3).await?, [1, 2, 3]);
+        // Without pagination in the response there is a single page
+        let items = paginate(|_| async { Ok::<_, DaemonError>((vec![1], None)) }).await?;
+        assert_eq!(items, [1]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn not_found_errors_are_none() {
+        assert_eq!(not_found_as_none(async { Ok(1) }).await.unwrap(), Some(1));
+        assert_eq!(
+            not_found_as_none(async { Err::<u64, _>(Status::not_found("").into()) })
+                .await
+                .unwrap(),
+            None
+        );
+        assert!(
+            not_found_as_none(async { Err::<u64, _>(Status::internal("").into()) })
+                .await
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn only_open_channels_are_converted() {
+        let mut channel = channel_end(&identified_channel(CONTRACT_PORT, "channel-1", State::Open));
+        channel.ordering = Order::Ordered.into();
+
+        let converted = to_cosmwasm_channel(
+            CONTRACT_PORT.to_string(),
+            "channel-1".to_string(),
+            channel.clone(),
+        )
+        .unwrap();
+        assert_eq!(
+            converted,
+            IbcChannel::new(
+                IbcEndpoint {
+                    port_id: CONTRACT_PORT.to_string(),
+                    channel_id: "channel-1".to_string(),
+                },
+                IbcEndpoint {
+                    port_id: "transfer".to_string(),
+                    channel_id: "channel-9".to_string(),
+                },
+                IbcOrder::Ordered,
+                "ics20-1",
+                "connection-0",
+            )
+        );
+
+        channel.state = State::Tryopen.into();
+        assert_eq!(
+            to_cosmwasm_channel(CONTRACT_PORT.to_string(), "channel-1".to_string(), channel),
+            None
+        );
+    }
+
     #[test]
     fn bank_balance_querier() -> Result<(), anyhow::Error> {
         let address = "juno1rkhrfuq7k2k68k0hctrmv8efyxul6tgn8hny6y";
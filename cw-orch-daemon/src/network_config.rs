@@ -1,29 +1,152 @@
-use std::collections::HashMap;
+//! Layered network configuration.
+//!
+//! The config of a chain id is merged from the following `networks.toml` files, each layer overwriting the previous ones:
+//! 1. `~/.cw-orchestrator/networks.toml`
+//! 2. The first `networks.toml` found walking up from the current directory
+//! 3. The file at the `CW_ORCH_NETWORKS_CONFIG` env variable
+//!
+//! The daemon builder applies its programmatic overrides ([`DaemonAsyncBuilder::network_overrides`](crate::DaemonAsyncBuilder::network_overrides)) last.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
 
 use cw_orch_core::environment::ChainInfoOwned;
+use cw_orch_networks::networks::parse_network;
+
+use crate::{
+    env::{default_state_folder, DaemonEnvVars, NETWORKS_CONFIG_ENV_NAME},
+    DaemonError,
+};
+
+/// Name of the network config files
+pub const NETWORKS_CONFIG_FILE: &str = "networks.toml";
+
+/// Existing network config files, from the lowest to the highest priority
+pub fn network_config_files() -> Result<Vec<PathBuf>, DaemonError> {
+    let mut files = vec![];
+    if let Ok(state_folder) = default_state_folder() {
+        let global = state_folder.join(NETWORKS_CONFIG_FILE);
+        if global.is_file() {
+            files.push(global);
+        }
+    }
+
+    let current_dir = std::env::current_dir()?;
+    let local = current_dir
+        .ancestors()
+        .map(|dir| dir.join(NETWORKS_CONFIG_FILE))
+        .find(|path| path.is_file());
+    if let Some(local) = local {
+        if !files.contains(&local) {
+            files.push(local);
+        }
+    }
+
+    if let Some(env_file) = DaemonEnvVars::networks_config() {
+        if !env_file.is_file() {
+            return Err(network_config_error(
+                &env_file,
+                format!("file set by {NETWORKS_CONFIG_ENV_NAME} not found"),
+            ));
+        }
+        files.push(env_file);
+    }
+    Ok(files)
+}
+
+/// Parses and validates a network config file, indexed by chain id
+pub fn parse_network_config_file(
+    path: &Path,
+) -> Result<BTreeMap<String, ChainInfoOwned>, DaemonError> {
+    let content = std::fs::read_to_string(path)?;
+    let table: toml::Table = toml::from_str(&content).map_err(|e| network_config_error(path, e))?;
+    table
+        .into_iter()
+        .map(|(chain_id, value)| {
+            let chain_info = parse_chain_config(value)
+                .map_err(|reason| network_config_error(path, format!("[{chain_id}] {reason}")))?;
+            Ok((chain_id, chain_info))
+        })
+        .collect()
+}
 
-use crate::env::default_state_folder;
+fn parse_chain_config(value: toml::Value) -> Result<ChainInfoOwned, String> {
+    let chain_info: ChainInfoOwned = value.try_into().map_err(|e| e.to_string())?;
+    if chain_info.gas_price < 0.0 {
+        return Err(format!("negative gas_price {}", chain_info.gas_price));
+    }
+    if let Some(url) = chain_info
+        .grpc_urls
+        .iter()
+        .find(|url| !url.starts_with("http://") && !url.starts_with("https://"))
+    {
+        return Err(format!(
+            "grpc url {url} must start with http:// or https://"
+        ));
+    }
+    Ok(chain_info)
+}
 
-/// Reads network config from `~/.cw-orchestrator/networks.toml` and returns the config for the chain id
-pub fn read_network_config(chain_id: &str) -> Option<ChainInfoOwned> {
-    let mut state_folder = default_state_folder().ok()?;
-    state_folder.push("networks.toml");
-    let file_content = std::fs::read_to_string(state_folder).ok()?;
+fn network_config_error(path: &Path, reason: impl Display) -> DaemonError {
+    DaemonError::NetworkConfig {
+        path: path.display().to_string(),
+        reason: reason.to_string(),
+    }
+}
 
-    let mut network_config: HashMap<String, ChainInfoOwned> = toml::from_str(&file_content).ok()?;
-    network_config.remove(chain_id)
+/// Reads the network config of a chain id, merged from all the [network config files](network_config_files).
+/// Returns `None` if no file configures the chain id.
+pub fn read_network_config(chain_id: &str) -> Result<Option<ChainInfoOwned>, DaemonError> {
+    let mut network_config: Option<ChainInfoOwned> = None;
+    for path in network_config_files()? {
+        if let Some(layer) = parse_network_config_file(&path)?.remove(chain_id) {
+            network_config = Some(match network_config {
+                Some(network_config) => network_config.overwrite_with(layer),
+                None => layer,
+            });
+        }
+    }
+    Ok(network_config)
+}
+
+/// Effective chain info of a chain id: the cw-orch network with this chain id (if any), overwritten by the network config files
+pub fn effective_network_config(chain_id: &str) -> Result<ChainInfoOwned, DaemonError> {
+    let chain_info = parse_network(chain_id)
+        .map(ChainInfoOwned::from)
+        .unwrap_or_else(|_| ChainInfoOwned::config(chain_id.to_string()));
+    Ok(match read_network_config(chain_id)? {
+        Some(network_config) => chain_info.overwrite_with(network_config),
+        None => chain_info,
+    })
+}
+
+/// [`effective_network_config`] as a `networks.toml` entry, preceded by the config files it's merged from
+pub fn dump_network_config(chain_id: &str) -> Result<String, DaemonError> {
+    let chain_info = effective_network_config(chain_id)?;
+    let mut dump = String::new();
+    for path in network_config_files()? {
+        dump.push_str(&format!("# {}\n", path.display()));
+    }
+    let entry = toml::to_string(&BTreeMap::from([(chain_id, chain_info)]))
+        .map_err(|e| DaemonError::StdErr(e.to_string()))?;
+    dump.push_str(&entry);
+    Ok(dump)
 }
 
 #[cfg(test)]
 mod test {
-    use super::read_network_config;
+    use super::{dump_network_config, parse_network_config_file, read_network_config};
 
     use crate::{
+        env::NETWORKS_CONFIG_ENV_NAME,
         networks::{JUNO_1, NEUTRON_1},
-        Daemon,
+        Daemon, DaemonError,
     };
     use cw_orch::{
-        environment::{ChainKind, NetworkInfoOwned},
+        environment::{ChainKind, KeyAlgorithm, KeyAlgorithmOwned, NetworkInfoOwned},
         prelude::{ChainInfo, ChainInfoOwned},
     };
 
@@ -31,7 +154,7 @@ mod test {
     #[ignore = "This test is for testing config in CI"]
     fn existing_network_config() {
         let chain_info = ChainInfoOwned::from(NEUTRON_1)
-            .overwrite_with(read_network_config(JUNO_1.chain_id).unwrap());
+            .overwrite_with(read_network_config(JUNO_1.chain_id).unwrap().unwrap());
         let expected_chain_info = ChainInfoOwned {
             chain_id: "joono-1".to_owned(),
             gas_denom: "gas_denom".to_owned(),
@@ -43,6 +166,7 @@ mod test {
                 chain_name: "joono".to_owned(),
                 pub_address_prefix: "joo".to_owned(),
                 coin_type: 42,
+                ..Default::default()
             },
             kind: cw_orch::environment::ChainKind::Local,
         };
@@ -55,7 +179,7 @@ mod test {
     #[ignore = "This test is for testing config in CI"]
     fn existing_network_partial_config() {
         let chain_info = ChainInfoOwned::from(NEUTRON_1)
-            .overwrite_with(read_network_config(NEUTRON_1.chain_id).unwrap());
+            .overwrite_with(read_network_config(NEUTRON_1.chain_id).unwrap().unwrap());
         let expected_chain_info = ChainInfoOwned {
             gas_price: 1.23f64,
             ..NEUTRON_1.into()
@@ -70,7 +194,7 @@ mod test {
     #[serial_test::serial]
     #[ignore = "This test is for testing config in CI"]
     fn missing_network_full_config() {
-        let chain_info = read_network_config("abstr-1").unwrap();
+        let chain_info = read_network_config("abstr-1").unwrap().unwrap();
         let expected_chain_info = ChainInfoOwned {
             kind: ChainKind::Mainnet,
             chain_id: "juno-1".to_owned(),
@@ -81,6 +205,7 @@ mod test {
                 chain_name: "juno".to_owned(),
                 pub_address_prefix: "juno".to_owned(),
                 coin_type: 118u32,
+                ..Default::default()
             },
             lcd_url: None,
             fcd_url: None,
@@ -93,4 +218,113 @@ mod test {
             .unwrap();
         assert_eq!(daemon_sender.chain_info().clone(), expected_chain_info);
     }
+
+    #[test]
+    #[serial_test::serial]
+    fn env_network_config_layer() -> anyhow::Result<()> {
+        let path = crate::gen_temp_file_path();
+        std::fs::write(
+            &path,
+            r#"
+            [layered-1]
+            gas_price = 0.5
+            grpc_urls = ["https://layered-grpc.com:443"]
+
+            [layered-1.network_info]
+            pub_address_prefix = "layer"
+            "#,
+        )?;
+        std::env::set_var(NETWORKS_CONFIG_ENV_NAME, &path);
+
+        let chain_info = read_network_config("layered-1");
+        let dump = dump_network_config("layered-1");
+        std::env::remove_var(NETWORKS_CONFIG_ENV_NAME);
+        std::fs::remove_file(path)?;
+
+        let chain_info = chain_info?.unwrap();
+        assert_eq!(chain_info.gas_price, 0.5);
+        assert_eq!(chain_info.grpc_urls, vec!["https://layered-grpc.com:443"]);
+        assert_eq!(chain_info.network_info.pub_address_prefix, "layer");
+
+        let dump = dump?;
+        assert!(dump.contains("[layered-1]"));
+        assert!(dump.contains("chain_id = \"layered-1\""));
+        assert!(dump.contains("gas_price = 0.5"));
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_network_config() -> anyhow::Result<()> {
+        let path = crate::gen_temp_file_path();
+        let reason = |content: &str| -> anyhow::Result<String> {
+            std::fs::write(&path, content)?;
+            match parse_network_config_file(&path) {
+                Err(DaemonError::NetworkConfig { reason, .. }) => Ok(reason),
+                other => panic!("expected a network config error, got {other:?}"),
+            }
+        };
+
+        // Syntax errors
+        assert!(!reason("[juno-1\ngas_price = 1")?.is_empty());
+        assert_eq!(
+            reason("[juno-1]\ngas_prices = 1.0")?
+                .split(',')
+                .next()
+                .unwrap(),
+            "[juno-1] unknown field `gas_prices`"
+        );
+        assert!(reason("[juno-1]\ngas_price = \"cheap\"")?.starts_with("[juno-1]"));
+        assert_eq!(
+            reason("[juno-1]\ngrpc_urls = [\"juno-grpc.com:9090\"]")?,
+            "[juno-1] grpc url juno-grpc.com:9090 must start with http:// or https://"
+        );
+        assert_eq!(
+            reason("[juno-1]\ngas_price = -1.0")?,
+            "[juno-1] negative gas_price -1"
+        );
+
+        std::fs::write(&path, "[juno-1]\ngas_price = 1.0")?;
+        assert_eq!(parse_network_config_file(&path)?["juno-1"].gas_price, 1.0);
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn inferred_key_algorithm() -> anyhow::Result<()> {
+        let path = crate::gen_temp_file_path();
+        std::fs::write(
+            &path,
+            r#"
+            [evmos_9001-2.network_info]
+            pub_address_prefix = "evmos"
+            coin_type = 60
+
+            [injective-1.network_info]
+            pub_address_prefix = "inj"
+            coin_type = 60
+
+            [juno-1.network_info]
+            coin_type = 60
+            key_algorithm = "secp256k1"
+            "#,
+        )?;
+        let config = parse_network_config_file(&path);
+        std::fs::remove_file(path)?;
+        let config = config?;
+
+        assert_eq!(
+            config["evmos_9001-2"].network_info.key_algorithm,
+            KeyAlgorithm::ETHERMINT.into()
+        );
+        assert_eq!(
+            config["injective-1"].network_info.key_algorithm,
+            KeyAlgorithm::INJECTIVE.into()
+        );
+        // An explicit key algorithm is kept
+        assert_eq!(
+            config["juno-1"].network_info.key_algorithm,
+            KeyAlgorithmOwned::Secp256k1
+        );
+        Ok(())
+    }
 }
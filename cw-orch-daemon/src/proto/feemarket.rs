@@ -0,0 +1,18 @@
+#![allow(missing_docs)]
+
+use cosmrs::proto::cosmos::base::v1beta1::DecCoin;
+
+/// gRPC path of the Skip `x/feemarket` gas price query
+pub const GAS_PRICE_PATH: &str = "/feemarket.feemarket.v1.Query/GasPrice";
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GasPriceRequest {
+    #[prost(string, tag = "1")]
+    pub denom: String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GasPriceResponse {
+    #[prost(message, optional, tag = "1")]
+    pub price: ::core::option::Option<DecCoin>,
+}
@@ -11,8 +11,6 @@ use crate::keys::private::PrivateKey;
 #[cfg(feature = "eth")]
 use ::{cosmrs::proto, ethers_core::utils::keccak256};
 
-pub const ETHEREUM_COIN_TYPE: u32 = 60;
-
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InjectiveEthAccount {
     #[prost(message, optional, tag = "1")]
@@ -0,0 +1,21 @@
+#![allow(missing_docs)]
+
+use cosmrs::Any;
+
+/// Key record of the Cosmos SDK keyring (v0.46+)
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Record {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub pub_key: ::core::option::Option<Any>,
+    /// Only local keys hold a private key, the other items of the `oneof` are not supported
+    #[prost(message, optional, tag = "3")]
+    pub local: ::core::option::Option<Local>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Local {
+    #[prost(message, optional, tag = "1")]
+    pub priv_key: ::core::option::Option<Any>,
+}
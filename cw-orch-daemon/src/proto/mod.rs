@@ -1 +1,6 @@
+pub mod feemarket;
 pub mod injective;
+#[cfg(feature = "keystore")]
+pub mod keyring;
+pub mod osmosis;
+pub mod tx;
@@ -0,0 +1,14 @@
+#![allow(missing_docs)]
+
+/// gRPC path of the Osmosis `txfees` EIP-1559 base fee query
+pub const EIP_BASE_FEE_PATH: &str = "/osmosis.txfees.v1beta1.Query/GetEipBaseFee";
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryEipBaseFeeRequest {}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryEipBaseFeeResponse {
+    /// `LegacyDec` in its protobuf encoding
+    #[prost(string, tag = "1")]
+    pub base_fee: String,
+}
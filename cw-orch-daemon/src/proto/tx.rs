@@ -0,0 +1,66 @@
+#![allow(missing_docs)]
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use cosmrs::{proto::tendermint::google::protobuf::Timestamp, tx::Body, Any};
+
+/// `TxBody` of Cosmos SDK 0.53, which adds unordered transactions
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TxBody {
+    #[prost(message, repeated, tag = "1")]
+    pub messages: ::prost::alloc::vec::Vec<Any>,
+    #[prost(string, tag = "2")]
+    pub memo: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "3")]
+    pub timeout_height: u64,
+    #[prost(bool, tag = "4")]
+    pub unordered: bool,
+    #[prost(message, optional, tag = "5")]
+    pub timeout_timestamp: ::core::option::Option<Timestamp>,
+    #[prost(message, repeated, tag = "1023")]
+    pub extension_options: ::prost::alloc::vec::Vec<Any>,
+    #[prost(message, repeated, tag = "2047")]
+    pub non_critical_extension_options: ::prost::alloc::vec::Vec<Any>,
+}
+
+impl TxBody {
+    /// Unordered version of `body`, valid until `timeout_timestamp`
+    pub fn unordered(body: &Body, timeout_timestamp: SystemTime) -> Self {
+        let timeout = timeout_timestamp
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        Self {
+            messages: body.messages.clone(),
+            memo: body.memo.clone(),
+            timeout_height: body.timeout_height.value(),
+            unordered: true,
+            timeout_timestamp: Some(Timestamp {
+                seconds: timeout.as_secs() as i64,
+                nanos: timeout.subsec_nanos() as i32,
+            }),
+            extension_options: body.extension_options.clone(),
+            non_critical_extension_options: body.non_critical_extension_options.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+
+    #[test]
+    fn ordered_fields_match_sdk_body() {
+        let body = Body::new(vec![], "memo", 42u32);
+        let unordered = TxBody::unordered(&body, UNIX_EPOCH);
+
+        // Without the unordered fields, the encoding is the one of the Cosmos SDK `TxBody`
+        let ordered = TxBody {
+            unordered: false,
+            timeout_timestamp: None,
+            ..unordered.clone()
+        };
+        assert_eq!(ordered.encode_to_vec(), body.into_bytes().unwrap());
+        assert!(unordered.unordered);
+    }
+}
@@ -22,9 +22,13 @@
 //! ```
 
 /// macro for constructing and performing a query on a CosmosSDK module.
+/// An optional trailing `Option<u64>` block height scopes the query to that height.
 #[macro_export]
 macro_rules! cosmos_query {
     ($self:ident, $module:ident, $func_name:ident, $request_type:ident { $($field:ident : $value:expr),* $(,)?  }) => {
+        $crate::cosmos_query!($self, $module, $func_name, $request_type { $($field : $value),* }, None)
+    };
+    ($self:ident, $module:ident, $func_name:ident, $request_type:ident { $($field:ident : $value:expr),* $(,)?  }, $height:expr) => {
         {
         use $crate::cosmos_modules::$module::{
             query_client::QueryClient, $request_type,
@@ -32,7 +36,10 @@ macro_rules! cosmos_query {
         let mut client = QueryClient::new($self.channel.clone());
         #[allow(clippy::redundant_field_names)]
         let request = $request_type { $($field : $value),* };
-        let response = client.$func_name(request.clone()).await?.into_inner();
+        let response = client
+            .$func_name($crate::queriers::request_at_height(request.clone(), $height))
+            .await?
+            .into_inner();
         ::log::trace!(
             "cosmos_query: {:?} resulted in: {:?}",
             request,
@@ -43,22 +50,47 @@ macro_rules! cosmos_query {
 };
 }
 
+/// gRPC metadata key used by Cosmos SDK nodes to serve a query from the state at a given block height
+pub const BLOCK_HEIGHT_METADATA: &str = "x-cosmos-block-height";
+
+/// Wraps a query message in a gRPC request, resolved at `height` if provided, at the latest height otherwise.
+/// The node needs to have the state at that height available (i.e. not pruned).
+pub fn request_at_height<T>(message: T, height: Option<u64>) -> tonic::Request<T> {
+    let mut request = tonic::Request::new(message);
+    if let Some(height) = height {
+        request
+            .metadata_mut()
+            .insert(BLOCK_HEIGHT_METADATA, height.into());
+    }
+    request
+}
+
 mod authz;
 mod bank;
 mod cosmwasm;
+mod distribution;
 mod env;
 mod feegrant;
 mod gov;
 mod ibc;
+mod mint;
 mod node;
+mod slashing;
 mod staking;
+mod upgrade;
 
 pub use authz::Authz;
 pub use bank::{cosmrs_to_cosmwasm_coins, Bank};
 pub use cosmwasm::{CosmWasm, CosmWasmBase};
+pub use distribution::{
+    cosmrs_to_cosmwasm_dec_coin, cosmrs_to_cosmwasm_dec_coins, parse_legacy_dec, Distribution,
+};
 pub use feegrant::FeeGrant;
 pub use ibc::Ibc;
+pub use mint::Mint;
 pub use node::Node;
+pub use slashing::Slashing;
+pub use upgrade::Upgrade;
 
 // this two containt structs that are helpers for the queries
 pub use gov::*;
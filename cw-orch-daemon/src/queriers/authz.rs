@@ -1,4 +1,4 @@
-use crate::{cosmos_modules, error::DaemonError, Daemon};
+use crate::{cosmos_modules, error::DaemonError, queriers::request_at_height, Daemon};
 use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
 use cosmwasm_std::Addr;
 use cw_orch_core::environment::{Querier, QuerierGetter};
@@ -10,6 +10,8 @@ use tonic::transport::Channel;
 pub struct Authz {
     pub channel: Channel,
     pub rt_handle: Option<Handle>,
+    /// Block height the queries are resolved at, latest if `None`
+    pub height: Option<u64>,
 }
 
 impl Authz {
@@ -17,6 +19,7 @@ impl Authz {
         Self {
             channel: daemon.channel(),
             rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
         }
     }
 
@@ -24,8 +27,15 @@ impl Authz {
         Self {
             channel,
             rt_handle: None,
+            height: None,
         }
     }
+
+    /// Resolve the queries at the given block height
+    pub fn at_height(mut self, height: u64) -> Self {
+        self.height = Some(height);
+        self
+    }
 }
 
 impl Querier for Authz {
@@ -50,12 +60,15 @@ impl Authz {
         use cosmos_modules::authz::{query_client::QueryClient, QueryGrantsRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
         let grants = client
-            .grants(QueryGrantsRequest {
-                granter: granter.to_string(),
-                grantee: grantee.to_string(),
-                msg_type_url,
-                pagination,
-            })
+            .grants(request_at_height(
+                QueryGrantsRequest {
+                    granter: granter.to_string(),
+                    grantee: grantee.to_string(),
+                    msg_type_url,
+                    pagination,
+                },
+                self.height,
+            ))
             .await?
             .into_inner();
         Ok(grants)
@@ -71,10 +84,13 @@ impl Authz {
         use cosmos_modules::authz::{query_client::QueryClient, QueryGranteeGrantsRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
         let grants = client
-            .grantee_grants(QueryGranteeGrantsRequest {
-                grantee: grantee.to_string(),
-                pagination,
-            })
+            .grantee_grants(request_at_height(
+                QueryGranteeGrantsRequest {
+                    grantee: grantee.to_string(),
+                    pagination,
+                },
+                self.height,
+            ))
             .await?
             .into_inner();
         Ok(grants)
@@ -90,10 +106,13 @@ impl Authz {
         use cosmos_modules::authz::{query_client::QueryClient, QueryGranterGrantsRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
         let grants = client
-            .granter_grants(QueryGranterGrantsRequest {
-                granter: granter.to_string(),
-                pagination,
-            })
+            .granter_grants(request_at_height(
+                QueryGranterGrantsRequest {
+                    granter: granter.to_string(),
+                    pagination,
+                },
+                self.height,
+            ))
             .await?
             .into_inner();
         Ok(grants)
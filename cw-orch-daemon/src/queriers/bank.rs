@@ -1,4 +1,7 @@
-use crate::{cosmos_modules, error::DaemonError, senders::query::QuerySender, DaemonBase};
+use crate::{
+    cosmos_modules, error::DaemonError, queriers::request_at_height, senders::query::QuerySender,
+    DaemonBase,
+};
 use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
 use cosmwasm_std::{Addr, Coin, StdError};
 use cw_orch_core::environment::{BankQuerier, Querier, QuerierGetter};
@@ -10,6 +13,8 @@ use tonic::transport::Channel;
 pub struct Bank {
     pub channel: Channel,
     pub rt_handle: Option<Handle>,
+    /// Block height the queries are resolved at, latest if `None`
+    pub height: Option<u64>,
 }
 
 impl Bank {
@@ -17,14 +22,22 @@ impl Bank {
         Self {
             channel: daemon.channel(),
             rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
         }
     }
     pub fn new_async(channel: Channel) -> Self {
         Self {
             channel,
             rt_handle: None,
+            height: None,
         }
     }
+
+    /// Resolve the queries at the given block height
+    pub fn at_height(mut self, height: u64) -> Self {
+        self.height = Some(height);
+        self
+    }
 }
 
 impl Querier for Bank {
@@ -53,7 +66,10 @@ impl Bank {
                     address: address.to_string(),
                     denom,
                 };
-                let resp = client.balance(request).await?.into_inner();
+                let resp = client
+                    .balance(request_at_height(request, self.height))
+                    .await?
+                    .into_inner();
                 let coin = resp.balance.unwrap();
                 Ok(vec![cosmrs_to_cosmwasm_coin(coin)?])
             }
@@ -63,7 +79,10 @@ impl Bank {
                     address: address.to_string(),
                     ..Default::default()
                 };
-                let resp = client.all_balances(request).await?.into_inner();
+                let resp = client
+                    .all_balances(request_at_height(request, self.height))
+                    .await?
+                    .into_inner();
                 Ok(cosmrs_to_cosmwasm_coins(resp.balances)?)
             }
         }
@@ -78,7 +97,8 @@ impl Bank {
             QuerySpendableBalancesRequest {
                 address: address.to_string(),
                 pagination: None,
-            }
+            },
+            self.height
         );
         Ok(cosmrs_to_cosmwasm_coins(spendable_balances.balances)?)
     }
@@ -89,7 +109,8 @@ impl Bank {
             self,
             bank,
             total_supply,
-            QueryTotalSupplyRequest { pagination: None }
+            QueryTotalSupplyRequest { pagination: None },
+            self.height
         );
         Ok(cosmrs_to_cosmwasm_coins(total_supply.supply)?)
     }
@@ -102,7 +123,8 @@ impl Bank {
             supply_of,
             QuerySupplyOfRequest {
                 denom: denom.into()
-            }
+            },
+            self.height
         );
         Ok(cosmrs_to_cosmwasm_coin(supply_of.amount.unwrap())?)
     }
@@ -110,7 +132,7 @@ impl Bank {
     /// Query params
     pub async fn _params(&self) -> Result<cosmos_modules::bank::Params, DaemonError> {
         let params: cosmos_modules::bank::QueryParamsResponse =
-            cosmos_query!(self, bank, params, QueryParamsRequest {});
+            cosmos_query!(self, bank, params, QueryParamsRequest {}, self.height);
         Ok(params.params.unwrap())
     }
 
@@ -125,7 +147,8 @@ impl Bank {
             denom_metadata,
             QueryDenomMetadataRequest {
                 denom: denom.into()
-            }
+            },
+            self.height
         );
         Ok(denom_metadata.metadata.unwrap())
     }
@@ -143,7 +166,8 @@ impl Bank {
             denoms_metadata,
             QueryDenomsMetadataRequest {
                 pagination: pagination
-            }
+            },
+            self.height
         );
         Ok(denoms_metadata.metadatas)
     }
@@ -1,5 +1,6 @@
 use std::{marker::PhantomData, str::FromStr};
 
+use crate::queriers::request_at_height;
 use crate::senders::query::QuerySender;
 use crate::senders::QueryOnlySender;
 use crate::{cosmos_modules, error::DaemonError, DaemonBase};
@@ -22,6 +23,8 @@ use tonic::transport::Channel;
 pub struct CosmWasmBase<Sender = QueryOnlySender> {
     pub channel: Channel,
     pub rt_handle: Option<Handle>,
+    /// Block height the queries are resolved at, latest if `None`
+    pub height: Option<u64>,
     _sender: PhantomData<Sender>,
 }
 
@@ -32,6 +35,7 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         Self {
             channel: daemon.channel(),
             rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
             _sender: PhantomData,
         }
     }
@@ -39,6 +43,7 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         Self {
             channel,
             rt_handle: None,
+            height: None,
             _sender: PhantomData,
         }
     }
@@ -46,9 +51,16 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         Self {
             channel,
             rt_handle: Some(handle.clone()),
+            height: None,
             _sender: PhantomData,
         }
     }
+
+    /// Resolve the queries at the given block height
+    pub fn at_height(mut self, height: u64) -> Self {
+        self.height = Some(height);
+        self
+    }
 }
 
 impl<Sender: QuerySender> QuerierGetter<CosmWasmBase<Sender>> for DaemonBase<Sender> {
@@ -62,12 +74,16 @@ impl<Sender> Querier for CosmWasmBase<Sender> {
 }
 
 impl<Sender: QuerySender> CosmWasmBase<Sender> {
+    fn request<T>(&self, message: T) -> tonic::Request<T> {
+        request_at_height(message, self.height)
+    }
+
     /// Query code_id by hash
     pub async fn _code_id_hash(&self, code_id: u64) -> Result<Checksum, DaemonError> {
         use cosmos_modules::cosmwasm::{query_client::*, QueryCodeRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
         let request = QueryCodeRequest { code_id };
-        let resp = client.code(request).await?.into_inner();
+        let resp = client.code(self.request(request)).await?.into_inner();
         let contract_hash = resp.code_info.unwrap().data_hash;
         Ok(contract_hash.as_slice().try_into()?)
     }
@@ -82,7 +98,10 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         let request = QueryContractInfoRequest {
             address: address.into(),
         };
-        let resp = client.contract_info(request).await?.into_inner();
+        let resp = client
+            .contract_info(self.request(request))
+            .await?
+            .into_inner();
         let contract_info = resp.contract_info.unwrap();
 
         let c = ContractInfoResponse::new(
@@ -115,7 +134,10 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
             address: address.into(),
             pagination,
         };
-        Ok(client.contract_history(request).await?.into_inner())
+        Ok(client
+            .contract_history(self.request(request))
+            .await?
+            .into_inner())
     }
 
     /// Query contract state
@@ -131,7 +153,7 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
             query_data,
         };
         Ok(client
-            .smart_contract_state(request)
+            .smart_contract_state(self.request(request))
             .await?
             .into_inner()
             .data)
@@ -149,7 +171,10 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
             address: address.into(),
             pagination,
         };
-        Ok(client.all_contract_state(request).await?.into_inner())
+        Ok(client
+            .all_contract_state(self.request(request))
+            .await?
+            .into_inner())
     }
 
     /// Query code
@@ -157,7 +182,12 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         use cosmos_modules::cosmwasm::{query_client::*, QueryCodeRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
         let request = QueryCodeRequest { code_id };
-        let response = client.code(request).await?.into_inner().code_info.unwrap();
+        let response = client
+            .code(self.request(request))
+            .await?
+            .into_inner()
+            .code_info
+            .unwrap();
 
         Ok(cosmrs_to_cosmwasm_code_info(response))
     }
@@ -167,7 +197,7 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         use cosmos_modules::cosmwasm::{query_client::*, QueryCodeRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
         let request = QueryCodeRequest { code_id };
-        Ok(client.code(request).await?.into_inner().data)
+        Ok(client.code(self.request(request)).await?.into_inner().data)
     }
 
     /// Query codes
@@ -178,7 +208,11 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         use cosmos_modules::cosmwasm::{query_client::*, QueryCodesRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
         let request = QueryCodesRequest { pagination };
-        let response = client.codes(request).await?.into_inner().code_infos;
+        let response = client
+            .codes(self.request(request))
+            .await?
+            .into_inner()
+            .code_infos;
 
         Ok(response
             .into_iter()
@@ -193,7 +227,10 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         use cosmos_modules::cosmwasm::{query_client::*, QueryPinnedCodesRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
         let request = QueryPinnedCodesRequest { pagination: None };
-        Ok(client.pinned_codes(request).await?.into_inner())
+        Ok(client
+            .pinned_codes(self.request(request))
+            .await?
+            .into_inner())
     }
 
     /// Query contracts by code
@@ -207,7 +244,10 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
             code_id,
             pagination: None,
         };
-        Ok(client.contracts_by_code(request).await?.into_inner())
+        Ok(client
+            .contracts_by_code(self.request(request))
+            .await?
+            .into_inner())
     }
 
     /// Query raw contract state
@@ -222,7 +262,10 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
             address: address.into(),
             query_data,
         };
-        Ok(client.raw_contract_state(request).await?.into_inner())
+        Ok(client
+            .raw_contract_state(self.request(request))
+            .await?
+            .into_inner())
     }
 
     /// Query params
@@ -231,7 +274,10 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
     ) -> Result<cosmos_modules::cosmwasm::QueryParamsResponse, DaemonError> {
         use cosmos_modules::cosmwasm::{query_client::*, QueryParamsRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
-        Ok(client.params(QueryParamsRequest {}).await?.into_inner())
+        Ok(client
+            .params(self.request(QueryParamsRequest {}))
+            .await?
+            .into_inner())
     }
 }
 
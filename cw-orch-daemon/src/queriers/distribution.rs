@@ -0,0 +1,247 @@
+use std::str::FromStr;
+
+use crate::{cosmos_modules, error::DaemonError, Daemon};
+use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
+use cosmwasm_std::{Addr, DecCoin, Decimal256, StdError, Uint256};
+use cw_orch_core::environment::{Querier, QuerierGetter};
+use tokio::runtime::Handle;
+use tonic::transport::Channel;
+
+/// Querier for the Cosmos Distribution module
+/// All the async function are prefixed with `_`
+pub struct Distribution {
+    pub channel: Channel,
+    pub rt_handle: Option<Handle>,
+    /// Block height the queries are resolved at, latest if `None`
+    pub height: Option<u64>,
+}
+
+impl Distribution {
+    pub fn new(daemon: &Daemon) -> Self {
+        Self {
+            channel: daemon.channel(),
+            rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
+        }
+    }
+
+    pub fn new_async(channel: Channel) -> Self {
+        Self {
+            channel,
+            rt_handle: None,
+            height: None,
+        }
+    }
+
+    /// Resolve the queries at the given block height
+    pub fn at_height(mut self, height: u64) -> Self {
+        self.height = Some(height);
+        self
+    }
+}
+
+impl Querier for Distribution {
+    type Error = DaemonError;
+}
+
+impl QuerierGetter<Distribution> for Daemon {
+    fn querier(&self) -> Distribution {
+        Distribution::new(self)
+    }
+}
+
+impl Distribution {
+    /// Query distribution params
+    pub async fn _params(&self) -> Result<cosmos_modules::distribution::Params, DaemonError> {
+        let params: cosmos_modules::distribution::QueryParamsResponse = cosmos_query!(
+            self,
+            distribution,
+            params,
+            QueryParamsRequest {},
+            self.height
+        );
+        Ok(params.params.unwrap())
+    }
+
+    /// Query the rewards of a validator that are not yet withdrawn (delegators rewards and commission)
+    pub async fn _validator_outstanding_rewards(
+        &self,
+        validator_address: &Addr,
+    ) -> Result<Vec<DecCoin>, DaemonError> {
+        let outstanding_rewards: cosmos_modules::distribution::QueryValidatorOutstandingRewardsResponse = cosmos_query!(
+            self,
+            distribution,
+            validator_outstanding_rewards,
+            QueryValidatorOutstandingRewardsRequest {
+                validator_address: validator_address.into()
+            }, self.height
+        );
+        Ok(cosmrs_to_cosmwasm_dec_coins(
+            outstanding_rewards.rewards.unwrap_or_default().rewards,
+        )?)
+    }
+
+    /// Query the accumulated commission of a validator
+    pub async fn _validator_commission(
+        &self,
+        validator_address: &Addr,
+    ) -> Result<Vec<DecCoin>, DaemonError> {
+        let commission: cosmos_modules::distribution::QueryValidatorCommissionResponse = cosmos_query!(
+            self,
+            distribution,
+            validator_commission,
+            QueryValidatorCommissionRequest {
+                validator_address: validator_address.into()
+            },
+            self.height
+        );
+        Ok(cosmrs_to_cosmwasm_dec_coins(
+            commission.commission.unwrap_or_default().commission,
+        )?)
+    }
+
+    /// Query the slash events of a validator between two heights
+    ///
+    /// see [PageRequest] for pagination
+    pub async fn _validator_slashes(
+        &self,
+        validator_address: &Addr,
+        starting_height: u64,
+        ending_height: u64,
+        pagination: Option<PageRequest>,
+    ) -> Result<Vec<cosmos_modules::distribution::ValidatorSlashEvent>, DaemonError> {
+        let slashes: cosmos_modules::distribution::QueryValidatorSlashesResponse = cosmos_query!(
+            self,
+            distribution,
+            validator_slashes,
+            QueryValidatorSlashesRequest {
+                validator_address: validator_address.into(),
+                starting_height: starting_height,
+                ending_height: ending_height,
+                pagination: pagination
+            },
+            self.height
+        );
+        Ok(slashes.slashes)
+    }
+
+    /// Query the rewards of a delegation to a validator
+    pub async fn _delegation_rewards(
+        &self,
+        delegator_address: &Addr,
+        validator_address: &Addr,
+    ) -> Result<Vec<DecCoin>, DaemonError> {
+        let rewards: cosmos_modules::distribution::QueryDelegationRewardsResponse = cosmos_query!(
+            self,
+            distribution,
+            delegation_rewards,
+            QueryDelegationRewardsRequest {
+                delegator_address: delegator_address.into(),
+                validator_address: validator_address.into()
+            },
+            self.height
+        );
+        Ok(cosmrs_to_cosmwasm_dec_coins(rewards.rewards)?)
+    }
+
+    /// Query the rewards of all the delegations of a delegator, per validator and in total
+    pub async fn _delegation_total_rewards(
+        &self,
+        delegator_address: &Addr,
+    ) -> Result<cosmos_modules::distribution::QueryDelegationTotalRewardsResponse, DaemonError>
+    {
+        let total_rewards: cosmos_modules::distribution::QueryDelegationTotalRewardsResponse = cosmos_query!(
+            self,
+            distribution,
+            delegation_total_rewards,
+            QueryDelegationTotalRewardsRequest {
+                delegator_address: delegator_address.into()
+            },
+            self.height
+        );
+        Ok(total_rewards)
+    }
+
+    /// Query the validators a delegator is delegating to
+    pub async fn _delegator_validators(
+        &self,
+        delegator_address: &Addr,
+    ) -> Result<Vec<String>, DaemonError> {
+        let validators: cosmos_modules::distribution::QueryDelegatorValidatorsResponse = cosmos_query!(
+            self,
+            distribution,
+            delegator_validators,
+            QueryDelegatorValidatorsRequest {
+                delegator_address: delegator_address.into()
+            },
+            self.height
+        );
+        Ok(validators.validators)
+    }
+
+    /// Query the address the rewards of a delegator are withdrawn to
+    pub async fn _delegator_withdraw_address(
+        &self,
+        delegator_address: &Addr,
+    ) -> Result<Addr, DaemonError> {
+        let withdraw_address: cosmos_modules::distribution::QueryDelegatorWithdrawAddressResponse = cosmos_query!(
+            self,
+            distribution,
+            delegator_withdraw_address,
+            QueryDelegatorWithdrawAddressRequest {
+                delegator_address: delegator_address.into()
+            },
+            self.height
+        );
+        Ok(Addr::unchecked(withdraw_address.withdraw_address))
+    }
+
+    /// Query the community pool coins
+    pub async fn _community_pool(&self) -> Result<Vec<DecCoin>, DaemonError> {
+        let community_pool: cosmos_modules::distribution::QueryCommunityPoolResponse = cosmos_query!(
+            self,
+            distribution,
+            community_pool,
+            QueryCommunityPoolRequest {},
+            self.height
+        );
+        Ok(cosmrs_to_cosmwasm_dec_coins(community_pool.pool)?)
+    }
+}
+
+/// Parses a Cosmos SDK `LegacyDec`.
+/// gRPC returns them as their 18 decimals atomics (`1500000000000000000` for `1.5`), a decimal string is also accepted.
+pub fn parse_legacy_dec(dec: &str) -> Result<Decimal256, StdError> {
+    if dec.contains('.') {
+        return Decimal256::from_str(dec);
+    }
+    Decimal256::from_atomics(Uint256::from_str(dec)?, 18)
+        .map_err(|e| StdError::generic_err(e.to_string()))
+}
+
+pub fn cosmrs_to_cosmwasm_dec_coin(
+    c: cosmrs::proto::cosmos::base::v1beta1::DecCoin,
+) -> Result<DecCoin, StdError> {
+    Ok(DecCoin::new(parse_legacy_dec(&c.amount)?, c.denom))
+}
+
+pub fn cosmrs_to_cosmwasm_dec_coins(
+    c: Vec<cosmrs::proto::cosmos::base::v1beta1::DecCoin>,
+) -> Result<Vec<DecCoin>, StdError> {
+    c.into_iter().map(cosmrs_to_cosmwasm_dec_coin).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_dec() {
+        assert_eq!(
+            parse_legacy_dec("1500000000000000000").unwrap(),
+            Decimal256::from_str("1.5").unwrap()
+        );
+        assert_eq!(parse_legacy_dec("0.13").unwrap(), Decimal256::percent(13));
+        assert!(parse_legacy_dec("abc").is_err());
+    }
+}
@@ -10,6 +10,8 @@ use tonic::transport::Channel;
 pub struct FeeGrant {
     pub channel: Channel,
     pub rt_handle: Option<Handle>,
+    /// Block height the queries are resolved at, latest if `None`
+    pub height: Option<u64>,
 }
 
 impl FeeGrant {
@@ -17,6 +19,7 @@ impl FeeGrant {
         Self {
             channel: daemon.channel(),
             rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
         }
     }
 
@@ -24,8 +27,15 @@ impl FeeGrant {
         Self {
             channel,
             rt_handle: None,
+            height: None,
         }
     }
+
+    /// Resolve the queries at the given block height
+    pub fn at_height(mut self, height: u64) -> Self {
+        self.height = Some(height);
+        self
+    }
 }
 
 impl Querier for FeeGrant {
@@ -52,7 +62,8 @@ impl FeeGrant {
             QueryAllowanceRequest {
                 granter: granter.to_string(),
                 grantee: grantee.to_string(),
-            }
+            },
+            self.height
         );
         Ok(allowance.allowance.unwrap())
     }
@@ -72,7 +83,8 @@ impl FeeGrant {
             QueryAllowancesRequest {
                 grantee: grantee.to_string(),
                 pagination: pagination
-            }
+            },
+            self.height
         );
         Ok(allowances.allowances)
     }
@@ -10,6 +10,8 @@ use tonic::transport::Channel;
 pub struct Gov {
     pub channel: Channel,
     pub rt_handle: Option<Handle>,
+    /// Block height the queries are resolved at, latest if `None`
+    pub height: Option<u64>,
 }
 
 impl Gov {
@@ -17,6 +19,7 @@ impl Gov {
         Self {
             channel: daemon.channel(),
             rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
         }
     }
 
@@ -24,8 +27,15 @@ impl Gov {
         Self {
             channel,
             rt_handle: None,
+            height: None,
         }
     }
+
+    /// Resolve the queries at the given block height
+    pub fn at_height(mut self, height: u64) -> Self {
+        self.height = Some(height);
+        self
+    }
 }
 
 impl Querier for Gov {
@@ -50,7 +60,8 @@ impl Gov {
             proposal,
             QueryProposalRequest {
                 proposal_id: proposal_id,
-            }
+            },
+            self.height
         );
         Ok(proposal.proposal.unwrap())
     }
@@ -74,7 +85,8 @@ impl Gov {
                 voter: voter.to_string(),
                 depositor: depositor.to_string(),
                 pagination: pagination
-            }
+            },
+            self.height
         );
         Ok(proposals)
     }
@@ -92,7 +104,8 @@ impl Gov {
             QueryVoteRequest {
                 proposal_id: proposal_id,
                 voter: voter.to_string()
-            }
+            },
+            self.height
         );
         Ok(vote.vote.unwrap())
     }
@@ -112,7 +125,8 @@ impl Gov {
             QueryVotesRequest {
                 proposal_id: proposal_id.into(),
                 pagination: pagination
-            }
+            },
+            self.height
         );
         Ok(votes)
     }
@@ -128,7 +142,8 @@ impl Gov {
             params,
             QueryParamsRequest {
                 params_type: params_type.into()
-            }
+            },
+            self.height
         );
         Ok(params)
     }
@@ -146,7 +161,8 @@ impl Gov {
             QueryDepositRequest {
                 proposal_id: proposal_id,
                 depositor: depositor.into()
-            }
+            },
+            self.height
         );
         Ok(deposit.deposit.unwrap())
     }
@@ -166,7 +182,8 @@ impl Gov {
             QueryDepositsRequest {
                 proposal_id: proposal_id,
                 pagination: pagination
-            }
+            },
+            self.height
         );
         Ok(deposits)
     }
@@ -182,7 +199,8 @@ impl Gov {
             tally_result,
             QueryTallyResultRequest {
                 proposal_id: proposal_id,
-            }
+            },
+            self.height
         );
         Ok(tally_result.tally.unwrap())
     }
@@ -20,6 +20,8 @@ use tonic::transport::Channel;
 pub struct Ibc {
     pub channel: Channel,
     pub rt_handle: Option<Handle>,
+    /// Block height the queries are resolved at, latest if `None`
+    pub height: Option<u64>,
 }
 
 impl Ibc {
@@ -27,6 +29,7 @@ impl Ibc {
         Self {
             channel: daemon.channel(),
             rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
         }
     }
 
@@ -34,8 +37,15 @@ impl Ibc {
         Self {
             channel,
             rt_handle: None,
+            height: None,
         }
     }
+
+    /// Resolve the queries at the given block height
+    pub fn at_height(mut self, height: u64) -> Self {
+        self.height = Some(height);
+        self
+    }
 }
 
 impl Querier for Ibc {
@@ -57,7 +67,8 @@ impl Ibc {
             self,
             ibc_transfer,
             denom_trace,
-            QueryDenomTraceRequest { hash: hash }
+            QueryDenomTraceRequest { hash: hash },
+            self.height
         );
         Ok(denom_trace.denom_trace.unwrap())
     }
@@ -68,7 +79,8 @@ impl Ibc {
             self,
             ibc_transfer,
             denom_hash,
-            QueryDenomHashRequest { trace: trace }
+            QueryDenomHashRequest { trace: trace },
+            self.height
         );
         Ok(denom_hash.hash)
     }
@@ -81,7 +93,8 @@ impl Ibc {
             self,
             ibc_client,
             client_states,
-            QueryClientStatesRequest { pagination: None }
+            QueryClientStatesRequest { pagination: None },
+            self.height
         );
         Ok(ibc_clients.client_states)
     }
@@ -98,7 +111,8 @@ impl Ibc {
             client_state,
             QueryClientStateRequest {
                 client_id: client_id.to_string(),
-            }
+            },
+            self.height
         );
         Ok(response)
     }
@@ -116,7 +130,8 @@ impl Ibc {
             QueryConsensusStatesRequest {
                 client_id: client_id,
                 pagination: None,
-            }
+            },
+            self.height
         );
         Ok(response)
     }
@@ -133,7 +148,8 @@ impl Ibc {
             client_status,
             QueryClientStatusRequest {
                 client_id: client_id.to_string(),
-            }
+            },
+            self.height
         );
         Ok(response)
     }
@@ -142,8 +158,13 @@ impl Ibc {
     pub async fn _client_params(
         &self,
     ) -> Result<cosmos_modules::ibc_client::QueryClientParamsResponse, DaemonError> {
-        let response: cosmos_modules::ibc_client::QueryClientParamsResponse =
-            cosmos_query!(self, ibc_client, client_params, QueryClientParamsRequest {});
+        let response: cosmos_modules::ibc_client::QueryClientParamsResponse = cosmos_query!(
+            self,
+            ibc_client,
+            client_params,
+            QueryClientParamsRequest {},
+            self.height
+        );
         Ok(response)
     }
 
@@ -157,7 +178,8 @@ impl Ibc {
             self,
             ibc_connection,
             connections,
-            QueryConnectionsRequest { pagination: None }
+            QueryConnectionsRequest { pagination: None },
+            self.height
         );
         Ok(ibc_connections.connections)
     }
@@ -201,7 +223,8 @@ impl Ibc {
             connection,
             QueryConnectionRequest {
                 connection_id: connection_id.clone()
-            }
+            },
+            self.height
         );
 
         Ok(ibc_client_connections.connection)
@@ -221,7 +244,8 @@ impl Ibc {
             client_connections,
             QueryClientConnectionsRequest {
                 client_id: client_id.clone()
-            }
+            },
+            self.height
         );
 
         Ok(ibc_client_connections.connection_paths)
@@ -241,7 +265,8 @@ impl Ibc {
             connection_client_state,
             QueryConnectionClientStateRequest {
                 connection_id: connection_id.clone()
-            }
+            },
+            self.height
         );
 
         let client_state =
@@ -277,7 +302,8 @@ impl Ibc {
             QueryChannelRequest {
                 port_id: port_id.clone(),
                 channel_id: channel_id.clone(),
-            }
+            },
+            self.height
         );
 
         ibc_channel.channel.ok_or(DaemonError::ibc_err(format!(
@@ -299,7 +325,8 @@ impl Ibc {
             channels,
             QueryChannelsRequest {
                 pagination: pagination
-            }
+            },
+            self.height
         );
 
         Ok(ibc_channels.channels)
@@ -320,7 +347,8 @@ impl Ibc {
             QueryConnectionChannelsRequest {
                 connection: connection_id.clone(),
                 pagination: None,
-            }
+            },
+            self.height
         );
 
         Ok(ibc_connection_channels.channels)
@@ -343,7 +371,8 @@ impl Ibc {
             QueryChannelClientStateRequest {
                 port_id: port_id.clone(),
                 channel_id: channel_id.clone(),
-            }
+            },
+            self.height
         );
 
         ibc_channel_client_state
@@ -376,7 +405,8 @@ impl Ibc {
                 port_id: port_id.clone(),
                 channel_id: channel_id.clone(),
                 pagination: None,
-            }
+            },
+            self.height
         );
 
         Ok(ibc_packet_commitments.commitments)
@@ -399,7 +429,8 @@ impl Ibc {
                 port_id: port_id.clone(),
                 channel_id: channel_id.clone(),
                 sequence: sequence,
-            }
+            },
+            self.height
         );
 
         Ok(ibc_packet_commitment)
@@ -424,7 +455,8 @@ impl Ibc {
                 port_id: port_id.clone(),
                 channel_id: channel_id.clone(),
                 sequence: sequence,
-            }
+            },
+            self.height
         );
 
         Ok(ibc_packet_receipt.received)
@@ -452,7 +484,8 @@ impl Ibc {
                 channel_id: channel_id.clone(),
                 packet_commitment_sequences: packet_commitment_sequences,
                 pagination: None,
-            }
+            },
+            self.height
         );
 
         Ok(ibc_packet_acknowledgements.acknowledgements)
@@ -475,7 +508,8 @@ impl Ibc {
                 port_id: port_id.clone(),
                 channel_id: channel_id.clone(),
                 sequence: sequence,
-            }
+            },
+            self.height
         );
 
         Ok(ibc_packet_acknowledgement.acknowledgement)
@@ -501,7 +535,8 @@ impl Ibc {
                 port_id: port_id.clone(),
                 channel_id: channel_id.clone(),
                 packet_commitment_sequences: packet_commitment_sequences,
-            }
+            },
+            self.height
         );
 
         Ok(ibc_packet_unreceived.sequences)
@@ -526,7 +561,8 @@ impl Ibc {
                 port_id: port_id.clone(),
                 channel_id: channel_id.clone(),
                 packet_ack_sequences: packet_ack_sequences,
-            }
+            },
+            self.height
         );
 
         Ok(ibc_packet_unreceived.sequences)
@@ -549,7 +585,8 @@ impl Ibc {
             QueryNextSequenceReceiveRequest {
                 port_id: port_id.clone(),
                 channel_id: channel_id.clone(),
-            }
+            },
+            self.height
         );
 
         Ok(next_receive.next_sequence_receive)
@@ -0,0 +1,82 @@
+use crate::{cosmos_modules, error::DaemonError, Daemon};
+use cosmwasm_std::Decimal256;
+use cw_orch_core::environment::{Querier, QuerierGetter};
+use tokio::runtime::Handle;
+use tonic::transport::Channel;
+
+use super::distribution::parse_legacy_dec;
+
+/// Querier for the Cosmos Mint module
+/// All the async function are prefixed with `_`
+pub struct Mint {
+    pub channel: Channel,
+    pub rt_handle: Option<Handle>,
+    /// Block height the queries are resolved at, latest if `None`
+    pub height: Option<u64>,
+}
+
+impl Mint {
+    pub fn new(daemon: &Daemon) -> Self {
+        Self {
+            channel: daemon.channel(),
+            rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
+        }
+    }
+
+    pub fn new_async(channel: Channel) -> Self {
+        Self {
+            channel,
+            rt_handle: None,
+            height: None,
+        }
+    }
+
+    /// Resolve the queries at the given block height
+    pub fn at_height(mut self, height: u64) -> Self {
+        self.height = Some(height);
+        self
+    }
+}
+
+impl Querier for Mint {
+    type Error = DaemonError;
+}
+
+impl QuerierGetter<Mint> for Daemon {
+    fn querier(&self) -> Mint {
+        Mint::new(self)
+    }
+}
+
+impl Mint {
+    /// Query mint params
+    pub async fn _params(&self) -> Result<cosmos_modules::mint::Params, DaemonError> {
+        let params: cosmos_modules::mint::QueryParamsResponse =
+            cosmos_query!(self, mint, params, QueryParamsRequest {}, self.height);
+        Ok(params.params.unwrap())
+    }
+
+    /// Query the current minting inflation rate
+    pub async fn _inflation(&self) -> Result<Decimal256, DaemonError> {
+        let inflation: cosmos_modules::mint::QueryInflationResponse =
+            cosmos_query!(self, mint, inflation, QueryInflationRequest {}, self.height);
+        Ok(parse_legacy_dec(&String::from_utf8_lossy(
+            &inflation.inflation,
+        ))?)
+    }
+
+    /// Query the current minting annual provisions
+    pub async fn _annual_provisions(&self) -> Result<Decimal256, DaemonError> {
+        let annual_provisions: cosmos_modules::mint::QueryAnnualProvisionsResponse = cosmos_query!(
+            self,
+            mint,
+            annual_provisions,
+            QueryAnnualProvisionsRequest {},
+            self.height
+        );
+        Ok(parse_legacy_dec(&String::from_utf8_lossy(
+            &annual_provisions.annual_provisions,
+        ))?)
+    }
+}
@@ -1,5 +1,7 @@
 use std::{cmp::min, time::Duration};
 
+#[cfg(feature = "websocket")]
+use crate::websocket::EventSubscription;
 use crate::{
     cosmos_modules, env::DaemonEnvVars, error::DaemonError, senders::query::QuerySender,
     tx_resp::CosmTxResponse, DaemonBase,
@@ -20,12 +22,19 @@ use cw_orch_core::{
 use tokio::runtime::Handle;
 use tonic::transport::Channel;
 
+/// Maximum time to wait for a tx inclusion event before falling back to polling
+#[cfg(feature = "websocket")]
+const WEBSOCKET_TX_TIMEOUT: Duration = Duration::from_secs(60);
+
 /// Querier for the Tendermint node.
 /// Supports queries for block and tx information
 /// All the async function are prefixed with `_`
 pub struct Node {
     pub channel: Channel,
     pub rt_handle: Option<Handle>,
+    /// Block height the latest block queries are resolved at, latest if `None`.
+    /// Transaction lookups and simulations are not affected
+    pub height: Option<u64>,
 }
 
 impl Node {
@@ -33,14 +42,22 @@ impl Node {
         Self {
             channel: daemon.channel(),
             rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
         }
     }
     pub fn new_async(channel: Channel) -> Self {
         Self {
             channel,
             rt_handle: None,
+            height: None,
         }
     }
+
+    /// Resolve the latest block queries at the given block height
+    pub fn at_height(mut self, height: u64) -> Self {
+        self.height = Some(height);
+        self
+    }
 }
 
 impl<Sender: QuerySender> QuerierGetter<Node> for DaemonBase<Sender> {
@@ -82,8 +99,11 @@ impl Node {
         Ok(resp.syncing)
     }
 
-    /// Returns latests block information
+    /// Returns latests block information, or the block at the querier height if set
     pub async fn _latest_block(&self) -> Result<Block, DaemonError> {
+        if let Some(height) = self.height {
+            return self._block_by_height(height).await;
+        }
         let mut client =
             cosmos_modules::tendermint::service_client::ServiceClient::new(self.channel.clone());
 
@@ -272,6 +292,64 @@ impl Node {
         Err(DaemonError::TXNotFound(hash, retries))
     }
 
+    /// Find TX by hash, waiting for its inclusion with a CometBFT websocket subscription instead of polling the node.
+    /// Falls back to [`Node::_find_tx`] if the subscription fails or no event was received in time.
+    #[cfg(feature = "websocket")]
+    pub async fn _find_tx_with_websocket(
+        &self,
+        hash: String,
+        websocket_url: &str,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let mut subscription = match EventSubscription::tx(websocket_url, &hash).await {
+            Ok(subscription) => subscription,
+            Err(err) => {
+                log::warn!(
+                    target: &query_target(),
+                    "Couldn't subscribe to tx events on {}, falling back to polling: {:?}",
+                    websocket_url,
+                    err
+                );
+                return self._find_tx(hash).await;
+            }
+        };
+
+        // The tx might have been included before we subscribed
+        if let Ok(resp) = self._tx(hash.clone()).await {
+            return Ok(resp);
+        }
+
+        match tokio::time::timeout(WEBSOCKET_TX_TIMEOUT, subscription.next()).await {
+            Ok(Some(Ok(_))) => {
+                log::debug!(target: &query_target(), "TX inclusion event received: {}", hash);
+                self._find_tx_with_retries(hash, DaemonEnvVars::max_tx_query_retries())
+                    .await
+            }
+            Ok(event) => {
+                log::debug!(target: &query_target(), "TX subscription ended without event: {:?}", event);
+                self._find_tx(hash).await
+            }
+            Err(_) => {
+                log::debug!(target: &query_target(), "No TX inclusion event received after {:?}", WEBSOCKET_TX_TIMEOUT);
+                self._find_tx(hash).await
+            }
+        }
+    }
+
+    /// Query a TX by hash once, without waiting for its inclusion
+    pub async fn _tx(&self, hash: String) -> Result<CosmTxResponse, DaemonError> {
+        let mut client =
+            cosmos_modules::tx::service_client::ServiceClient::new(self.channel.clone());
+
+        let resp = client
+            .get_tx(cosmos_modules::tx::GetTxRequest { hash: hash.clone() })
+            .await?
+            .into_inner();
+
+        resp.tx_response
+            .map(Into::into)
+            .ok_or(DaemonError::TXNotFound(hash, 1))
+    }
+
     /// Find TX by events
     pub async fn _find_tx_by_events(
         &self,
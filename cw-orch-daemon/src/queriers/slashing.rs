@@ -0,0 +1,93 @@
+use crate::{cosmos_modules, error::DaemonError, Daemon};
+use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
+use cw_orch_core::environment::{Querier, QuerierGetter};
+use tokio::runtime::Handle;
+use tonic::transport::Channel;
+
+/// Querier for the Cosmos Slashing module
+/// All the async function are prefixed with `_`
+pub struct Slashing {
+    pub channel: Channel,
+    pub rt_handle: Option<Handle>,
+    /// Block height the queries are resolved at, latest if `None`
+    pub height: Option<u64>,
+}
+
+impl Slashing {
+    pub fn new(daemon: &Daemon) -> Self {
+        Self {
+            channel: daemon.channel(),
+            rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
+        }
+    }
+
+    pub fn new_async(channel: Channel) -> Self {
+        Self {
+            channel,
+            rt_handle: None,
+            height: None,
+        }
+    }
+
+    /// Resolve the queries at the given block height
+    pub fn at_height(mut self, height: u64) -> Self {
+        self.height = Some(height);
+        self
+    }
+}
+
+impl Querier for Slashing {
+    type Error = DaemonError;
+}
+
+impl QuerierGetter<Slashing> for Daemon {
+    fn querier(&self) -> Slashing {
+        Slashing::new(self)
+    }
+}
+
+impl Slashing {
+    /// Query slashing params
+    pub async fn _params(&self) -> Result<cosmos_modules::slashing::Params, DaemonError> {
+        let params: cosmos_modules::slashing::QueryParamsResponse =
+            cosmos_query!(self, slashing, params, QueryParamsRequest {}, self.height);
+        Ok(params.params.unwrap())
+    }
+
+    /// Query the signing info of a validator by its consensus address (`{prefix}valcons1...`)
+    pub async fn _signing_info(
+        &self,
+        cons_address: impl Into<String>,
+    ) -> Result<cosmos_modules::slashing::ValidatorSigningInfo, DaemonError> {
+        let signing_info: cosmos_modules::slashing::QuerySigningInfoResponse = cosmos_query!(
+            self,
+            slashing,
+            signing_info,
+            QuerySigningInfoRequest {
+                cons_address: cons_address.into()
+            },
+            self.height
+        );
+        Ok(signing_info.val_signing_info.unwrap())
+    }
+
+    /// Query the signing infos of all validators
+    ///
+    /// see [PageRequest] for pagination
+    pub async fn _signing_infos(
+        &self,
+        pagination: Option<PageRequest>,
+    ) -> Result<Vec<cosmos_modules::slashing::ValidatorSigningInfo>, DaemonError> {
+        let signing_infos: cosmos_modules::slashing::QuerySigningInfosResponse = cosmos_query!(
+            self,
+            slashing,
+            signing_infos,
+            QuerySigningInfosRequest {
+                pagination: pagination
+            },
+            self.height
+        );
+        Ok(signing_infos.info)
+    }
+}
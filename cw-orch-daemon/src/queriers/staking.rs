@@ -7,13 +7,15 @@ use cw_orch_core::environment::{Querier, QuerierGetter};
 use tokio::runtime::Handle;
 use tonic::transport::Channel;
 
-use super::bank::cosmrs_to_cosmwasm_coin;
+use super::{bank::cosmrs_to_cosmwasm_coin, distribution::parse_legacy_dec};
 
 /// Querier for the Cosmos Staking module
 /// All the async function are prefixed with `_`
 pub struct Staking {
     pub channel: Channel,
     pub rt_handle: Option<Handle>,
+    /// Block height the queries are resolved at, latest if `None`
+    pub height: Option<u64>,
 }
 
 impl Staking {
@@ -21,6 +23,7 @@ impl Staking {
         Self {
             channel: daemon.channel(),
             rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
         }
     }
 
@@ -28,8 +31,15 @@ impl Staking {
         Self {
             channel,
             rt_handle: None,
+            height: None,
         }
     }
+
+    /// Resolve the queries at the given block height
+    pub fn at_height(mut self, height: u64) -> Self {
+        self.height = Some(height);
+        self
+    }
 }
 
 impl Querier for Staking {
@@ -53,7 +63,8 @@ impl Staking {
             validator,
             QueryValidatorRequest {
                 validator_addr: validator_addr.into()
-            }
+            },
+            self.height
         );
 
         Ok(cosmrs_to_cosmwasm_validator(
@@ -75,7 +86,8 @@ impl Staking {
             QueryValidatorsRequest {
                 status: status.to_string(),
                 pagination: None,
-            }
+            },
+            self.height
         );
 
         Ok(validators
@@ -100,7 +112,8 @@ impl Staking {
             QueryValidatorDelegationsRequest {
                 validator_addr: validator_addr.into(),
                 pagination: pagination
-            }
+            },
+            self.height
         );
         Ok(validator_delegations
             .delegation_responses
@@ -121,7 +134,7 @@ impl Staking {
             QueryValidatorUnbondingDelegationsRequest {
                 validator_addr: validator_addr.into(),
                 pagination: None
-            }
+            }, self.height
         );
         Ok(validator_unbonding_delegations.unbonding_responses)
     }
@@ -139,7 +152,8 @@ impl Staking {
             QueryDelegationRequest {
                 validator_addr: validator_addr.into(),
                 delegator_addr: delegator_addr.into()
-            }
+            },
+            self.height
         );
         Ok(cosmrs_to_cosmwasm_delegation(
             delegation.delegation_response.unwrap(),
@@ -159,7 +173,8 @@ impl Staking {
             QueryUnbondingDelegationRequest {
                 validator_addr: validator_addr.into(),
                 delegator_addr: delegator_addr.into()
-            }
+            },
+            self.height
         );
         Ok(unbonding_delegation.unbond.unwrap())
     }
@@ -179,7 +194,8 @@ impl Staking {
             QueryDelegatorDelegationsRequest {
                 delegator_addr: delegator_addr.into(),
                 pagination: pagination
-            }
+            },
+            self.height
         );
         Ok(delegator_delegations)
     }
@@ -200,7 +216,7 @@ impl Staking {
             QueryDelegatorUnbondingDelegationsRequest {
                 delegator_addr: delegator_addr.into(),
                 pagination: pagination
-            }
+            }, self.height
         );
         Ok(delegator_unbonding_delegations)
     }
@@ -224,7 +240,8 @@ impl Staking {
                 src_validator_addr: src_validator_addr.into(),
                 dst_validator_addr: dst_validator_addr.into(),
                 pagination: pagination
-            }
+            },
+            self.height
         );
         Ok(redelegations)
     }
@@ -242,7 +259,8 @@ impl Staking {
             QueryDelegatorValidatorRequest {
                 validator_addr: validator_addr.into(),
                 delegator_addr: delegator_addr.into(),
-            }
+            },
+            self.height
         );
         Ok(delegator_validator)
     }
@@ -262,7 +280,8 @@ impl Staking {
             QueryDelegatorValidatorsRequest {
                 delegator_addr: delegator_addr.into(),
                 pagination: pagination
-            }
+            },
+            self.height
         );
 
         Ok(delegator_validators)
@@ -277,7 +296,8 @@ impl Staking {
             self,
             staking,
             historical_info,
-            QueryHistoricalInfoRequest { height: height }
+            QueryHistoricalInfoRequest { height: height },
+            self.height
         );
         Ok(historical_info)
     }
@@ -285,7 +305,7 @@ impl Staking {
     /// Query the pool info
     pub async fn _pool(&self) -> Result<cosmos_modules::staking::QueryPoolResponse, DaemonError> {
         let pool: cosmos_modules::staking::QueryPoolResponse =
-            cosmos_query!(self, staking, pool, QueryPoolRequest {});
+            cosmos_query!(self, staking, pool, QueryPoolRequest {}, self.height);
         Ok(pool)
     }
 
@@ -294,7 +314,7 @@ impl Staking {
         &self,
     ) -> Result<cosmos_modules::staking::QueryParamsResponse, DaemonError> {
         let params: cosmos_modules::staking::QueryParamsResponse =
-            cosmos_query!(self, staking, params, QueryParamsRequest {});
+            cosmos_query!(self, staking, params, QueryParamsRequest {}, self.height);
         Ok(params)
     }
 }
@@ -329,12 +349,19 @@ pub fn cosmrs_to_cosmwasm_validator(
     let comission = validator.commission.unwrap().commission_rates.unwrap();
     Ok(cosmwasm_std::Validator::new(
         validator.operator_address,
-        comission.rate.parse()?,
-        comission.max_rate.parse()?,
-        comission.max_change_rate.parse()?,
+        parse_rate(&comission.rate)?,
+        parse_rate(&comission.max_rate)?,
+        parse_rate(&comission.max_change_rate)?,
     ))
 }
 
+/// Commission rates are `LegacyDec`s, see [`parse_legacy_dec`]
+fn parse_rate(rate: &str) -> Result<cosmwasm_std::Decimal, StdError> {
+    parse_legacy_dec(rate)?
+        .try_into()
+        .map_err(|e: cosmwasm_std::DecimalRangeExceeded| StdError::generic_err(e.to_string()))
+}
+
 pub fn cosmrs_to_cosmwasm_delegation(
     delegation_response: cosmrs::proto::cosmos::staking::v1beta1::DelegationResponse,
 ) -> Result<cosmwasm_std::Delegation, StdError> {
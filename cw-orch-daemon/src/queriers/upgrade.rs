@@ -0,0 +1,93 @@
+use crate::{cosmos_modules, error::DaemonError, Daemon};
+use cw_orch_core::environment::{Querier, QuerierGetter};
+use tokio::runtime::Handle;
+use tonic::transport::Channel;
+
+/// Querier for the Cosmos Upgrade module
+/// All the async function are prefixed with `_`
+pub struct Upgrade {
+    pub channel: Channel,
+    pub rt_handle: Option<Handle>,
+    /// Block height the queries are resolved at, latest if `None`
+    pub height: Option<u64>,
+}
+
+impl Upgrade {
+    pub fn new(daemon: &Daemon) -> Self {
+        Self {
+            channel: daemon.channel(),
+            rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
+        }
+    }
+
+    pub fn new_async(channel: Channel) -> Self {
+        Self {
+            channel,
+            rt_handle: None,
+            height: None,
+        }
+    }
+
+    /// Resolve the queries at the given block height
+    pub fn at_height(mut self, height: u64) -> Self {
+        self.height = Some(height);
+        self
+    }
+}
+
+impl Querier for Upgrade {
+    type Error = DaemonError;
+}
+
+impl QuerierGetter<Upgrade> for Daemon {
+    fn querier(&self) -> Upgrade {
+        Upgrade::new(self)
+    }
+}
+
+impl Upgrade {
+    /// Query the currently scheduled upgrade plan, `None` if no upgrade is scheduled
+    pub async fn _current_plan(
+        &self,
+    ) -> Result<Option<cosmos_modules::upgrade::Plan>, DaemonError> {
+        let current_plan: cosmos_modules::upgrade::QueryCurrentPlanResponse = cosmos_query!(
+            self,
+            upgrade,
+            current_plan,
+            QueryCurrentPlanRequest {},
+            self.height
+        );
+        Ok(current_plan.plan)
+    }
+
+    /// Query the height at which the upgrade `name` was applied, `None` if it was not applied
+    pub async fn _applied_plan(&self, name: impl Into<String>) -> Result<Option<u64>, DaemonError> {
+        let applied_plan: cosmos_modules::upgrade::QueryAppliedPlanResponse = cosmos_query!(
+            self,
+            upgrade,
+            applied_plan,
+            QueryAppliedPlanRequest { name: name.into() },
+            self.height
+        );
+        Ok((applied_plan.height > 0).then_some(applied_plan.height as u64))
+    }
+
+    /// Query the consensus versions of the modules of the chain.
+    /// Only the version of `module_name` is returned if provided.
+    pub async fn _module_versions(
+        &self,
+        module_name: Option<String>,
+    ) -> Result<Vec<cosmos_modules::upgrade::ModuleVersion>, DaemonError> {
+        let module_versions: cosmos_modules::upgrade::QueryModuleVersionsResponse = cosmos_query!(
+            self,
+            upgrade,
+            module_versions,
+            QueryModuleVersionsRequest {
+                module_name: module_name.unwrap_or_default()
+            },
+            self.height
+        );
+        Ok(module_versions.module_versions)
+    }
+}
@@ -0,0 +1,717 @@
+//! # REST transport
+//!
+//! Some providers only expose the REST gateway (LCD) of a node. [`RestChannel`] serves the gRPC queries used by cw-orch from that gateway,
+//! behind a regular [`Channel`]. All the queriers and senders of this crate can therefore be used without access to a gRPC endpoint.
+//!
+//! The REST transport is used when the [`ChainInfo`](cw_orch_core::environment::ChainInfo) has an `lcd_url` and either no `grpc_urls`
+//! or the `CW_ORCH_REST_TRANSPORT` env variable is set to `true`.
+//!
+//! Supported gRPC services:
+//! - bank: balances, spendable balances and supply
+//! - cosmwasm: contract state, contract info, codes and contracts by code
+//! - auth: accounts
+//! - tx: simulate, broadcast and get tx
+//! - tendermint: node info, syncing and blocks
+//!
+//! Any other method returns an `Unimplemented` status.
+//!
+//! ```no_run
+//! use cw_orch_daemon::{queriers::Node, RestChannel};
+//! # tokio_test::block_on(async {
+//! let channel = RestChannel::connect("https://rest-kralum.neutron-1.neutron.org", "neutron-1")
+//!     .await
+//!     .unwrap();
+//! let block_height = Node::new_async(channel)._block_height().await.unwrap();
+//! # })
+//! ```
+
+mod convert;
+
+use std::{convert::Infallible, future::Future};
+
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE},
+    Engine,
+};
+use cw_orch_core::log::connectivity_target;
+use http::Uri;
+use hyper::body::Incoming;
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use serde_json::{json, Value};
+use tokio::{io::DuplexStream, sync::mpsc};
+use tonic::{
+    body::BoxBody,
+    codec::ProstCodec,
+    server::Grpc,
+    transport::{Channel, Endpoint},
+    Code, Status,
+};
+
+use crate::{
+    cosmos_modules::{self, auth, bank, cosmwasm, tendermint, tx},
+    queriers::BLOCK_HEIGHT_METADATA,
+    DaemonError,
+};
+
+/// Size of the in-memory pipe between the gRPC channel and the REST gateway
+const DUPLEX_BUFFER_SIZE: usize = 1024 * 1024;
+
+/// A helper for constructing a gRPC channel backed by the Cosmos REST gateway
+pub struct RestChannel {}
+
+impl RestChannel {
+    /// Connect to the REST endpoint of a node and verify that it serves the expected chain
+    pub async fn connect(lcd_url: &str, chain_id: &str) -> Result<Channel, DaemonError> {
+        log::debug!(target: &connectivity_target(), "Trying to connect to REST endpoint: {}", lcd_url);
+
+        let connections = RestGateway::new(lcd_url).spawn_server();
+        let channel = Endpoint::from_static("http://rest.gateway")
+            .connect_with_connector(tower::service_fn(move |_: Uri| {
+                let connections = connections.clone();
+                async move {
+                    let (client, server) = tokio::io::duplex(DUPLEX_BUFFER_SIZE);
+                    connections.send(server).map_err(|_| {
+                        std::io::Error::new(std::io::ErrorKind::BrokenPipe, "REST gateway stopped")
+                    })?;
+                    Ok::<_, std::io::Error>(TokioIo::new(client))
+                }
+            }))
+            .await?;
+
+        // Verify that node is the expected network
+        let node_info = tendermint::service_client::ServiceClient::new(channel.clone())
+            .get_node_info(tendermint::GetNodeInfoRequest {})
+            .await;
+
+        let network = match node_info {
+            Ok(node_info) => node_info
+                .into_inner()
+                .default_node_info
+                .map(|info| info.network)
+                .unwrap_or_default(),
+            Err(e) => {
+                log::warn!("Cannot connect to REST endpoint: {}, {:?}", lcd_url, e);
+                return Err(DaemonError::CannotConnectREST(lcd_url.to_string()));
+            }
+        };
+
+        if network != chain_id {
+            log::error!(
+                "Network mismatch: connection:{} != config:{}",
+                network,
+                chain_id
+            );
+            return Err(DaemonError::CannotConnectREST(lcd_url.to_string()));
+        }
+
+        Ok(channel)
+    }
+}
+
+/// Translates the gRPC requests of a channel into REST requests
+#[derive(Clone)]
+struct RestGateway {
+    client: reqwest::Client,
+    url: String,
+    /// Block height of the request being handled, forwarded to the REST gateway
+    height: Option<String>,
+}
+
+impl RestGateway {
+    fn new(url: &str) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url: url.trim_end_matches('/').to_string(),
+            height: None,
+        }
+    }
+
+    /// Spawns the HTTP/2 server of the gateway.
+    /// It serves the channel connections sent on the returned sender, until the sender is dropped.
+    fn spawn_server(self) -> mpsc::UnboundedSender<DuplexStream> {
+        let (connections, mut incoming) = mpsc::unbounded_channel::<DuplexStream>();
+        let server = hyper::server::conn::http2::Builder::new(TokioExecutor::new());
+        let service = hyper::service::service_fn(move |request: http::Request<Incoming>| {
+            let gateway = self.clone();
+            async move { Ok::<_, Infallible>(gateway.handle(request).await) }
+        });
+
+        tokio::spawn(async move {
+            while let Some(io) = incoming.recv().await {
+                let connection = server.serve_connection(TokioIo::new(io), service.clone());
+                tokio::spawn(async move {
+                    if let Err(e) = connection.await {
+                        log::debug!(target: &connectivity_target(), "REST gateway connection closed: {}", e);
+                    }
+                });
+            }
+        });
+        connections
+    }
+
+    async fn handle(mut self, request: http::Request<Incoming>) -> http::Response<BoxBody> {
+        // The gRPC metadata and the REST gateway use the same header for the query height
+        self.height = request
+            .headers()
+            .get(BLOCK_HEIGHT_METADATA)
+            .and_then(|height| height.to_str().ok())
+            .map(ToString::to_string);
+        let path = request.uri().path().to_string();
+        match path.as_str() {
+            "/cosmos.bank.v1beta1.Query/Balance" => self.unary(request, Self::balance).await,
+            "/cosmos.bank.v1beta1.Query/AllBalances" => {
+                self.unary(request, Self::all_balances).await
+            }
+            "/cosmos.bank.v1beta1.Query/SpendableBalances" => {
+                self.unary(request, Self::spendable_balances).await
+            }
+            "/cosmos.bank.v1beta1.Query/SupplyOf" => self.unary(request, Self::supply_of).await,
+            "/cosmos.bank.v1beta1.Query/TotalSupply" => {
+                self.unary(request, Self::total_supply).await
+            }
+            "/cosmwasm.wasm.v1.Query/SmartContractState" => {
+                self.unary(request, Self::smart_contract_state).await
+            }
+            "/cosmwasm.wasm.v1.Query/RawContractState" => {
+                self.unary(request, Self::raw_contract_state).await
+            }
+            "/cosmwasm.wasm.v1.Query/ContractInfo" => {
+                self.unary(request, Self::contract_info).await
+            }
+            "/cosmwasm.wasm.v1.Query/Code" => self.unary(request, Self::code).await,
+            "/cosmwasm.wasm.v1.Query/Codes" => self.unary(request, Self::codes).await,
+            "/cosmwasm.wasm.v1.Query/ContractsByCode" => {
+                self.unary(request, Self::contracts_by_code).await
+            }
+            "/cosmos.auth.v1beta1.Query/Account" => self.unary(request, Self::account).await,
+            "/cosmos.tx.v1beta1.Service/Simulate" => self.unary(request, Self::simulate).await,
+            "/cosmos.tx.v1beta1.Service/BroadcastTx" => {
+                self.unary(request, Self::broadcast_tx).await
+            }
+            "/cosmos.tx.v1beta1.Service/GetTx" => self.unary(request, Self::get_tx).await,
+            "/cosmos.base.tendermint.v1beta1.Service/GetNodeInfo" => {
+                self.unary(request, Self::node_info).await
+            }
+            "/cosmos.base.tendermint.v1beta1.Service/GetSyncing" => {
+                self.unary(request, Self::syncing).await
+            }
+            "/cosmos.base.tendermint.v1beta1.Service/GetLatestBlock" => {
+                self.unary(request, Self::latest_block).await
+            }
+            "/cosmos.base.tendermint.v1beta1.Service/GetBlockByHeight" => {
+                self.unary(request, Self::block_by_height).await
+            }
+            _ => Status::unimplemented(format!("{path} is not supported by the REST transport"))
+                .into_http(),
+        }
+    }
+
+    /// Decodes the gRPC request, calls the handler and encodes its response
+    async fn unary<Req, Resp, F, Fut>(
+        self,
+        request: http::Request<Incoming>,
+        handler: F,
+    ) -> http::Response<BoxBody>
+    where
+        Req: prost::Message + Default + Send + 'static,
+        Resp: prost::Message + Send + 'static,
+        F: Fn(Self, Req) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Resp, Status>> + Send + 'static,
+    {
+        let service = tower::service_fn(move |request: tonic::Request<Req>| {
+            let response = handler(self.clone(), request.into_inner());
+            async move { response.await.map(tonic::Response::new) }
+        });
+
+        Grpc::new(ProstCodec::<Resp, Req>::default())
+            .unary(service, request)
+            .await
+    }
+
+    async fn get(&self, path: &str, query: &[(&str, String)]) -> Result<Value, Status> {
+        self.send(
+            self.client
+                .get(format!("{}{}", self.url, path))
+                .query(query),
+        )
+        .await
+    }
+
+    async fn post(&self, path: &str, body: Value) -> Result<Value, Status> {
+        self.send(
+            self.client
+                .post(format!("{}{}", self.url, path))
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(body.to_string()),
+        )
+        .await
+    }
+
+    /// Sends the request at the height of the gRPC request and maps the gateway errors back to gRPC statuses
+    async fn send(&self, mut request: reqwest::RequestBuilder) -> Result<Value, Status> {
+        if let Some(height) = &self.height {
+            request = request.header(BLOCK_HEIGHT_METADATA, height);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| Status::unavailable(e.to_string()))?;
+        let status = response.status();
+        let text = response
+            .text()
+            .await
+            .map_err(|e| Status::unavailable(e.to_string()))?;
+
+        let body: Value = serde_json::from_str(&text)
+            .map_err(|_| Status::internal(format!("Invalid REST response ({status}): {text}")))?;
+
+        if status.is_success() {
+            return Ok(body);
+        }
+
+        let code = match body["code"].as_i64() {
+            Some(code) => Code::from_i32(code as i32),
+            None if status == http::StatusCode::NOT_FOUND => Code::NotFound,
+            None => Code::Unknown,
+        };
+        let message = body["message"]
+            .as_str()
+            .map(ToString::to_string)
+            .unwrap_or(text);
+        Err(Status::new(code, message))
+    }
+
+    async fn balance(
+        self,
+        request: bank::QueryBalanceRequest,
+    ) -> Result<bank::QueryBalanceResponse, Status> {
+        let response = self
+            .get(
+                &format!("/cosmos/bank/v1beta1/balances/{}/by_denom", request.address),
+                &[("denom", request.denom)],
+            )
+            .await?;
+        Ok(bank::QueryBalanceResponse {
+            balance: Some(convert::coin(&response["balance"])),
+        })
+    }
+
+    async fn all_balances(
+        self,
+        request: bank::QueryAllBalancesRequest,
+    ) -> Result<bank::QueryAllBalancesResponse, Status> {
+        let response = self
+            .get(
+                &format!("/cosmos/bank/v1beta1/balances/{}", request.address),
+                &convert::page_request(request.pagination),
+            )
+            .await?;
+        Ok(bank::QueryAllBalancesResponse {
+            balances: convert::coins(&response, "balances"),
+            pagination: convert::page_response(&response)?,
+        })
+    }
+
+    async fn spendable_balances(
+        self,
+        request: bank::QuerySpendableBalancesRequest,
+    ) -> Result<bank::QuerySpendableBalancesResponse, Status> {
+        let response = self
+            .get(
+                &format!(
+                    "/cosmos/bank/v1beta1/spendable_balances/{}",
+                    request.address
+                ),
+                &convert::page_request(request.pagination),
+            )
+            .await?;
+        Ok(bank::QuerySpendableBalancesResponse {
+            balances: convert::coins(&response, "balances"),
+            pagination: convert::page_response(&response)?,
+        })
+    }
+
+    async fn supply_of(
+        self,
+        request: bank::QuerySupplyOfRequest,
+    ) -> Result<bank::QuerySupplyOfResponse, Status> {
+        let response = self
+            .get(
+                "/cosmos/bank/v1beta1/supply/by_denom",
+                &[("denom", request.denom)],
+            )
+            .await?;
+        Ok(bank::QuerySupplyOfResponse {
+            amount: Some(convert::coin(&response["amount"])),
+        })
+    }
+
+    async fn total_supply(
+        self,
+        request: bank::QueryTotalSupplyRequest,
+    ) -> Result<bank::QueryTotalSupplyResponse, Status> {
+        let response = self
+            .get(
+                "/cosmos/bank/v1beta1/supply",
+                &convert::page_request(request.pagination),
+            )
+            .await?;
+        Ok(bank::QueryTotalSupplyResponse {
+            supply: convert::coins(&response, "supply"),
+            pagination: convert::page_response(&response)?,
+        })
+    }
+
+    async fn smart_contract_state(
+        self,
+        request: cosmwasm::QuerySmartContractStateRequest,
+    ) -> Result<cosmwasm::QuerySmartContractStateResponse, Status> {
+        let response = self
+            .get(
+                &format!(
+                    "/cosmwasm/wasm/v1/contract/{}/smart/{}",
+                    request.address,
+                    URL_SAFE.encode(request.query_data)
+                ),
+                &[],
+            )
+            .await?;
+        // The gateway returns the contract response as JSON
+        Ok(cosmwasm::QuerySmartContractStateResponse {
+            data: serde_json::to_vec(&response["data"])
+                .map_err(|e| Status::internal(e.to_string()))?,
+        })
+    }
+
+    async fn raw_contract_state(
+        self,
+        request: cosmwasm::QueryRawContractStateRequest,
+    ) -> Result<cosmwasm::QueryRawContractStateResponse, Status> {
+        let response = self
+            .get(
+                &format!(
+                    "/cosmwasm/wasm/v1/contract/{}/raw/{}",
+                    request.address,
+                    URL_SAFE.encode(request.query_data)
+                ),
+                &[],
+            )
+            .await?;
+        Ok(cosmwasm::QueryRawContractStateResponse {
+            data: convert::bytes(&response, "data")?,
+        })
+    }
+
+    async fn contract_info(
+        self,
+        request: cosmwasm::QueryContractInfoRequest,
+    ) -> Result<cosmwasm::QueryContractInfoResponse, Status> {
+        let response = self
+            .get(
+                &format!("/cosmwasm/wasm/v1/contract/{}", request.address),
+                &[],
+            )
+            .await?;
+        let info = &response["contract_info"];
+        Ok(cosmwasm::QueryContractInfoResponse {
+            address: convert::string(&response, "address"),
+            contract_info: Some(cosmwasm::ContractInfo {
+                code_id: convert::int(info, "code_id")?,
+                creator: convert::string(info, "creator"),
+                admin: convert::string(info, "admin"),
+                label: convert::string(info, "label"),
+                ibc_port_id: convert::string(info, "ibc_port_id"),
+                ..Default::default()
+            }),
+        })
+    }
+
+    async fn code(
+        self,
+        request: cosmwasm::QueryCodeRequest,
+    ) -> Result<cosmwasm::QueryCodeResponse, Status> {
+        let response = self
+            .get(&format!("/cosmwasm/wasm/v1/code/{}", request.code_id), &[])
+            .await?;
+        // Depending on the wasmd version, the code info is either nested or inlined
+        let info = match &response["code_info"] {
+            Value::Null => &response,
+            info => info,
+        };
+        Ok(cosmwasm::QueryCodeResponse {
+            code_info: Some(code_info(info)?),
+            data: convert::bytes(&response, "data")?,
+        })
+    }
+
+    async fn codes(
+        self,
+        request: cosmwasm::QueryCodesRequest,
+    ) -> Result<cosmwasm::QueryCodesResponse, Status> {
+        let response = self
+            .get(
+                "/cosmwasm/wasm/v1/code",
+                &convert::page_request(request.pagination),
+            )
+            .await?;
+        Ok(cosmwasm::QueryCodesResponse {
+            code_infos: response["code_infos"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(code_info)
+                .collect::<Result<_, _>>()?,
+            pagination: convert::page_response(&response)?,
+        })
+    }
+
+    async fn contracts_by_code(
+        self,
+        request: cosmwasm::QueryContractsByCodeRequest,
+    ) -> Result<cosmwasm::QueryContractsByCodeResponse, Status> {
+        let response = self
+            .get(
+                &format!("/cosmwasm/wasm/v1/code/{}/contracts", request.code_id),
+                &convert::page_request(request.pagination),
+            )
+            .await?;
+        Ok(cosmwasm::QueryContractsByCodeResponse {
+            contracts: response["contracts"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|contract| contract.as_str().map(ToString::to_string))
+                .collect(),
+            pagination: convert::page_response(&response)?,
+        })
+    }
+
+    async fn account(
+        self,
+        request: auth::QueryAccountRequest,
+    ) -> Result<auth::QueryAccountResponse, Status> {
+        let response = self
+            .get(
+                &format!("/cosmos/auth/v1beta1/accounts/{}", request.address),
+                &[],
+            )
+            .await?;
+        Ok(auth::QueryAccountResponse {
+            account: Some(convert::base_account(&response["account"])?),
+        })
+    }
+
+    async fn simulate(self, request: tx::SimulateRequest) -> Result<tx::SimulateResponse, Status> {
+        let response = self
+            .post(
+                "/cosmos/tx/v1beta1/simulate",
+                json!({ "tx_bytes": STANDARD.encode(request.tx_bytes) }),
+            )
+            .await?;
+        let gas_info = &response["gas_info"];
+        let result = &response["result"];
+        Ok(tx::SimulateResponse {
+            gas_info: Some(cosmos_modules::abci::GasInfo {
+                gas_wanted: convert::int(gas_info, "gas_wanted")?,
+                gas_used: convert::int(gas_info, "gas_used")?,
+            }),
+            result: if result.is_null() {
+                None
+            } else {
+                Some(convert::simulation_result(result)?)
+            },
+        })
+    }
+
+    async fn broadcast_tx(
+        self,
+        request: tx::BroadcastTxRequest,
+    ) -> Result<tx::BroadcastTxResponse, Status> {
+        let mode = tx::BroadcastMode::try_from(request.mode).unwrap_or(tx::BroadcastMode::Sync);
+        let response = self
+            .post(
+                "/cosmos/tx/v1beta1/txs",
+                json!({
+                    "tx_bytes": STANDARD.encode(request.tx_bytes),
+                    "mode": mode.as_str_name(),
+                }),
+            )
+            .await?;
+        Ok(tx::BroadcastTxResponse {
+            tx_response: Some(convert::tx_response(&response["tx_response"])?),
+        })
+    }
+
+    async fn get_tx(self, request: tx::GetTxRequest) -> Result<tx::GetTxResponse, Status> {
+        let response = self
+            .get(&format!("/cosmos/tx/v1beta1/txs/{}", request.hash), &[])
+            .await?;
+        Ok(tx::GetTxResponse {
+            tx: None,
+            tx_response: Some(convert::tx_response(&response["tx_response"])?),
+        })
+    }
+
+    async fn node_info(
+        self,
+        _request: tendermint::GetNodeInfoRequest,
+    ) -> Result<tendermint::GetNodeInfoResponse, Status> {
+        let response = self
+            .get("/cosmos/base/tendermint/v1beta1/node_info", &[])
+            .await?;
+        convert::node_info(&response)
+    }
+
+    async fn syncing(
+        self,
+        _request: tendermint::GetSyncingRequest,
+    ) -> Result<tendermint::GetSyncingResponse, Status> {
+        let response = self
+            .get("/cosmos/base/tendermint/v1beta1/syncing", &[])
+            .await?;
+        Ok(tendermint::GetSyncingResponse {
+            syncing: response["syncing"].as_bool().unwrap_or_default(),
+        })
+    }
+
+    async fn latest_block(
+        self,
+        _request: tendermint::GetLatestBlockRequest,
+    ) -> Result<tendermint::GetLatestBlockResponse, Status> {
+        let response = self
+            .get("/cosmos/base/tendermint/v1beta1/blocks/latest", &[])
+            .await?;
+        let (block_id, block) = convert::block(&response)?;
+        Ok(tendermint::GetLatestBlockResponse {
+            block_id,
+            block,
+            ..Default::default()
+        })
+    }
+
+    async fn block_by_height(
+        self,
+        request: tendermint::GetBlockByHeightRequest,
+    ) -> Result<tendermint::GetBlockByHeightResponse, Status> {
+        let response = self
+            .get(
+                &format!("/cosmos/base/tendermint/v1beta1/blocks/{}", request.height),
+                &[],
+            )
+            .await?;
+        let (block_id, block) = convert::block(&response)?;
+        Ok(tendermint::GetBlockByHeightResponse {
+            block_id,
+            block,
+            ..Default::default()
+        })
+    }
+}
+
+fn code_info(info: &Value) -> Result<cosmwasm::CodeInfoResponse, Status> {
+    Ok(cosmwasm::CodeInfoResponse {
+        code_id: convert::int(info, "code_id")?,
+        creator: convert::string(info, "creator"),
+        data_hash: convert::hash(info, "data_hash")?,
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::queriers::{Bank, Node};
+    use cosmwasm_std::{coin, Addr};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    /// Minimal REST gateway answering a few fixed routes
+    async fn mock_gateway() -> anyhow::Result<String> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut buffer = vec![0; 4096];
+                let read = stream.read(&mut buffer).await.unwrap();
+                let request = String::from_utf8_lossy(&buffer[..read]).to_string();
+                let path = request.split_whitespace().nth(1).unwrap_or_default();
+                let height = request.lines().find_map(|line| {
+                    line.to_lowercase()
+                        .strip_prefix("x-cosmos-block-height: ")
+                        .map(ToString::to_string)
+                });
+
+                let (status, body) = match path {
+                    "/cosmos/base/tendermint/v1beta1/node_info" => (
+                        "200 OK",
+                        json!({ "default_node_info": { "network": "testing" } }),
+                    ),
+                    // The balance is the query height, 100 at the latest height
+                    "/cosmos/bank/v1beta1/balances/juno1test/by_denom?denom=ujuno" => (
+                        "200 OK",
+                        json!({
+                            "balance": {
+                                "denom": "ujuno",
+                                "amount": height.unwrap_or("100".to_string())
+                            }
+                        }),
+                    ),
+                    _ => (
+                        "501 Not Implemented",
+                        json!({ "code": 12, "message": "Not Implemented" }),
+                    ),
+                };
+
+                let body = body.to_string();
+                let response = format!(
+                    "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        Ok(format!("http://{address}"))
+    }
+
+    #[tokio::test]
+    async fn queries_over_rest() -> anyhow::Result<()> {
+        let url = mock_gateway().await?;
+        let channel = RestChannel::connect(&url, "testing").await?;
+
+        let balance = Bank::new_async(channel.clone())
+            ._balance(&Addr::unchecked("juno1test"), Some("ujuno".to_string()))
+            .await?;
+        assert_eq!(balance, vec![coin(100, "ujuno")]);
+
+        // The query height is forwarded to the gateway
+        let balance = Bank::new_async(channel.clone())
+            .at_height(42)
+            ._balance(&Addr::unchecked("juno1test"), Some("ujuno".to_string()))
+            .await?;
+        assert_eq!(balance, vec![coin(42, "ujuno")]);
+
+        // Errors of the gateway are forwarded as gRPC statuses
+        let err = Node::new_async(channel)._syncing().await.unwrap_err();
+        match err {
+            DaemonError::Status(status) => assert_eq!(status.code(), Code::Unimplemented),
+            other => panic!("unexpected error {other:?}"),
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rest_network_mismatch() -> anyhow::Result<()> {
+        let url = mock_gateway().await?;
+        let err = RestChannel::connect(&url, "juno-1").await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("Can not connect to the REST endpoint {url}.")
+        );
+        Ok(())
+    }
+}
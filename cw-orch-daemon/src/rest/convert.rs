@@ -0,0 +1,513 @@
+//! Conversions from the JSON returned by the Cosmos REST gateway to the protobuf types used by the gRPC clients.
+//!
+//! The gateway serializes messages with their proto field names, 64-bit integers as strings and bytes as base64.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use cosmrs::proto::{
+    cosmos::{
+        auth::v1beta1::BaseAccount,
+        base::{
+            abci::v1beta1::{AbciMessageLog, Attribute, StringEvent, TxResponse},
+            query::v1beta1::{PageRequest, PageResponse},
+            v1beta1::Coin,
+        },
+        crypto::secp256k1::PubKey,
+    },
+    tendermint::{
+        google::protobuf::Timestamp,
+        v0_34::{
+            abci::{Event, EventAttribute},
+            p2p::{DefaultNodeInfo, DefaultNodeInfoOther, ProtocolVersion},
+            types::{Block, BlockId, Commit, CommitSig, Data, EvidenceList, Header, PartSetHeader},
+            version::Consensus,
+        },
+    },
+};
+use cosmrs::Any;
+use prost::Message;
+use serde_json::Value;
+use tonic::Status;
+
+use crate::cosmos_modules;
+
+pub(crate) const BASE_ACCOUNT_TYPE_URL: &str = "/cosmos.auth.v1beta1.BaseAccount";
+
+/// String field, empty when missing
+pub(crate) fn string(value: &Value, key: &str) -> String {
+    match &value[key] {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+/// Integer field, encoded either as a JSON number or as a string. Defaults to 0 when missing
+pub(crate) fn int<T: std::str::FromStr + Default>(value: &Value, key: &str) -> Result<T, Status> {
+    match &value[key] {
+        Value::Null => Ok(T::default()),
+        Value::Number(n) => n
+            .to_string()
+            .parse()
+            .map_err(|_| invalid_field(key, &value[key])),
+        Value::String(s) if s.is_empty() => Ok(T::default()),
+        Value::String(s) => s.parse().map_err(|_| invalid_field(key, &value[key])),
+        other => Err(invalid_field(key, other)),
+    }
+}
+
+/// Base64 encoded bytes field, empty when missing
+pub(crate) fn bytes(value: &Value, key: &str) -> Result<Vec<u8>, Status> {
+    match &value[key] {
+        Value::Null => Ok(vec![]),
+        Value::String(s) => STANDARD
+            .decode(s)
+            .map_err(|_| invalid_field(key, &value[key])),
+        other => Err(invalid_field(key, other)),
+    }
+}
+
+/// Hash field. Some hashes are serialized as hex by the gateway, others as base64
+pub(crate) fn hash(value: &Value, key: &str) -> Result<Vec<u8>, Status> {
+    match &value[key] {
+        Value::String(s) if s.len() == 64 && s.chars().all(|c| c.is_ascii_hexdigit()) => {
+            hex::decode(s).map_err(|_| invalid_field(key, &value[key]))
+        }
+        _ => bytes(value, key),
+    }
+}
+
+/// RFC3339 timestamp field
+pub(crate) fn timestamp(value: &Value, key: &str) -> Result<Option<Timestamp>, Status> {
+    let Some(raw) = value[key].as_str() else {
+        return Ok(None);
+    };
+    let time =
+        chrono::DateTime::parse_from_rfc3339(raw).map_err(|_| invalid_field(key, &value[key]))?;
+    Ok(Some(Timestamp {
+        seconds: time.timestamp(),
+        nanos: time.timestamp_subsec_nanos() as i32,
+    }))
+}
+
+fn array<'a>(value: &'a Value, key: &str) -> impl Iterator<Item = &'a Value> {
+    value[key].as_array().into_iter().flatten()
+}
+
+fn invalid_field(key: &str, value: &Value) -> Status {
+    Status::internal(format!(
+        "Unexpected value for `{key}` in REST response: {value}"
+    ))
+}
+
+pub(crate) fn coin(value: &Value) -> Coin {
+    Coin {
+        denom: string(value, "denom"),
+        amount: string(value, "amount"),
+    }
+}
+
+pub(crate) fn coins(value: &Value, key: &str) -> Vec<Coin> {
+    array(value, key).map(coin).collect()
+}
+
+/// Query parameters of a paginated request
+pub(crate) fn page_request(pagination: Option<PageRequest>) -> Vec<(&'static str, String)> {
+    let Some(pagination) = pagination else {
+        return vec![];
+    };
+    let mut params = vec![];
+    if !pagination.key.is_empty() {
+        params.push(("pagination.key", STANDARD.encode(pagination.key)));
+    }
+    if pagination.offset != 0 {
+        params.push(("pagination.offset", pagination.offset.to_string()));
+    }
+    if pagination.limit != 0 {
+        params.push(("pagination.limit", pagination.limit.to_string()));
+    }
+    if pagination.count_total {
+        params.push(("pagination.count_total", "true".to_string()));
+    }
+    if pagination.reverse {
+        params.push(("pagination.reverse", "true".to_string()));
+    }
+    params
+}
+
+pub(crate) fn page_response(value: &Value) -> Result<Option<PageResponse>, Status> {
+    let pagination = &value["pagination"];
+    if pagination.is_null() {
+        return Ok(None);
+    }
+    Ok(Some(PageResponse {
+        next_key: bytes(pagination, "next_key")?,
+        total: int(pagination, "total")?,
+    }))
+}
+
+/// Converts an account to a [`BaseAccount`].
+/// Vesting and module specific accounts are unwrapped to the base account they contain.
+pub(crate) fn base_account(account: &Value) -> Result<Any, Status> {
+    let base = find_base_account(account)
+        .ok_or_else(|| Status::internal(format!("No base account found in {account}")))?;
+
+    let pub_key = &base["pub_key"];
+    let pub_key = if pub_key.is_null() {
+        None
+    } else {
+        Some(Any {
+            type_url: string(pub_key, "@type"),
+            value: PubKey {
+                key: bytes(pub_key, "key")?,
+            }
+            .encode_to_vec(),
+        })
+    };
+
+    let account = BaseAccount {
+        address: string(base, "address"),
+        pub_key,
+        account_number: int(base, "account_number")?,
+        sequence: int(base, "sequence")?,
+    };
+
+    Ok(Any {
+        type_url: BASE_ACCOUNT_TYPE_URL.to_string(),
+        value: account.encode_to_vec(),
+    })
+}
+
+fn find_base_account(value: &Value) -> Option<&Value> {
+    if value.get("account_number").is_some() {
+        return Some(value);
+    }
+    value
+        .as_object()?
+        .values()
+        .filter(|v| v.is_object())
+        .find_map(find_base_account)
+}
+
+pub(crate) fn tx_response(value: &Value) -> Result<TxResponse, Status> {
+    let logs = array(value, "logs")
+        .map(|log| {
+            Ok(AbciMessageLog {
+                msg_index: int(log, "msg_index")?,
+                log: string(log, "log"),
+                events: array(log, "events")
+                    .map(|event| StringEvent {
+                        r#type: string(event, "type"),
+                        attributes: array(event, "attributes")
+                            .map(|attribute| Attribute {
+                                key: string(attribute, "key"),
+                                value: string(attribute, "value"),
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            })
+        })
+        .collect::<Result<_, Status>>()?;
+
+    Ok(TxResponse {
+        height: int(value, "height")?,
+        txhash: string(value, "txhash"),
+        codespace: string(value, "codespace"),
+        code: int(value, "code")?,
+        data: string(value, "data"),
+        raw_log: string(value, "raw_log"),
+        logs,
+        info: string(value, "info"),
+        gas_wanted: int(value, "gas_wanted")?,
+        gas_used: int(value, "gas_used")?,
+        // The decoded tx can't be converted back to its protobuf form without the type registry
+        tx: None,
+        timestamp: string(value, "timestamp"),
+        events: events(value),
+    })
+}
+
+/// Result of a simulated transaction
+#[allow(deprecated)]
+pub(crate) fn simulation_result(value: &Value) -> Result<cosmos_modules::abci::Result, Status> {
+    Ok(cosmos_modules::abci::Result {
+        data: bytes(value, "data")?,
+        log: string(value, "log"),
+        events: events(value),
+        // The decoded responses can't be converted back to their protobuf form without the type registry
+        msg_responses: vec![],
+    })
+}
+
+fn events(value: &Value) -> Vec<Event> {
+    array(value, "events")
+        .map(|event| Event {
+            r#type: string(event, "type"),
+            attributes: array(event, "attributes")
+                .map(|attribute| EventAttribute {
+                    key: string(attribute, "key").into_bytes().into(),
+                    value: string(attribute, "value").into_bytes().into(),
+                    index: attribute["index"].as_bool().unwrap_or_default(),
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+pub(crate) fn node_info(
+    value: &Value,
+) -> Result<cosmos_modules::tendermint::GetNodeInfoResponse, Status> {
+    let node = &value["default_node_info"];
+    let protocol = &node["protocol_version"];
+    let app = &value["application_version"];
+
+    Ok(cosmos_modules::tendermint::GetNodeInfoResponse {
+        default_node_info: Some(DefaultNodeInfo {
+            protocol_version: Some(ProtocolVersion {
+                p2p: int(protocol, "p2p")?,
+                block: int(protocol, "block")?,
+                app: int(protocol, "app")?,
+            }),
+            default_node_id: string(node, "default_node_id"),
+            listen_addr: string(node, "listen_addr"),
+            network: string(node, "network"),
+            version: string(node, "version"),
+            channels: bytes(node, "channels")?,
+            moniker: string(node, "moniker"),
+            other: Some(DefaultNodeInfoOther {
+                tx_index: string(&node["other"], "tx_index"),
+                rpc_address: string(&node["other"], "rpc_address"),
+            }),
+        }),
+        application_version: Some(cosmos_modules::tendermint::VersionInfo {
+            name: string(app, "name"),
+            app_name: string(app, "app_name"),
+            version: string(app, "version"),
+            git_commit: string(app, "git_commit"),
+            build_tags: string(app, "build_tags"),
+            go_version: string(app, "go_version"),
+            cosmos_sdk_version: string(app, "cosmos_sdk_version"),
+            ..Default::default()
+        }),
+    })
+}
+
+fn block_id(value: &Value) -> Result<Option<BlockId>, Status> {
+    if value.is_null() {
+        return Ok(None);
+    }
+    let part_set_header = &value["part_set_header"];
+    Ok(Some(BlockId {
+        hash: hash(value, "hash")?,
+        part_set_header: Some(PartSetHeader {
+            total: int(part_set_header, "total")?,
+            hash: hash(part_set_header, "hash")?,
+        }),
+    }))
+}
+
+fn block_id_flag(value: &Value) -> Result<i32, Status> {
+    match &value["block_id_flag"] {
+        Value::String(flag) => match flag.as_str() {
+            "BLOCK_ID_FLAG_UNKNOWN" => Ok(0),
+            "BLOCK_ID_FLAG_ABSENT" => Ok(1),
+            "BLOCK_ID_FLAG_COMMIT" => Ok(2),
+            "BLOCK_ID_FLAG_NIL" => Ok(3),
+            _ => int(value, "block_id_flag"),
+        },
+        _ => int(value, "block_id_flag"),
+    }
+}
+
+/// Converts a block. Evidences are not converted.
+pub(crate) fn block(value: &Value) -> Result<(Option<BlockId>, Option<Block>), Status> {
+    let block = &value["block"];
+    if block.is_null() {
+        return Ok((block_id(&value["block_id"])?, None));
+    }
+    let header = &block["header"];
+    let version = &header["version"];
+    let last_commit = &block["last_commit"];
+
+    let txs = array(&block["data"], "txs")
+        .map(|tx| {
+            tx.as_str()
+                .and_then(|tx| STANDARD.decode(tx).ok())
+                .ok_or_else(|| invalid_field("txs", tx))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let last_commit = if last_commit.is_null() {
+        None
+    } else {
+        Some(Commit {
+            height: int(last_commit, "height")?,
+            round: int(last_commit, "round")?,
+            block_id: block_id(&last_commit["block_id"])?,
+            signatures: array(last_commit, "signatures")
+                .map(|signature| {
+                    Ok(CommitSig {
+                        block_id_flag: block_id_flag(signature)?,
+                        validator_address: bytes(signature, "validator_address")?,
+                        timestamp: timestamp(signature, "timestamp")?,
+                        signature: bytes(signature, "signature")?,
+                    })
+                })
+                .collect::<Result<_, Status>>()?,
+        })
+    };
+
+    let block = Block {
+        header: Some(Header {
+            version: Some(Consensus {
+                block: int(version, "block")?,
+                app: int(version, "app")?,
+            }),
+            chain_id: string(header, "chain_id"),
+            height: int(header, "height")?,
+            time: timestamp(header, "time")?,
+            last_block_id: block_id(&header["last_block_id"])?,
+            last_commit_hash: hash(header, "last_commit_hash")?,
+            data_hash: hash(header, "data_hash")?,
+            validators_hash: hash(header, "validators_hash")?,
+            next_validators_hash: hash(header, "next_validators_hash")?,
+            consensus_hash: hash(header, "consensus_hash")?,
+            app_hash: hash(header, "app_hash")?,
+            last_results_hash: hash(header, "last_results_hash")?,
+            evidence_hash: hash(header, "evidence_hash")?,
+            proposer_address: bytes(header, "proposer_address")?,
+        }),
+        data: Some(Data { txs }),
+        evidence: Some(EvidenceList::default()),
+        last_commit,
+    };
+
+    Ok((block_id(&value["block_id"])?, Some(block)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn integers_and_bytes() -> anyhow::Result<()> {
+        let value = json!({ "a": "12", "b": 13, "c": "aGVsbG8=", "d": "" });
+
+        assert_eq!(int::<u64>(&value, "a")?, 12);
+        assert_eq!(int::<i64>(&value, "b")?, 13);
+        assert_eq!(int::<u64>(&value, "d")?, 0);
+        assert_eq!(int::<u64>(&value, "missing")?, 0);
+        assert!(int::<u64>(&value, "c").is_err());
+        assert_eq!(bytes(&value, "c")?, b"hello".to_vec());
+        Ok(())
+    }
+
+    #[test]
+    fn vesting_account_is_unwrapped() -> anyhow::Result<()> {
+        let account = json!({
+            "@type": "/cosmos.vesting.v1beta1.PeriodicVestingAccount",
+            "base_vesting_account": {
+                "base_account": {
+                    "address": "juno1...",
+                    "pub_key": {
+                        "@type": "/cosmos.crypto.secp256k1.PubKey",
+                        "key": "aGVsbG8="
+                    },
+                    "account_number": "42",
+                    "sequence": "7"
+                },
+                "original_vesting": []
+            }
+        });
+
+        let any = base_account(&account)?;
+        assert_eq!(any.type_url, BASE_ACCOUNT_TYPE_URL);
+
+        let account = BaseAccount::decode(any.value.as_slice())?;
+        assert_eq!(account.account_number, 42);
+        assert_eq!(account.sequence, 7);
+        assert_eq!(
+            account.pub_key.unwrap().type_url,
+            "/cosmos.crypto.secp256k1.PubKey"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn tx_response_events() -> anyhow::Result<()> {
+        let response = json!({
+            "height": "100",
+            "txhash": "ABCD",
+            "code": 0,
+            "gas_wanted": "200000",
+            "gas_used": "150000",
+            "timestamp": "2024-08-01T10:00:00Z",
+            "events": [{
+                "type": "wasm",
+                "attributes": [{ "key": "action", "value": "increment", "index": true }]
+            }]
+        });
+
+        let response = tx_response(&response)?;
+        assert_eq!(response.height, 100);
+        assert_eq!(response.gas_used, 150_000);
+        assert_eq!(response.events[0].r#type, "wasm");
+        assert_eq!(
+            response.events[0].attributes[0].value.as_ref(),
+            b"increment"
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn simulation_result_events() -> anyhow::Result<()> {
+        let result = json!({
+            "log": "",
+            "events": [{
+                "type": "message",
+                "attributes": [{ "key": "sender", "value": "juno1", "index": true }]
+            }],
+            "msg_responses": [{ "@type": "/cosmwasm.wasm.v1.MsgExecuteContractResponse" }]
+        });
+
+        let result = simulation_result(&result)?;
+        assert!(result.data.is_empty());
+        assert_eq!(result.events[0].r#type, "message");
+        assert_eq!(result.events[0].attributes[0].key.as_ref(), b"sender");
+        assert!(result.events[0].attributes[0].index);
+        Ok(())
+    }
+
+    #[test]
+    fn block_is_converted() -> anyhow::Result<()> {
+        let response = json!({
+            "block_id": {
+                "hash": "3q2+7w==",
+                "part_set_header": { "total": 1, "hash": "3q2+7w==" }
+            },
+            "block": {
+                "header": {
+                    "version": { "block": "11", "app": "0" },
+                    "chain_id": "testing",
+                    "height": "1",
+                    "time": "2024-08-01T10:00:00.123456789Z",
+                    "proposer_address": "AAAAAAAAAAAAAAAAAAAAAAAAAAA="
+                },
+                "data": { "txs": ["aGVsbG8="] },
+                "evidence": { "evidence": [] },
+                "last_commit": null
+            }
+        });
+
+        let (_, converted) = block(&response)?;
+        let converted = converted.unwrap();
+        let header = converted.header.unwrap();
+        assert_eq!(header.height, 1);
+        assert_eq!(header.chain_id, "testing");
+        assert_eq!(header.time.unwrap().nanos, 123456789);
+        assert_eq!(converted.data.unwrap().txs, vec![b"hello".to_vec()]);
+        Ok(())
+    }
+}
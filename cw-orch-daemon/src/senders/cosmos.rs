@@ -1,7 +1,11 @@
+#[cfg(feature = "keystore")]
+use super::cosmos_options::Keystore;
 use super::{
-    cosmos_options::CosmosWalletKey,
+    cosmos_options::{CosmosWalletKey, FeeDenom},
     query::QuerySender,
-    sign::{Signer, SigningAccount},
+    remote_signer::RemoteSigner,
+    sequence::SequenceManager,
+    sign::{simulation_tx, Signer, SigningAccount},
     tx::TxSender,
 };
 use crate::{
@@ -9,8 +13,9 @@ use crate::{
     env::{DaemonEnvVars, LOCAL_MNEMONIC_ENV_NAME, MAIN_MNEMONIC_ENV_NAME, TEST_MNEMONIC_ENV_NAME},
     error::DaemonError,
     keys::private::PrivateKey,
-    proto::injective::{InjectiveEthAccount, ETHEREUM_COIN_TYPE},
+    proto::injective::InjectiveEthAccount,
     queriers::{Bank, Node},
+    tx_broadcaster::RetryPolicy,
     tx_builder::TxBuilder,
     tx_resp::CosmTxResponse,
     upload_wasm, CosmosOptions, GrpcChannel,
@@ -19,7 +24,7 @@ use bitcoin::secp256k1::{All, Secp256k1, Signing};
 use cosmos_modules::vesting::PeriodicVestingAccount;
 use cosmrs::{
     crypto::secp256k1::SigningKey,
-    proto::traits::Message,
+    proto::{cosmos::tx::v1beta1::TxRaw, traits::Message},
     tendermint::chain::Id,
     tx::{self, Fee, ModeInfo, Msg, Raw, SignDoc, SignMode, SignerInfo, SignerPublicKey},
     AccountId, Any,
@@ -27,7 +32,8 @@ use cosmrs::{
 use cosmwasm_std::{coin, Addr, Coin};
 use cw_orch_core::{
     contract::WasmPath,
-    environment::{AccessConfig, ChainInfoOwned, ChainKind},
+    environment::{AccessConfig, ChainInfoOwned, ChainKind, KeyAlgorithmOwned},
+    log::transaction_target,
     CoreEnvVars, CwEnvError,
 };
 use std::sync::Arc;
@@ -36,10 +42,6 @@ use tonic::transport::Channel;
 #[cfg(feature = "eth")]
 use crate::proto::injective::InjectiveSigner;
 
-const GAS_BUFFER: f64 = 1.3;
-const BUFFER_THRESHOLD: u64 = 200_000;
-const SMALL_GAS_BUFFER: f64 = 1.4;
-
 /// A wallet is a sender of transactions, can be safely cloned and shared within the same thread.
 pub type Wallet = CosmosSender<All>;
 
@@ -47,13 +49,34 @@ pub type Wallet = CosmosSender<All>;
 /// This is the main interface for simulating and signing transactions
 #[derive(Clone)]
 pub struct CosmosSender<C: Signing + Clone> {
-    pub private_key: PrivateKey,
+    pub(crate) key: SenderKey,
     /// gRPC channel
     pub grpc_channel: Channel,
     /// Information about the chain
     pub chain_info: Arc<ChainInfoOwned>,
     pub(crate) options: CosmosOptions,
     pub secp: Secp256k1<C>,
+    /// Locally tracked account sequence, shared between clones of this sender
+    pub(crate) sequence_manager: Option<SequenceManager>,
+}
+
+/// Key signing the transactions of a [CosmosSender]
+#[derive(Clone)]
+pub(crate) enum SenderKey {
+    Local(Box<PrivateKey>),
+    Remote(Arc<dyn RemoteSigner>),
+}
+
+impl SenderKey {
+    /// The key algorithm is a property of the network, it's applied to every local key of the sender
+    fn with_key_algorithm(self, key_algorithm: &KeyAlgorithmOwned) -> Self {
+        match self {
+            SenderKey::Local(private_key) => SenderKey::Local(Box::new(
+                private_key.with_key_algorithm(key_algorithm.clone()),
+            )),
+            remote => remote,
+        }
+    }
 }
 
 impl Wallet {
@@ -61,6 +84,10 @@ impl Wallet {
         chain_info: &Arc<ChainInfoOwned>,
         options: CosmosOptions,
     ) -> Result<Wallet, DaemonError> {
+        // Fees would be computed as 0 without gas price
+        if chain_info.gas_price.is_nan() {
+            return Err(DaemonError::GasPriceError(chain_info.gas_denom.clone()));
+        }
         let secp = Secp256k1::new();
 
         let pk_from_mnemonic = |mnemonic: &str| -> Result<PrivateKey, DaemonError> {
@@ -73,32 +100,68 @@ impl Wallet {
             )
         };
 
-        let pk: PrivateKey = match &options.key {
-            CosmosWalletKey::Mnemonic(mnemonic) => pk_from_mnemonic(mnemonic)?,
+        let key = match &options.key {
+            CosmosWalletKey::Mnemonic(mnemonic) => {
+                SenderKey::Local(Box::new(pk_from_mnemonic(mnemonic)?))
+            }
             CosmosWalletKey::Env => {
                 let mnemonic = get_mnemonic_env(&chain_info.kind)?;
-                pk_from_mnemonic(&mnemonic)?
+                SenderKey::Local(Box::new(pk_from_mnemonic(&mnemonic)?))
             }
-            CosmosWalletKey::RawKey(bytes) => PrivateKey::from_raw_key(
+            CosmosWalletKey::RawKey(bytes) => SenderKey::Local(Box::new(PrivateKey::from_raw_key(
                 &secp,
                 bytes,
                 0,
                 options.hd_index.unwrap_or(0),
                 chain_info.network_info.coin_type,
-            )?,
-        };
+            )?)),
+            #[cfg(feature = "keystore")]
+            CosmosWalletKey::Keystore {
+                keystore,
+                passphrase,
+            } => {
+                let passphrase = match passphrase {
+                    Some(passphrase) => passphrase.clone(),
+                    None => keystore_passphrase()?,
+                };
+                let coin_type = chain_info.network_info.coin_type;
+                SenderKey::Local(Box::new(match keystore {
+                    Keystore::CosmosKeyring { dir, name } => {
+                        PrivateKey::from_keyring_file(&secp, dir, name, &passphrase, coin_type)?
+                    }
+                    Keystore::Ethereum { path } => {
+                        PrivateKey::from_eth_keystore(path, &passphrase, coin_type)?
+                    }
+                }))
+            }
+            CosmosWalletKey::Remote(remote_signer) => {
+                if chain_info.network_info.key_algorithm.is_eth() {
+                    return Err(DaemonError::StdErr(
+                        "Remote signers only support secp256k1 keys".to_string(),
+                    ));
+                }
+                SenderKey::Remote(remote_signer.clone())
+            }
+        }
+        .with_key_algorithm(&chain_info.network_info.key_algorithm);
 
         // ensure address is valid
-        AccountId::new(
-            &chain_info.network_info.pub_address_prefix,
-            &pk.public_key(&secp).raw_address.unwrap(),
-        )?;
+        match &key {
+            SenderKey::Local(pk) => AccountId::new(
+                &chain_info.network_info.pub_address_prefix,
+                &pk.public_key(&secp).raw_address.unwrap(),
+            )?,
+            SenderKey::Remote(remote_signer) => remote_signer
+                .public_key()
+                .account_id(&chain_info.network_info.pub_address_prefix)?,
+        };
 
         Ok(Self {
             chain_info: chain_info.clone(),
             grpc_channel: GrpcChannel::from_chain_info(chain_info.as_ref()).await?,
-            private_key: pk,
+            key,
             secp,
+            sequence_manager: options.manage_sequence.then(SequenceManager::default),
             options,
         })
     }
@@ -124,7 +187,18 @@ impl Wallet {
     }
 
     pub fn public_key(&self) -> Option<SignerPublicKey> {
-        self.private_key.get_signer_public_key(&self.secp)
+        match &self.key {
+            SenderKey::Local(private_key) => private_key.get_signer_public_key(&self.secp),
+            SenderKey::Remote(remote_signer) => Some(remote_signer.public_key().into()),
+        }
+    }
+
+    /// Private key of the sender, `None` when transactions are signed by a [RemoteSigner]
+    pub fn private_key(&self) -> Option<&PrivateKey> {
+        match &self.key {
+            SenderKey::Local(private_key) => Some(private_key.as_ref()),
+            SenderKey::Remote(_) => None,
+        }
     }
 
     /// Replaces the private key that the [CosmosSender] is using with key derived from the provided 24-word mnemonic.
@@ -146,7 +220,20 @@ impl Wallet {
     /// Replaces the private key the sender is using
     /// You can use a mnemonic to overwrite the key using [Self::set_mnemonic]
     pub fn set_private_key(&mut self, private_key: PrivateKey) {
-        self.private_key = private_key
+        self.set_key(SenderKey::Local(Box::new(private_key)));
+    }
+
+    /// Signs the transactions with `remote_signer` instead of a local private key
+    pub fn set_remote_signer(&mut self, remote_signer: impl RemoteSigner + 'static) {
+        self.set_key(SenderKey::Remote(Arc::new(remote_signer)));
+    }
+
+    fn set_key(&mut self, key: SenderKey) {
+        self.key = key.with_key_algorithm(&self.chain_info.network_info.key_algorithm);
+        // The tracked sequence belongs to the previous account
+        if self.sequence_manager.is_some() {
+            self.sequence_manager = Some(SequenceManager::default());
+        }
     }
 
     pub fn set_authz_granter(&mut self, granter: &Addr) {
@@ -175,12 +262,7 @@ impl Wallet {
             self.options.fee_granter.clone(),
         )?;
 
-        let auth_info = SignerInfo {
-            public_key: self.private_key.get_signer_public_key(&self.secp),
-            mode_info: ModeInfo::single(SignMode::Direct),
-            sequence,
-        }
-        .auth_info(fee);
+        let auth_info = self.signer_info(sequence).auth_info(fee);
 
         let sign_doc = SignDoc::new(
             tx_body,
@@ -189,7 +271,7 @@ impl Wallet {
             account_number,
         )?;
 
-        let tx_raw = self.sign(sign_doc)?;
+        let tx_raw = simulation_tx(sign_doc);
 
         Node::new_async(self.channel())
             ._simulate_tx(tx_raw.to_bytes()?)
@@ -207,12 +289,21 @@ impl Wallet {
 
         let tx_body = TxBuilder::build_body(msgs, memo, timeout_height);
 
-        let tx_builder = TxBuilder::new(tx_body);
+        // Reading the managed sequence doesn't reserve it
+        let SigningAccount {
+            account_number,
+            sequence,
+        } = Signer::signing_account(self).await?;
 
-        let gas_needed = tx_builder.simulate(self).await?;
+        let gas_needed = self
+            .calculate_gas(&tx_body, sequence, account_number)
+            .await?;
 
-        let (gas_for_submission, fee_amount) = self.get_fee_from_gas(gas_needed)?;
-        let expected_fee = coin(fee_amount, self.get_fee_token());
+        let (gas_for_submission, fee_amount, fee_denom) = self.fee_from_gas(gas_needed).await?;
+        let expected_fee = coin(
+            fee_amount,
+            fee_denom.unwrap_or_else(|| self.get_fee_token()),
+        );
         // During simulation, we also make sure the account has enough balance to submit the transaction
         // This is disabled by an env variable
         if DaemonEnvVars::wallet_balance_assertion() {
@@ -264,10 +355,23 @@ impl Wallet {
         Ok(acc)
     }
 
+    async fn fetch_signing_account(&self) -> Result<SigningAccount, DaemonError> {
+        let BaseAccount {
+            account_number,
+            sequence,
+            ..
+        } = self.base_account().await?;
+
+        Ok(SigningAccount {
+            account_number,
+            sequence,
+        })
+    }
+
     /// Allows for checking wether the sender is able to broadcast a transaction that necessitates the provided `gas`
     pub async fn has_enough_balance_for_gas(&self, gas: u64) -> Result<(), DaemonError> {
-        let (_gas_expected, fee_amount) = self.get_fee_from_gas(gas)?;
-        let fee_denom = self.get_fee_token();
+        let (_gas_expected, fee_amount, fee_denom) = self.fee_from_gas(gas).await?;
+        let fee_denom = fee_denom.unwrap_or_else(|| self.get_fee_token());
 
         self.assert_wallet_balance(&coin(fee_amount, fee_denom))
             .await
@@ -335,27 +439,29 @@ impl Wallet {
         self.chain_info.gas_denom.to_string()
     }
 
-    fn cosmos_private_key(&self) -> SigningKey {
-        SigningKey::from_slice(&self.private_key.raw_key()).unwrap()
-    }
-
-    /// Compute the gas fee from the expected gas in the transaction
-    /// Applies a Gas Buffer for including signature verification
-    pub(crate) fn get_fee_from_gas(&self, gas: u64) -> Result<(u64, u128), DaemonError> {
-        let mut gas_expected = if let Some(gas_buffer) = DaemonEnvVars::gas_buffer() {
-            gas as f64 * gas_buffer
-        } else if gas < BUFFER_THRESHOLD {
-            gas as f64 * SMALL_GAS_BUFFER
-        } else {
-            gas as f64 * GAS_BUFFER
-        };
-
-        let min_gas = DaemonEnvVars::min_gas();
-        gas_expected = (min_gas as f64).max(gas_expected);
-
-        let fee_amount = gas_expected * (self.chain_info.gas_price + 0.00001);
+    /// Denoms the wallet can pay fees with and their current gas price, in order of preference
+    async fn fee_denoms(&self) -> Result<Vec<(String, f64)>, DaemonError> {
+        if self.options.fee_denoms.is_empty() {
+            return Ok(vec![(
+                self.get_fee_token(),
+                self.current_gas_price().await?,
+            )]);
+        }
 
-        Ok((gas_expected as u64, fee_amount as u128))
+        let mut fee_denoms = vec![];
+        for FeeDenom { denom, gas_price } in &self.options.fee_denoms {
+            let gas_price = match &self.options.dynamic_gas_price {
+                // Oracles only know the price of the gas denom of the chain
+                Some(dynamic_gas_price) if denom == &self.chain_info.gas_denom => {
+                    dynamic_gas_price
+                        .gas_price(self.channel(), denom, *gas_price)
+                        .await
+                }
+                _ => *gas_price,
+            };
+            fee_denoms.push((denom.clone(), gas_price));
+        }
+        Ok(fee_denoms)
     }
 }
 
@@ -383,6 +489,19 @@ impl QuerySender for Wallet {
     fn channel(&self) -> Channel {
         self.channel()
     }
+
+    #[cfg(feature = "websocket")]
+    fn websocket_url(&self) -> Option<String> {
+        self.options.websocket_url.clone()
+    }
+}
+
+/// Reads the keystore passphrase from the environment
+#[cfg(feature = "keystore")]
+fn keystore_passphrase() -> Result<String, CwEnvError> {
+    DaemonEnvVars::keystore_passphrase().ok_or(CwEnvError::EnvVarNotPresentNamed(
+        crate::env::KEYSTORE_PASSPHRASE_ENV_NAME.to_string(),
+    ))
 }
 
 fn get_mnemonic_env(chain_kind: &ChainKind) -> Result<String, CwEnvError> {
@@ -407,17 +526,29 @@ fn get_mnemonic_env_name(chain_kind: &ChainKind) -> &str {
 }
 
 impl Signer for Wallet {
-    fn sign(&self, sign_doc: SignDoc) -> Result<Raw, DaemonError> {
-        let tx_raw = if self.private_key.coin_type == ETHEREUM_COIN_TYPE {
+    async fn sign(&self, sign_doc: SignDoc) -> Result<Raw, DaemonError> {
+        let private_key = match &self.key {
+            SenderKey::Local(private_key) => private_key,
+            SenderKey::Remote(remote_signer) => {
+                let body_bytes = sign_doc.body_bytes.clone();
+                let auth_info_bytes = sign_doc.auth_info_bytes.clone();
+                let signature = remote_signer.sign_bytes(&sign_doc.into_bytes()?).await?;
+                return Ok(TxRaw {
+                    body_bytes,
+                    auth_info_bytes,
+                    signatures: vec![signature],
+                }
+                .into());
+            }
+        };
+
+        let tx_raw = if private_key.key_algorithm.is_eth() {
             #[cfg(not(feature = "eth"))]
-            panic!(
-                "Coin Type {} not supported without eth feature",
-                ETHEREUM_COIN_TYPE
-            );
+            panic!("eth_secp256k1 keys are not supported without the eth feature");
             #[cfg(feature = "eth")]
-            self.private_key.sign_injective(sign_doc)?
+            private_key.sign_injective(sign_doc)?
         } else {
-            sign_doc.sign(&self.cosmos_private_key())?
+            sign_doc.sign(&SigningKey::from_slice(&private_key.raw_key()).unwrap())?
         };
         Ok(tx_raw)
     }
@@ -428,7 +559,7 @@ impl Signer for Wallet {
 
     fn signer_info(&self, sequence: u64) -> SignerInfo {
         SignerInfo {
-            public_key: self.private_key.get_signer_public_key(&self.secp),
+            public_key: self.public_key(),
             mode_info: ModeInfo::single(SignMode::Direct),
             sequence,
         }
@@ -443,28 +574,126 @@ impl Signer for Wallet {
         )
     }
 
+    fn build_fee_with_denom(
+        &self,
+        amount: impl Into<u128>,
+        denom: Option<&str>,
+        gas_limit: u64,
+    ) -> Result<Fee, DaemonError> {
+        TxBuilder::build_fee(
+            amount,
+            denom.unwrap_or(&self.get_fee_token()),
+            gas_limit,
+            self.options.fee_granter.clone(),
+        )
+    }
+
     async fn signing_account(&self) -> Result<super::sign::SigningAccount, DaemonError> {
-        let BaseAccount {
-            account_number,
-            sequence,
-            ..
-        } = self.base_account().await?;
+        match &self.sequence_manager {
+            Some(sequence_manager) => {
+                sequence_manager
+                    .current_account(self.fetch_signing_account())
+                    .await
+            }
+            None => self.fetch_signing_account().await,
+        }
+    }
 
-        Ok(SigningAccount {
-            account_number,
-            sequence,
-        })
+    async fn reserve_signing_account(&self) -> Result<SigningAccount, DaemonError> {
+        match &self.sequence_manager {
+            Some(sequence_manager) => {
+                sequence_manager
+                    .next_account(self.fetch_signing_account())
+                    .await
+            }
+            None => self.fetch_signing_account().await,
+        }
+    }
+
+    async fn release_sequence(&self, sequence: u64) {
+        if let Some(sequence_manager) = &self.sequence_manager {
+            sequence_manager.release(sequence).await;
+        }
+    }
+
+    async fn on_sequence_accepted(&self, sequence: u64) {
+        if let Some(sequence_manager) = &self.sequence_manager {
+            sequence_manager.accepted(sequence).await;
+        }
+    }
+
+    async fn on_sequence_mismatch(&self, expected: Option<u64>) {
+        if let Some(sequence_manager) = &self.sequence_manager {
+            log::debug!(
+                target: &transaction_target(),
+                "Resynchronizing account sequence, expected by the node: {:?}",
+                expected
+            );
+            sequence_manager.resync(expected).await;
+        }
     }
 
     fn gas_price(&self) -> Result<f64, DaemonError> {
         Ok(self.chain_info.gas_price)
     }
 
+    async fn fee_from_gas(&self, gas: u64) -> Result<(u64, u128, Option<String>), DaemonError> {
+        let mut fees = self
+            .fee_denoms()
+            .await?
+            .into_iter()
+            .map(|(denom, gas_price)| {
+                TxBuilder::get_fee_from_gas(gas, gas_price)
+                    .map(|(gas_limit, fee_amount)| (gas_limit, fee_amount, denom))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // With several fee denoms, we pick the first one the fee payer has enough balance of.
+        // The fee granter pays the fees when there is one
+        if fees.len() > 1 {
+            let fee_payer = self
+                .options
+                .fee_granter
+                .clone()
+                .unwrap_or_else(|| self.address());
+            let balances = Bank::new_async(self.channel())
+                ._balance(&fee_payer, None)
+                .await?;
+            let affordable = fees.iter().position(|(_, fee_amount, denom)| {
+                balances
+                    .iter()
+                    .any(|balance| &balance.denom == denom && balance.amount.u128() >= *fee_amount)
+            });
+            if let Some(index) = affordable {
+                let (gas_limit, fee_amount, denom) = fees.swap_remove(index);
+                log::debug!(target: &transaction_target(), "Paying fees in {}", denom);
+                return Ok((gas_limit, fee_amount, Some(denom)));
+            }
+        }
+
+        // Fees is never empty, there is always at least one fee denom
+        let (gas_limit, fee_amount, denom) = fees.swap_remove(0);
+        Ok((gas_limit, fee_amount, Some(denom)))
+    }
+
+    async fn current_gas_price(&self) -> Result<f64, DaemonError> {
+        match &self.options.dynamic_gas_price {
+            Some(dynamic_gas_price) => Ok(dynamic_gas_price
+                .gas_price(self.channel(), &self.get_fee_token(), self.gas_price()?)
+                .await),
+            None => self.gas_price(),
+        }
+    }
+
     fn account_id(&self) -> AccountId {
-        AccountId::new(
-            &self.chain_info.network_info.pub_address_prefix,
-            &self.private_key.public_key(&self.secp).raw_address.unwrap(),
-        )
+        let prefix = &self.chain_info.network_info.pub_address_prefix;
+        match &self.key {
+            SenderKey::Local(private_key) => AccountId::new(
+                prefix,
+                &private_key.public_key(&self.secp).raw_address.unwrap(),
+            ),
+            SenderKey::Remote(remote_signer) => remote_signer.public_key().account_id(prefix),
+        }
         // unwrap as address is validated on construction
         .unwrap()
     }
@@ -472,4 +701,8 @@ impl Signer for Wallet {
     fn authz_granter(&self) -> Option<&Addr> {
         self.options.authz_granter.as_ref()
     }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.options.retry_policy.clone()
+    }
 }
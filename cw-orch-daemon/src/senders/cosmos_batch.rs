@@ -1,7 +1,7 @@
 use super::builder::SenderBuilder;
 use super::cosmos::Wallet;
 use super::query::QuerySender;
-use super::tx::TxSender;
+use super::tx::{TxOptions, TxSender};
 use crate::parse_cw_coins;
 use crate::{error::DaemonError, tx_resp::CosmTxResponse};
 use crate::{DaemonBase, INSTANTIATE_2_TYPE_URL};
@@ -49,13 +49,25 @@ pub struct CosmosBatchSender {
 impl CosmosBatchSender {
     /// Broadcast the cached messages in a transaction.
     pub async fn broadcast(&self, memo: Option<&str>) -> Result<CosmTxResponse, DaemonError> {
+        self.broadcast_with_options(&TxOptions::from_memo(memo))
+            .await
+    }
+
+    /// Broadcast the cached messages in a transaction with custom transaction options.
+    pub async fn broadcast_with_options(
+        &self,
+        options: &TxOptions,
+    ) -> Result<CosmTxResponse, DaemonError> {
         let msgs = self.msgs.lock().unwrap().to_vec();
         log::info!(
             target: &transaction_target(),
             "[Broadcast] {} msgs in a single transaction",
             msgs.len()
         );
-        let tx_result = self.sender.commit_tx_any(msgs, memo).await?;
+        let tx_result = self
+            .sender
+            .commit_tx_any_with_options(msgs, options)
+            .await?;
         log::info!(
             target: &transaction_target(),
             "[Broadcasted] Success: {}",
@@ -88,6 +100,11 @@ impl QuerySender for CosmosBatchSender {
     fn channel(&self) -> tonic::transport::Channel {
         self.sender.channel()
     }
+
+    #[cfg(feature = "websocket")]
+    fn websocket_url(&self) -> Option<String> {
+        QuerySender::websocket_url(&self.sender)
+    }
 }
 
 impl TxSender for CosmosBatchSender {
@@ -95,6 +112,17 @@ impl TxSender for CosmosBatchSender {
         &self,
         msgs: Vec<Any>,
         memo: Option<&str>,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        self.commit_tx_any_with_options(msgs, &TxOptions::from_memo(memo))
+            .await
+    }
+
+    /// The options are only used for messages that are broadcasted immediately.
+    /// Use [`CosmosBatchSender::broadcast_with_options`] for batched messages.
+    async fn commit_tx_any_with_options(
+        &self,
+        msgs: Vec<Any>,
+        options: &TxOptions,
     ) -> Result<CosmTxResponse, DaemonError> {
         // We check the type URLS. We can safely put them inside the lock if they DON'T correspond to the following:
         // - Code Upload
@@ -111,7 +139,7 @@ impl TxSender for CosmosBatchSender {
             .any(|msg| broadcast_immediately_type_urls.contains(&msg.type_url));
 
         if broadcast_immediately {
-            self.sender.commit_tx_any(msgs, memo).await
+            self.sender.commit_tx_any_with_options(msgs, options).await
         } else {
             log::info!(
                 target: &transaction_target(),
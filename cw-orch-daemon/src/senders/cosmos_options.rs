@@ -4,9 +4,9 @@ use cosmrs::AccountId;
 use cosmwasm_std::Addr;
 use cw_orch_core::environment::ChainInfoOwned;
 
-use crate::{DaemonError, Wallet};
+use crate::{tx_broadcaster::RetryPolicy, DaemonError, Wallet};
 
-use super::{builder::SenderBuilder, CosmosSender};
+use super::{builder::SenderBuilder, CosmosSender, DynamicGasPrice, RemoteSigner};
 
 /// Options for how txs should be constructed for this sender.
 #[derive(Default, Clone)]
@@ -15,18 +15,71 @@ pub struct CosmosOptions {
     pub authz_granter: Option<Addr>,
     pub fee_granter: Option<Addr>,
     pub hd_index: Option<u32>,
+    /// Track the account sequence locally instead of querying it for every transaction.
+    /// Allows broadcasting concurrent transactions from the same account.
+    pub manage_sequence: bool,
+    /// CometBFT RPC websocket used to wait for transaction inclusion instead of polling the node.
+    #[cfg(feature = "websocket")]
+    pub websocket_url: Option<String>,
+    /// Query the gas price from the chain instead of using the static `gas_price` of the chain info.
+    pub dynamic_gas_price: Option<DynamicGasPrice>,
+    /// Denoms used to pay the transaction fees, in order of preference.
+    /// The first denom the wallet holds enough of is used. Defaults to the gas denom of the chain.
+    pub fee_denoms: Vec<FeeDenom>,
+    /// Strategies used to retry failed transaction broadcasts.
+    pub retry_policy: RetryPolicy,
     /// Used to derive the private key
     pub(crate) key: CosmosWalletKey,
 }
 
+/// Denom accepted by the chain to pay transaction fees
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeeDenom {
+    pub denom: String,
+    /// Gas price in this denom.
+    /// For the gas denom of the chain, the dynamic gas price takes precedence when it is configured.
+    pub gas_price: f64,
+}
+
+impl FeeDenom {
+    pub fn new(denom: impl Into<String>, gas_price: f64) -> Self {
+        Self {
+            denom: denom.into(),
+            gas_price,
+        }
+    }
+}
+
 #[derive(Default, Clone)]
 pub enum CosmosWalletKey {
     Mnemonic(String),
     RawKey(Vec<u8>),
+    /// Key loaded from an encrypted keystore.
+    /// When no passphrase is provided, it's read from `CW_ORCH_KEYSTORE_PASSPHRASE`.
+    #[cfg(feature = "keystore")]
+    Keystore {
+        keystore: Keystore,
+        passphrase: Option<String>,
+    },
+    /// Transactions are signed by a signer holding the key outside of this process
+    Remote(Arc<dyn RemoteSigner>),
     #[default]
     Env,
 }
 
+/// Encrypted local keystore
+#[cfg(feature = "keystore")]
+#[derive(Clone, Debug)]
+pub enum Keystore {
+    /// Key `name` of a Cosmos SDK `keyring-backend file` directory (`~/.<app>/keyring-file`)
+    CosmosKeyring {
+        dir: std::path::PathBuf,
+        name: String,
+    },
+    /// Ethereum JSON keystore file (scrypt or pbkdf2)
+    Ethereum { path: std::path::PathBuf },
+}
+
 impl CosmosOptions {
     pub fn check(&self) -> Result<(), DaemonError> {
         if let Some(addr) = &self.authz_granter {
@@ -55,11 +108,52 @@ impl CosmosOptions {
         self
     }
 
+    pub fn manage_sequence(mut self, manage_sequence: bool) -> Self {
+        self.manage_sequence = manage_sequence;
+        self
+    }
+
+    #[cfg(feature = "websocket")]
+    pub fn websocket_url(mut self, url: impl Into<String>) -> Self {
+        self.websocket_url = Some(url.into());
+        self
+    }
+
+    pub fn dynamic_gas_price(mut self, gas_price: DynamicGasPrice) -> Self {
+        self.dynamic_gas_price = Some(gas_price);
+        self
+    }
+
+    /// Adds a denom to pay fees with. Denoms are tried in the order they were added.
+    pub fn fee_denom(mut self, denom: impl Into<String>, gas_price: f64) -> Self {
+        self.fee_denoms.push(FeeDenom::new(denom, gas_price));
+        self
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
     pub fn mnemonic(mut self, mnemonic: impl Into<String>) -> Self {
         self.key = CosmosWalletKey::Mnemonic(mnemonic.into());
         self
     }
 
+    pub fn remote_signer(mut self, remote_signer: impl RemoteSigner + 'static) -> Self {
+        self.key = CosmosWalletKey::Remote(Arc::new(remote_signer));
+        self
+    }
+
+    #[cfg(feature = "keystore")]
+    pub fn keystore(mut self, keystore: Keystore, passphrase: Option<String>) -> Self {
+        self.key = CosmosWalletKey::Keystore {
+            keystore,
+            passphrase,
+        };
+        self
+    }
+
     pub fn set_authz_granter(&mut self, granter: &Addr) {
         self.authz_granter = Some(granter.clone());
     }
@@ -72,6 +166,39 @@ impl CosmosOptions {
         self.hd_index = Some(index);
     }
 
+    pub fn set_manage_sequence(&mut self, manage_sequence: bool) {
+        self.manage_sequence = manage_sequence;
+    }
+
+    #[cfg(feature = "websocket")]
+    pub fn set_websocket_url(&mut self, url: impl Into<String>) {
+        self.websocket_url = Some(url.into());
+    }
+
+    pub fn set_dynamic_gas_price(&mut self, gas_price: DynamicGasPrice) {
+        self.dynamic_gas_price = Some(gas_price);
+    }
+
+    pub fn set_fee_denoms(&mut self, fee_denoms: Vec<FeeDenom>) {
+        self.fee_denoms = fee_denoms;
+    }
+
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = retry_policy;
+    }
+
+    pub fn set_remote_signer(&mut self, remote_signer: impl RemoteSigner + 'static) {
+        self.key = CosmosWalletKey::Remote(Arc::new(remote_signer));
+    }
+
+    #[cfg(feature = "keystore")]
+    pub fn set_keystore(&mut self, keystore: Keystore, passphrase: Option<String>) {
+        self.key = CosmosWalletKey::Keystore {
+            keystore,
+            passphrase,
+        };
+    }
+
     pub fn set_mnemonic(&mut self, mnemonic: impl Into<String>) {
         self.key = CosmosWalletKey::Mnemonic(mnemonic.into());
     }
@@ -0,0 +1,194 @@
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use cw_orch_core::log::transaction_target;
+use http::uri::PathAndQuery;
+use tonic::{codec::ProstCodec, transport::Channel};
+
+use crate::{
+    proto::{feemarket, osmosis},
+    DaemonError,
+};
+
+/// Multiplier applied to the queried gas price by default.
+/// Absorbs base fee increases between the query and the inclusion of the transaction.
+pub const DEFAULT_GAS_PRICE_MULTIPLIER: f64 = 1.2;
+
+/// Precision of the protobuf encoding of `LegacyDec`
+const LEGACY_DEC_PRECISION: i32 = 18;
+
+pub type GasPriceFuture<'a> = Pin<Box<dyn Future<Output = Result<f64, DaemonError>> + Send + 'a>>;
+
+/// Source of the current gas price of a chain
+pub trait GasPriceOracle: Send + Sync {
+    /// Queries the current gas price of `denom`
+    fn query_gas_price<'a>(&'a self, channel: Channel, denom: &'a str) -> GasPriceFuture<'a>;
+}
+
+/// EIP-1559 base fee of the Osmosis `txfees` module.
+/// The base fee is expressed in the native fee denom of the chain.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OsmosisBaseFee;
+
+impl GasPriceOracle for OsmosisBaseFee {
+    fn query_gas_price<'a>(&'a self, channel: Channel, _denom: &'a str) -> GasPriceFuture<'a> {
+        Box::pin(async move {
+            let response: osmosis::QueryEipBaseFeeResponse = grpc_query(
+                channel,
+                osmosis::EIP_BASE_FEE_PATH,
+                osmosis::QueryEipBaseFeeRequest {},
+            )
+            .await?;
+            parse_legacy_dec(&response.base_fee)
+        })
+    }
+}
+
+/// Gas price of Skip's `x/feemarket` module (Neutron and others)
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FeeMarketGasPrice;
+
+impl GasPriceOracle for FeeMarketGasPrice {
+    fn query_gas_price<'a>(&'a self, channel: Channel, denom: &'a str) -> GasPriceFuture<'a> {
+        Box::pin(async move {
+            let response: feemarket::GasPriceResponse = grpc_query(
+                channel,
+                feemarket::GAS_PRICE_PATH,
+                feemarket::GasPriceRequest {
+                    denom: denom.to_string(),
+                },
+            )
+            .await?;
+            let price = response.price.ok_or_else(|| {
+                DaemonError::StdErr(format!(
+                    "No gas price returned by the feemarket module for {denom}"
+                ))
+            })?;
+            parse_legacy_dec(&price.amount)
+        })
+    }
+}
+
+/// Gas price queried from the chain for every transaction.
+/// The static `gas_price` of the chain is used when the query fails.
+#[derive(Clone)]
+pub struct DynamicGasPrice {
+    oracle: Arc<dyn GasPriceOracle>,
+    multiplier: f64,
+    max_gas_price: Option<f64>,
+}
+
+impl DynamicGasPrice {
+    pub fn new(oracle: impl GasPriceOracle + 'static) -> Self {
+        Self {
+            oracle: Arc::new(oracle),
+            multiplier: DEFAULT_GAS_PRICE_MULTIPLIER,
+            max_gas_price: None,
+        }
+    }
+
+    /// Gas price from the Osmosis `txfees` base fee
+    pub fn osmosis() -> Self {
+        Self::new(OsmosisBaseFee)
+    }
+
+    /// Gas price from Skip's `x/feemarket` module
+    pub fn feemarket() -> Self {
+        Self::new(FeeMarketGasPrice)
+    }
+
+    /// Multiplier applied to the queried gas price. Defaults to [`DEFAULT_GAS_PRICE_MULTIPLIER`]
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Upper bound of the gas price, applied after the multiplier
+    pub fn max_gas_price(mut self, max_gas_price: f64) -> Self {
+        self.max_gas_price = Some(max_gas_price);
+        self
+    }
+
+    /// Returns the gas price to use for `denom`, or `fallback` if it couldn't be queried
+    pub async fn gas_price(&self, channel: Channel, denom: &str, fallback: f64) -> f64 {
+        match self.oracle.query_gas_price(channel, denom).await {
+            Ok(gas_price) => {
+                let gas_price = self.apply_bounds(gas_price);
+                log::debug!(target: &transaction_target(), "Using queried gas price {}{}", gas_price, denom);
+                gas_price
+            }
+            Err(e) => {
+                log::warn!(
+                    target: &transaction_target(),
+                    "Couldn't query the gas price, falling back to {}{}: {}",
+                    fallback,
+                    denom,
+                    e
+                );
+                fallback
+            }
+        }
+    }
+
+    fn apply_bounds(&self, gas_price: f64) -> f64 {
+        let gas_price = gas_price * self.multiplier;
+        match self.max_gas_price {
+            Some(max_gas_price) => gas_price.min(max_gas_price),
+            None => gas_price,
+        }
+    }
+}
+
+/// Performs a unary gRPC query on a module that is not part of the Cosmos SDK protos
+async fn grpc_query<Req, Resp>(
+    channel: Channel,
+    path: &'static str,
+    request: Req,
+) -> Result<Resp, DaemonError>
+where
+    Req: prost::Message + Send + Sync + 'static,
+    Resp: prost::Message + Default + Send + Sync + 'static,
+{
+    let mut client = tonic::client::Grpc::new(channel);
+    client.ready().await?;
+    let response = client
+        .unary(
+            tonic::Request::new(request),
+            PathAndQuery::from_static(path),
+            ProstCodec::default(),
+        )
+        .await?;
+    Ok(response.into_inner())
+}
+
+/// `LegacyDec` values are encoded as their integer representation in protobuf (`1.5` is `1500000000000000000`).
+/// Human readable decimals are accepted as well.
+fn parse_legacy_dec(value: &str) -> Result<f64, DaemonError> {
+    if value.contains('.') {
+        Ok(value.parse()?)
+    } else {
+        Ok(value.parse::<f64>()? / 10f64.powi(LEGACY_DEC_PRECISION))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_dec() -> anyhow::Result<()> {
+        assert_eq!(parse_legacy_dec("2500000000000000")?, 0.0025);
+        assert_eq!(parse_legacy_dec("0.0025")?, 0.0025);
+        assert!(parse_legacy_dec("abc").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn multiplier_and_cap() {
+        let gas_price = DynamicGasPrice::osmosis().multiplier(2.0);
+        assert_eq!(gas_price.apply_bounds(0.1), 0.2);
+
+        let gas_price = gas_price.max_gas_price(0.15);
+        assert_eq!(gas_price.apply_bounds(0.1), 0.15);
+        assert_eq!(gas_price.apply_bounds(0.05), 0.1);
+    }
+}
@@ -8,11 +8,24 @@ pub mod tx;
 mod cosmos;
 mod cosmos_batch;
 mod cosmos_options;
+mod gas_price;
 mod query_only;
+mod remote_signer;
+mod sequence;
 
 pub use {
     cosmos::{CosmosSender, Wallet},
     cosmos_batch::{options::CosmosBatchOptions, BatchDaemon, CosmosBatchSender},
-    cosmos_options::{CosmosOptions, CosmosWalletKey},
+    cosmos_options::{CosmosOptions, CosmosWalletKey, FeeDenom},
+    gas_price::{
+        DynamicGasPrice, FeeMarketGasPrice, GasPriceFuture, GasPriceOracle, OsmosisBaseFee,
+        DEFAULT_GAS_PRICE_MULTIPLIER,
+    },
     query_only::{QueryOnlyDaemon, QueryOnlySender},
+    remote_signer::{HttpRemoteSigner, RemoteSigner, SignatureFuture},
+    sequence::SequenceManager,
+    tx::{TxOptions, TxTimeout},
 };
+
+#[cfg(feature = "keystore")]
+pub use cosmos_options::Keystore;
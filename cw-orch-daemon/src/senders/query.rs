@@ -12,4 +12,10 @@ pub trait QuerySender: Clone {
 
     /// Get the channel for the sender
     fn channel(&self) -> Channel;
+
+    /// CometBFT RPC websocket url used for event subscriptions, if any
+    #[cfg(feature = "websocket")]
+    fn websocket_url(&self) -> Option<String> {
+        None
+    }
 }
@@ -0,0 +1,271 @@
+//! Signing of transactions outside of the sender process.
+//!
+//! A [`RemoteSigner`] only exposes its public key and signs `SignDoc` bytes, the private key never reaches the sender.
+//! [`HttpRemoteSigner`] is a client of the following HTTP/JSON API:
+//! - `GET /pubkey` returns `{"public_key": "<base64 compressed secp256k1 public key>"}`
+//! - `POST /sign` with `{"sign_doc": "<base64 SignDoc>"}` returns `{"signature": "<base64 64 bytes signature>"}`
+
+use std::{future::Future, pin::Pin};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use cosmrs::crypto::PublicKey;
+use cw_orch_core::log::transaction_target;
+use serde::{Deserialize, Serialize};
+
+use crate::DaemonError;
+
+pub type SignatureFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Vec<u8>, DaemonError>> + Send + 'a>>;
+
+/// Signer holding a secp256k1 key outside of the sender (remote service, KMS, HSM...)
+pub trait RemoteSigner: Send + Sync {
+    /// Public key of the signing key
+    fn public_key(&self) -> PublicKey;
+
+    /// Signs the protobuf encoded `SignDoc` of a transaction (`SIGN_MODE_DIRECT`)
+    fn sign_bytes<'a>(&'a self, sign_doc: &'a [u8]) -> SignatureFuture<'a>;
+}
+
+#[derive(Serialize, Deserialize)]
+struct PubKeyResponse {
+    public_key: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SignRequest {
+    sign_doc: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SignResponse {
+    signature: String,
+}
+
+/// Client of a remote signer serving the HTTP/JSON API described in the [module documentation](self)
+#[derive(Clone)]
+pub struct HttpRemoteSigner {
+    client: reqwest::Client,
+    url: String,
+    auth_token: Option<String>,
+    public_key: PublicKey,
+}
+
+impl HttpRemoteSigner {
+    /// Connects to the remote signer at `url` and fetches its public key.
+    /// `auth_token` is sent as a bearer token with every request.
+    pub async fn connect(url: &str, auth_token: Option<String>) -> Result<Self, DaemonError> {
+        let client = reqwest::Client::new();
+        let url = url.trim_end_matches('/').to_string();
+
+        let mut request = client.get(format!("{url}/pubkey"));
+        if let Some(token) = &auth_token {
+            request = request.bearer_auth(token);
+        }
+        let response: PubKeyResponse = parse_response(request.send().await?).await?;
+        let public_key = cosmrs::tendermint::PublicKey::from_raw_secp256k1(
+            &STANDARD.decode(response.public_key)?,
+        )
+        .map(PublicKey::from)
+        .ok_or_else(|| DaemonError::StdErr("invalid remote signer public key".to_string()))?;
+
+        Ok(Self {
+            client,
+            url,
+            auth_token,
+            public_key,
+        })
+    }
+}
+
+impl RemoteSigner for HttpRemoteSigner {
+    fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    fn sign_bytes<'a>(&'a self, sign_doc: &'a [u8]) -> SignatureFuture<'a> {
+        Box::pin(async move {
+            log::debug!(target: &transaction_target(), "Requesting signature from {}", self.url);
+            let mut request = self
+                .client
+                .post(format!("{}/sign", self.url))
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(serde_json::to_string(&SignRequest {
+                    sign_doc: STANDARD.encode(sign_doc),
+                })?);
+            if let Some(token) = &self.auth_token {
+                request = request.bearer_auth(token);
+            }
+            let response: SignResponse = parse_response(request.send().await?).await?;
+            Ok(STANDARD.decode(response.signature)?)
+        })
+    }
+}
+
+async fn parse_response<T: serde::de::DeserializeOwned>(
+    response: reqwest::Response,
+) -> Result<T, DaemonError> {
+    let status = response.status();
+    let body = response.text().await?;
+    if !status.is_success() {
+        return Err(DaemonError::StdErr(format!(
+            "remote signer returned {status}: {body}"
+        )));
+    }
+    Ok(serde_json::from_str(&body)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::secp256k1::Secp256k1;
+    use cosmrs::crypto::secp256k1::SigningKey;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+        task::JoinHandle,
+    };
+
+    use crate::keys::private::PrivateKey;
+
+    /// Local stand-in for a remote signer, serving the HTTP/JSON API for a local key.
+    /// The server stops when this is dropped.
+    struct LocalSignerServer {
+        url: String,
+        handle: JoinHandle<()>,
+    }
+
+    impl LocalSignerServer {
+        /// Serves the remote signer API for `private_key` on a random local port
+        async fn spawn(private_key: &PrivateKey) -> Result<Self, DaemonError> {
+            let listener = TcpListener::bind("127.0.0.1:0").await?;
+            let url = format!("http://{}", listener.local_addr()?);
+            let raw_key = private_key.raw_key();
+
+            let handle = tokio::spawn(async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    tokio::spawn(async move {
+                        if let Err(e) = serve_request(stream, &raw_key).await {
+                            log::debug!(target: &transaction_target(), "Local signer request failed: {}", e);
+                        }
+                    });
+                }
+            });
+
+            Ok(Self { url, handle })
+        }
+
+        /// Url of the server
+        fn url(&self) -> &str {
+            &self.url
+        }
+    }
+
+    impl Drop for LocalSignerServer {
+        fn drop(&mut self) {
+            self.handle.abort();
+        }
+    }
+
+    /// Answers a single HTTP/1.1 request
+    async fn serve_request(mut stream: TcpStream, raw_key: &[u8]) -> Result<(), DaemonError> {
+        let mut request = vec![];
+        let mut buffer = [0; 4096];
+        let (head, body_start) = loop {
+            let read = stream.read(&mut buffer).await?;
+            if read == 0 {
+                return Ok(());
+            }
+            request.extend_from_slice(&buffer[..read]);
+            if let Some(position) = request.windows(4).position(|window| window == b"\r\n\r\n") {
+                break (
+                    String::from_utf8_lossy(&request[..position]).to_string(),
+                    position + 4,
+                );
+            }
+        };
+
+        let content_length = head
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+            .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+            .unwrap_or_default();
+        while request.len() < body_start + content_length {
+            let read = stream.read(&mut buffer).await?;
+            if read == 0 {
+                break;
+            }
+            request.extend_from_slice(&buffer[..read]);
+        }
+        let body = &request[body_start..];
+
+        let mut request_line = head.split_whitespace();
+        let (status, response) = respond(
+            raw_key,
+            request_line.next().unwrap_or_default(),
+            request_line.next().unwrap_or_default(),
+            body,
+        )?;
+
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+            response.len()
+        );
+        stream.write_all(response.as_bytes()).await?;
+        Ok(())
+    }
+
+    fn respond(
+        raw_key: &[u8],
+        method: &str,
+        path: &str,
+        body: &[u8],
+    ) -> Result<(&'static str, String), DaemonError> {
+        let signing_key =
+            SigningKey::from_slice(raw_key).map_err(|e| DaemonError::StdErr(e.to_string()))?;
+        match (method, path) {
+            ("GET", "/pubkey") => Ok((
+                "200 OK",
+                serde_json::to_string(&PubKeyResponse {
+                    public_key: STANDARD.encode(signing_key.public_key().to_bytes()),
+                })?,
+            )),
+            ("POST", "/sign") => {
+                let request: SignRequest = serde_json::from_slice(body)?;
+                let signature = signing_key
+                    .sign(&STANDARD.decode(request.sign_doc)?)
+                    .map_err(|e| DaemonError::StdErr(e.to_string()))?;
+                Ok((
+                    "200 OK",
+                    serde_json::to_string(&SignResponse {
+                        signature: STANDARD.encode(signature.to_bytes()),
+                    })?,
+                ))
+            }
+            _ => Ok(("404 Not Found", "{}".to_string())),
+        }
+    }
+
+    #[tokio::test]
+    async fn signs_through_local_server() -> anyhow::Result<()> {
+        let secp = Secp256k1::new();
+        let private_key = PrivateKey::new(&secp, 118)?;
+        let server = LocalSignerServer::spawn(&private_key).await?;
+
+        let signer = HttpRemoteSigner::connect(server.url(), None).await?;
+        let signing_key = SigningKey::from_slice(&private_key.raw_key()).unwrap();
+        assert_eq!(signer.public_key(), signing_key.public_key());
+        assert_eq!(
+            signer.public_key().account_id("juno").unwrap().to_string(),
+            private_key.public_key(&secp).account("juno")?
+        );
+
+        // Signatures are deterministic (RFC 6979)
+        let signature = signer.sign_bytes(b"sign doc").await?;
+        assert_eq!(
+            signature,
+            signing_key.sign(b"sign doc").unwrap().to_bytes().to_vec()
+        );
+        Ok(())
+    }
+}
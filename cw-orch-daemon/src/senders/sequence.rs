@@ -0,0 +1,222 @@
+use std::{future::Future, sync::Arc};
+
+use tokio::sync::{Mutex, MutexGuard};
+
+use crate::DaemonError;
+
+use super::sign::SigningAccount;
+
+/// Tracks the account sequence of a sender locally.
+///
+/// Every call to [`SequenceManager::next_account`] reserves a new, increasing sequence number.
+/// This allows concurrent transactions from the same account to be broadcasted without waiting for the previous one to be included in a block.
+/// A sequence is only reserved for a transaction that is about to be broadcasted, and given back with [`SequenceManager::release`]
+/// if the node didn't accept it.
+/// The account is only queried from the node once, or after the manager was resynchronized.
+#[derive(Clone, Default)]
+pub struct SequenceManager {
+    state: Arc<Mutex<Option<SequenceState>>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct SequenceState {
+    account_number: u64,
+    next_sequence: u64,
+    /// Last sequence the manager was rolled back to. Used to avoid rolling back several times for the same error.
+    last_rollback: Option<u64>,
+}
+
+impl SequenceManager {
+    /// Returns the account number and the next sequence, without reserving it. Used to simulate transactions.
+    /// `fetch` is only awaited when the manager doesn't know the account yet.
+    pub async fn current_account(
+        &self,
+        fetch: impl Future<Output = Result<SigningAccount, DaemonError>>,
+    ) -> Result<SigningAccount, DaemonError> {
+        let state = self.fetched_state(fetch).await?;
+        // Unwrap is safe, the state was filled when fetching
+        let current = state.as_ref().unwrap();
+
+        Ok(SigningAccount {
+            account_number: current.account_number,
+            sequence: current.next_sequence,
+        })
+    }
+
+    /// Returns the account number and reserves the next sequence to use for signing.
+    /// `fetch` is only awaited when the manager doesn't know the account yet.
+    pub async fn next_account(
+        &self,
+        fetch: impl Future<Output = Result<SigningAccount, DaemonError>>,
+    ) -> Result<SigningAccount, DaemonError> {
+        let mut state = self.fetched_state(fetch).await?;
+        // Unwrap is safe, the state was filled when fetching
+        let current = state.as_mut().unwrap();
+
+        let sequence = current.next_sequence;
+        current.next_sequence += 1;
+
+        Ok(SigningAccount {
+            account_number: current.account_number,
+            sequence,
+        })
+    }
+
+    /// Gives back a `sequence` reserved by [`SequenceManager::next_account`] whose transaction wasn't accepted by the node.
+    /// Only the last reserved sequence can be given back, the following ones are already used by other transactions.
+    pub async fn release(&self, sequence: u64) {
+        let mut state = self.state.lock().await;
+
+        if let Some(current) = state.as_mut() {
+            if current.next_sequence == sequence + 1 {
+                current.next_sequence = sequence;
+            }
+        }
+    }
+
+    /// Marks the transaction signed with `sequence` as accepted by the node.
+    /// The rollbacks of earlier sequences are over and a new mismatch on the same sequence can be resynchronized again.
+    pub async fn accepted(&self, sequence: u64) {
+        let mut state = self.state.lock().await;
+
+        if let Some(current) = state.as_mut() {
+            if current
+                .last_rollback
+                .is_some_and(|rollback| sequence >= rollback)
+            {
+                current.last_rollback = None;
+            }
+        }
+    }
+
+    async fn fetched_state(
+        &self,
+        fetch: impl Future<Output = Result<SigningAccount, DaemonError>>,
+    ) -> Result<MutexGuard<'_, Option<SequenceState>>, DaemonError> {
+        let mut state = self.state.lock().await;
+
+        if state.is_none() {
+            let SigningAccount {
+                account_number,
+                sequence,
+            } = fetch.await?;
+            *state = Some(SequenceState {
+                account_number,
+                next_sequence: sequence,
+                last_rollback: None,
+            });
+        }
+        Ok(state)
+    }
+
+    /// Resynchronizes the local sequence after a sequence mismatch error.
+    ///
+    /// - When the node reported the sequence it `expected`, the local counter is moved to that value.
+    ///   Multiple in-flight transactions usually fail with the same expected sequence, so the counter is only rolled back once per value.
+    /// - Without an expected sequence, the account will be queried again on the next call to [`SequenceManager::next_account`].
+    pub async fn resync(&self, expected: Option<u64>) {
+        let mut state = self.state.lock().await;
+
+        match (state.as_mut(), expected) {
+            (Some(current), Some(expected)) => {
+                if expected > current.next_sequence {
+                    current.next_sequence = expected;
+                } else if expected < current.next_sequence
+                    && current.last_rollback != Some(expected)
+                {
+                    current.next_sequence = expected;
+                    current.last_rollback = Some(expected);
+                }
+            }
+            _ => *state = None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn account(sequence: u64) -> Result<SigningAccount, DaemonError> {
+        Ok(SigningAccount {
+            account_number: 7,
+            sequence,
+        })
+    }
+
+    #[tokio::test]
+    async fn hands_out_increasing_sequences() -> anyhow::Result<()> {
+        let manager = SequenceManager::default();
+
+        let first = manager.next_account(account(10)).await?;
+        // The account is only fetched once
+        let second = manager.next_account(account(0)).await?;
+
+        assert_eq!(first.account_number, 7);
+        assert_eq!(first.sequence, 10);
+        assert_eq!(second.sequence, 11);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resyncs_on_mismatch() -> anyhow::Result<()> {
+        let manager = SequenceManager::default();
+        for _ in 0..5 {
+            manager.next_account(account(10)).await?;
+        }
+
+        // The chain is ahead of us
+        manager.resync(Some(20)).await;
+        assert_eq!(manager.next_account(account(0)).await?.sequence, 20);
+
+        // A sequence was lost, all the following txs fail with the same expected sequence
+        manager.resync(Some(18)).await;
+        assert_eq!(manager.next_account(account(0)).await?.sequence, 18);
+        manager.resync(Some(18)).await;
+        assert_eq!(manager.next_account(account(0)).await?.sequence, 19);
+
+        // Unknown expected sequence, the account is queried again
+        manager.resync(None).await;
+        assert_eq!(manager.next_account(account(42)).await?.sequence, 42);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn releases_unused_sequences() -> anyhow::Result<()> {
+        let manager = SequenceManager::default();
+
+        // Reading the sequence doesn't reserve it
+        assert_eq!(manager.current_account(account(10)).await?.sequence, 10);
+        assert_eq!(manager.current_account(account(0)).await?.sequence, 10);
+
+        // The last reserved sequence is given back
+        let first = manager.next_account(account(0)).await?;
+        manager.release(first.sequence).await;
+        assert_eq!(manager.next_account(account(0)).await?.sequence, 10);
+
+        // A sequence followed by other reservations can't be given back
+        let second = manager.next_account(account(0)).await?;
+        manager.next_account(account(0)).await?;
+        manager.release(second.sequence).await;
+        assert_eq!(manager.current_account(account(0)).await?.sequence, 13);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn accepted_tx_ends_rollback() -> anyhow::Result<()> {
+        let manager = SequenceManager::default();
+        for _ in 0..5 {
+            manager.next_account(account(10)).await?;
+        }
+
+        manager.resync(Some(12)).await;
+        let sequence = manager.next_account(account(0)).await?.sequence;
+        manager.accepted(sequence).await;
+
+        // A later mismatch on the same sequence is resynchronized again
+        manager.next_account(account(0)).await?;
+        manager.resync(Some(12)).await;
+        assert_eq!(manager.next_account(account(0)).await?.sequence, 12);
+        Ok(())
+    }
+}
@@ -1,20 +1,18 @@
-use std::str::FromStr;
+use std::{str::FromStr, time::SystemTime};
 
 use crate::{
     parse_cw_coins,
     queriers::Node,
-    tx_broadcaster::{
-        account_sequence_strategy, assert_broadcast_code_cosm_response, insufficient_fee_strategy,
-        TxBroadcaster,
-    },
+    senders::tx::{TxOptions, TxTimeout},
+    tx_broadcaster::{RetryPolicy, TxBroadcaster},
     CosmTxResponse, DaemonError, QuerySender, TxBuilder, TxSender,
 };
 use cosmrs::{
     bank::MsgSend,
-    proto::cosmos::authz::v1beta1::MsgExec,
+    proto::cosmos::{authz::v1beta1::MsgExec, tx::v1beta1::TxRaw},
     tendermint::chain::Id,
     tx::{Body, Fee, Raw, SignDoc, SignerInfo},
-    AccountId, Any,
+    AccountId, Any, Denom,
 };
 use cosmwasm_std::Addr;
 use prost::Message;
@@ -32,10 +30,45 @@ pub trait Signer: QuerySender<Error = DaemonError> + Sync {
     /// The account id of the signer.
     fn account_id(&self) -> AccountId;
 
+    /// Account number and sequence of the next transaction. Doesn't reserve the sequence, used for simulations.
     fn signing_account(
         &self,
     ) -> impl std::future::Future<Output = Result<SigningAccount, DaemonError>> + Send;
 
+    /// Account number and sequence of a transaction that is about to be broadcasted.
+    /// Signers that track their sequence locally reserve it here.
+    /// Defaults to [`Signer::signing_account`]
+    fn reserve_signing_account(
+        &self,
+    ) -> impl std::future::Future<Output = Result<SigningAccount, DaemonError>> + Send {
+        self.signing_account()
+    }
+
+    /// Called when a transaction signed with a sequence from [`Signer::reserve_signing_account`] wasn't accepted by the node.
+    fn release_sequence(&self, _sequence: u64) -> impl std::future::Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// Called when the node accepted a transaction signed with a sequence from [`Signer::reserve_signing_account`].
+    fn on_sequence_accepted(&self, _sequence: u64) -> impl std::future::Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// Called when the node rejected a transaction because of an account sequence mismatch.
+    /// `expected` is the sequence the node expected, if it could be parsed from the error.
+    /// Signers that track their sequence locally should resynchronize here.
+    fn on_sequence_mismatch(
+        &self,
+        _expected: Option<u64>,
+    ) -> impl std::future::Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// Strategies used to retry failed transaction broadcasts
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy::default()
+    }
+
     /// Signals wether this signer is using authz
     /// If set to true, the signed messages will be wrapped inside authz messages
     fn authz_granter(&self) -> Option<&Addr> {
@@ -44,14 +77,57 @@ pub trait Signer: QuerySender<Error = DaemonError> + Sync {
 
     // --- Related to transaction signing --- //
     /// Transaction signing
-    fn sign(&self, sign_doc: SignDoc) -> Result<Raw, DaemonError>;
+    fn sign(
+        &self,
+        sign_doc: SignDoc,
+    ) -> impl std::future::Future<Output = Result<Raw, DaemonError>> + Send;
 
     fn signer_info(&self, sequence: u64) -> SignerInfo;
 
     fn build_fee(&self, amount: impl Into<u128>, gas_limit: u64) -> Result<Fee, DaemonError>;
 
+    /// Builds the fee paid in `denom`. `None` stands for the default fee denom of the signer.
+    /// Defaults to the fee of [`Signer::build_fee`] with its denom replaced by `denom`
+    fn build_fee_with_denom(
+        &self,
+        amount: impl Into<u128>,
+        denom: Option<&str>,
+        gas_limit: u64,
+    ) -> Result<Fee, DaemonError> {
+        let mut fee = self.build_fee(amount, gas_limit)?;
+        if let Some(denom) = denom {
+            let denom: Denom = denom.parse()?;
+            for coin in &mut fee.amount {
+                coin.denom = denom.clone();
+            }
+        }
+        Ok(fee)
+    }
+
     fn gas_price(&self) -> Result<f64, DaemonError>;
 
+    /// Gas price used to compute the fee of the next transaction.
+    /// Defaults to [`Signer::gas_price`], signers can override it to query the gas price from the chain.
+    fn current_gas_price(
+        &self,
+    ) -> impl std::future::Future<Output = Result<f64, DaemonError>> + Send {
+        async move { self.gas_price() }
+    }
+
+    /// Computes the gas limit, fee amount and fee denom of a transaction that needs `gas` according to its simulation.
+    /// A `None` denom stands for the default fee denom of the signer.
+    fn fee_from_gas(
+        &self,
+        gas: u64,
+    ) -> impl std::future::Future<Output = Result<(u64, u128, Option<String>), DaemonError>> + Send
+    {
+        async move {
+            let (gas_limit, fee_amount) =
+                TxBuilder::get_fee_from_gas(gas, self.current_gas_price().await?)?;
+            Ok((gas_limit, fee_amount, None))
+        }
+    }
+
     /// Computes the gas needed for submitting a transaction
     fn calculate_gas(
         &self,
@@ -71,7 +147,7 @@ pub trait Signer: QuerySender<Error = DaemonError> + Sync {
                 account_number,
             )?;
 
-            let tx_raw = self.sign(sign_doc)?;
+            let tx_raw = simulation_tx(sign_doc);
 
             Node::new_async(self.channel())
                 ._simulate_tx(tx_raw.to_bytes()?)
@@ -80,6 +156,18 @@ pub trait Signer: QuerySender<Error = DaemonError> + Sync {
     }
 }
 
+/// Transaction used to simulate `sign_doc`.
+/// The node doesn't verify signatures during simulations so an empty signature is used,
+/// which avoids a signing request to remote signers.
+pub(crate) fn simulation_tx(sign_doc: SignDoc) -> Raw {
+    TxRaw {
+        body_bytes: sign_doc.body_bytes,
+        auth_info_bytes: sign_doc.auth_info_bytes,
+        signatures: vec![vec![]],
+    }
+    .into()
+}
+
 impl<T: Signer + Sync> TxSender for T {
     fn account_id(&self) -> cosmrs::AccountId {
         self.account_id()
@@ -90,7 +178,19 @@ impl<T: Signer + Sync> TxSender for T {
         msgs: Vec<Any>,
         memo: Option<&str>,
     ) -> Result<CosmTxResponse, DaemonError> {
-        let timeout_height = Node::new_async(self.channel())._block_height().await? + 10u64;
+        self.commit_tx_any_with_options(msgs, &TxOptions::from_memo(memo))
+            .await
+    }
+
+    async fn commit_tx_any_with_options(
+        &self,
+        msgs: Vec<Any>,
+        options: &TxOptions,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let timeout_height = match options.timeout {
+            TxTimeout::Height(height) if options.unordered.is_none() => height,
+            _ => 0,
+        };
 
         let msgs = if self.authz_granter().is_some() {
             // We wrap authz messages
@@ -106,26 +206,39 @@ impl<T: Signer + Sync> TxSender for T {
             msgs
         };
 
-        let tx_body = TxBuilder::build_body(msgs, memo, timeout_height);
+        let tx_body = TxBuilder::build_body(msgs, options.memo.as_deref(), timeout_height);
 
-        let tx_builder = TxBuilder::new(tx_body);
+        let mut tx_builder = TxBuilder::new(tx_body);
+        match (options.unordered, options.timeout) {
+            (Some(ttl), _) => {
+                tx_builder.unordered(SystemTime::now() + ttl);
+            }
+            (None, TxTimeout::Blocks(blocks)) => {
+                tx_builder.timeout_blocks(blocks);
+            }
+            _ => {}
+        }
 
-        // We retry broadcasting the tx, with the following strategies
-        // 1. In case there is an `incorrect account sequence` error, we can retry as much as possible (doesn't cost anything to the user)
-        // 2. In case there is an insufficient_fee error, we retry once (costs fee to the user everytime we submit this kind of tx)
-        // 3. In case there is an other error, we fail
+        let node = &Node::new_async(self.channel());
+        #[cfg(feature = "websocket")]
+        let websocket_url = &self.websocket_url();
+        let confirm = move |txhash: String| async move {
+            #[cfg(feature = "websocket")]
+            if let Some(websocket_url) = websocket_url {
+                return node._find_tx_with_websocket(txhash, websocket_url).await;
+            }
+            node._find_tx(txhash).await
+        };
 
-        let tx_response = TxBroadcaster::default()
-            .add_strategy(insufficient_fee_strategy())
-            .add_strategy(account_sequence_strategy())
-            .broadcast(tx_builder, self)
+        // We retry broadcasting the tx with the strategies of the signer's retry policy
+        let tx_response = TxBroadcaster::from(self.retry_policy())
+            .broadcast_and_confirm(tx_builder, self, confirm)
             .await?;
 
-        let resp = Node::new_async(self.channel())
-            ._find_tx(tx_response.txhash)
-            .await?;
+        #[cfg(feature = "tracing")]
+        crate::telemetry::record_tx(&self.chain_id(), &tx_response);
 
-        assert_broadcast_code_cosm_response(resp)
+        Ok(tx_response)
     }
     /// Actual sender of the messages.
     /// This is different when using authz capabilites
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use cosmrs::{
     tx::{Msg, Raw},
     AccountId, Any,
@@ -8,6 +10,70 @@ use crate::{cosmos_modules, CosmTxResponse, DaemonError};
 
 use super::query::QuerySender;
 
+/// Block height after which a transaction can't be included anymore
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TxTimeout {
+    /// The transaction times out after this block height
+    Height(u64),
+    /// The transaction times out this number of blocks after the latest block
+    Blocks(u64),
+    /// The transaction never times out
+    #[default]
+    None,
+}
+
+/// Options of a single transaction
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct TxOptions {
+    pub memo: Option<String>,
+    /// Defaults to no timeout.
+    /// A timeout relative to the latest block is computed again for every broadcast attempt.
+    /// Not used for unordered transactions.
+    pub timeout: TxTimeout,
+    /// Time to live of an unordered transaction (Cosmos SDK 0.53).
+    /// Unordered transactions don't depend on the account sequence and time out at `now + ttl`.
+    pub unordered: Option<Duration>,
+}
+
+impl TxOptions {
+    pub fn memo(mut self, memo: impl Into<String>) -> Self {
+        self.memo = Some(memo.into());
+        self
+    }
+
+    /// The transaction times out after the block `height`
+    pub fn timeout_height(mut self, height: u64) -> Self {
+        self.timeout = TxTimeout::Height(height);
+        self
+    }
+
+    /// The transaction times out `blocks` blocks after the latest block
+    pub fn timeout_blocks(mut self, blocks: u64) -> Self {
+        self.timeout = TxTimeout::Blocks(blocks);
+        self
+    }
+
+    /// The transaction never times out
+    pub fn no_timeout(mut self) -> Self {
+        self.timeout = TxTimeout::None;
+        self
+    }
+
+    /// Broadcast an unordered transaction, valid for `ttl`
+    pub fn unordered(mut self, ttl: Duration) -> Self {
+        self.unordered = Some(ttl);
+        self
+    }
+
+    pub(crate) fn from_memo(memo: Option<&str>) -> Self {
+        Self {
+            memo: memo.map(Into::into),
+            ..Default::default()
+        }
+    }
+}
+
 pub trait TxSender: QuerySender + Sync {
     /// Returns the `AccountId` of the sender that commits the transaction.
     fn account_id(&self) -> AccountId;
@@ -19,6 +85,16 @@ pub trait TxSender: QuerySender + Sync {
         memo: Option<&str>,
     ) -> impl std::future::Future<Output = Result<CosmTxResponse, Self::Error>> + Send;
 
+    /// Commit a proto `Any` message to the chain using this sender, with custom transaction options.
+    /// Senders that don't support transaction options only use the memo.
+    fn commit_tx_any_with_options(
+        &self,
+        msgs: Vec<Any>,
+        options: &TxOptions,
+    ) -> impl std::future::Future<Output = Result<CosmTxResponse, Self::Error>> + Send {
+        self.commit_tx_any(msgs, options.memo.as_deref())
+    }
+
     /// Get the address of the sender.
     fn address(&self) -> Addr {
         Addr::unchecked(self.account_id().to_string())
@@ -46,6 +122,21 @@ pub trait TxSender: QuerySender + Sync {
         self.commit_tx_any(msgs, memo)
     }
 
+    /// Commit a transaction to the chain using this sender, with custom transaction options.
+    fn commit_tx_with_options<T: Msg>(
+        &self,
+        msgs: Vec<T>,
+        options: &TxOptions,
+    ) -> impl std::future::Future<Output = Result<CosmTxResponse, Self::Error>> + Send {
+        let msgs = msgs
+            .into_iter()
+            .map(Msg::into_any)
+            .collect::<Result<Vec<Any>, _>>()
+            .unwrap();
+
+        self.commit_tx_any_with_options(msgs, options)
+    }
+
     /// Transaction broadcasting for Tendermint Transactions
     fn broadcast_tx(
         &self,
@@ -1,6 +1,8 @@
 use crate::senders::builder::SenderBuilder;
 
-use crate::{DaemonAsyncBuilder, DaemonBase, DaemonState, Wallet, RUNTIME};
+use crate::{
+    tx_broadcaster::RetryPolicy, DaemonAsyncBuilder, DaemonBase, DaemonState, Wallet, RUNTIME,
+};
 use cw_orch_core::environment::ChainInfoOwned;
 
 use super::super::error::DaemonError;
@@ -30,8 +32,11 @@ pub struct DaemonBuilder {
     // # Use tempfile as state
     pub(crate) is_test: bool,
     pub(crate) load_network: bool,
+    pub(crate) network_overrides: Option<ChainInfoOwned>,
+    pub(crate) registry_name: Option<String>,
 
     pub(crate) mnemonic: Option<String>,
+    pub(crate) retry_policy: Option<RetryPolicy>,
 }
 
 impl DaemonBuilder {
@@ -44,8 +49,11 @@ impl DaemonBuilder {
             state: None,
             write_on_change: None,
             mnemonic: None,
+            retry_policy: None,
             is_test: false,
             load_network: true,
+            network_overrides: None,
+            registry_name: None,
         }
     }
 
@@ -117,12 +125,25 @@ impl DaemonBuilder {
         self
     }
 
+    /// Set the strategies used to retry failed transaction broadcasts of the default Cosmos wallet
+    pub fn retry_policy(&mut self, retry_policy: RetryPolicy) -> &mut Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
     /// Overwrite the chain info
     pub fn chain(&mut self, chain: impl Into<ChainInfoOwned>) -> &mut Self {
         self.chain = chain.into();
         self
     }
 
+    /// Load the chain from the chain registry at `CW_ORCH_CHAIN_REGISTRY` by its registry name (ex. `osmosis`).
+    /// The builder chain info overwrites the registry chain info. Requires the `chain-registry` feature
+    pub fn chain_registry_name(&mut self, chain_name: impl Into<String>) -> &mut Self {
+        self.registry_name = Some(chain_name.into());
+        self
+    }
+
     /// Set daemon as testing daemon
     /// when set to `true` will use temporary file for state
     pub fn is_test(&mut self, is_test: bool) -> &mut Self {
@@ -130,13 +151,20 @@ impl DaemonBuilder {
         self
     }
 
-    /// Load network from `$HOME/.cw-orchestrator/networks.toml`
+    /// Load network from the layered `networks.toml` files, see [`network_config`](crate::network_config)
     /// Defaults to `true`
     pub fn load_network(&mut self, load_network: bool) -> &mut Self {
         self.load_network = load_network;
         self
     }
 
+    /// Overrides applied on top of the network config files.
+    /// Only the fields set in `overrides` are overwritten, see [`ChainInfoOwned::overwrite_with`]
+    pub fn network_overrides(&mut self, overrides: ChainInfoOwned) -> &mut Self {
+        self.network_overrides = Some(overrides);
+        self
+    }
+
     /// Build a Daemon with the default [`Wallet`] implementation.
     pub fn build(&self) -> Result<DaemonBase<Wallet>, DaemonError> {
         let rt_handle = self
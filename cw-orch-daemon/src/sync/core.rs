@@ -2,16 +2,17 @@ use std::{fmt::Debug, ops::DerefMut};
 
 use super::super::senders::Wallet;
 use crate::{
+    accounting::{SessionAccounting, SessionReport, TxAction, TxTarget},
     queriers::{Bank, CosmWasmBase, Node},
     senders::{builder::SenderBuilder, query::QuerySender},
     CosmTxResponse, DaemonAsyncBase, DaemonBuilder, DaemonError, DaemonState,
 };
-use cosmwasm_std::{Addr, Coin};
+use cosmwasm_std::{Addr, Coin, VoteOption};
 use cw_orch_core::{
     contract::{interface_traits::Uploadable, WasmPath},
     environment::{ChainInfoOwned, ChainState, DefaultQueriers, QueryHandler, TxHandler},
 };
-use cw_orch_traits::stargate::Stargate;
+use cw_orch_traits::{stargate::Stargate, Delegator, Voter};
 use serde::Serialize;
 use tokio::runtime::Handle;
 use tonic::transport::Channel;
@@ -88,6 +89,34 @@ impl<Sender> DaemonBase<Sender> {
         self.daemon.flush_state()
     }
 
+    /// Returns a copy of this daemon whose queries are resolved at the given block height.
+    /// Contract queries (including `item_query` and `map_query`), the module queriers and `block_info` are scoped to that height.
+    /// Transactions are not affected. The node needs to have the state at that height available (i.e. not pruned).
+    pub fn at_height(&self, height: u64) -> Self
+    where
+        Sender: Clone,
+    {
+        DaemonBase {
+            daemon: self.daemon.at_height(height),
+            rt_handle: self.rt_handle.clone(),
+        }
+    }
+
+    /// Block height the queries of this daemon are resolved at, `None` for the latest height
+    pub fn query_height(&self) -> Option<u64> {
+        self.daemon.query_height()
+    }
+
+    /// Gas and fee accounting of the transactions sent during this session
+    pub fn accounting(&self) -> &SessionAccounting {
+        self.daemon.accounting()
+    }
+
+    /// Gas and fee report of the transactions sent during this session
+    pub fn tx_report(&self) -> SessionReport {
+        self.daemon.tx_report()
+    }
+
     /// Return the chain info for this daemon
     pub fn chain_info(&self) -> &ChainInfoOwned {
         self.daemon.chain_info()
@@ -100,6 +129,16 @@ impl<Sender: QuerySender> DaemonBase<Sender> {
         self.daemon.sender().channel()
     }
 
+    /// Subscribe to the CometBFT events matching `query`, for example `tm.event='Tx' AND wasm._contract_address='juno1...'`
+    /// Events can be awaited with `daemon.rt_handle.block_on(subscription.next())`
+    #[cfg(feature = "websocket")]
+    pub fn subscribe_events(
+        &self,
+        query: impl Into<String>,
+    ) -> Result<crate::websocket::EventSubscription, DaemonError> {
+        self.rt_handle.block_on(self.daemon.subscribe_events(query))
+    }
+
     /// Returns a new [`DaemonBuilder`] with the current configuration.
     /// **Does not copy the `Sender`**
     /// Does not consume the original [`Daemon`].
@@ -112,10 +151,13 @@ impl<Sender: QuerySender> DaemonBase<Sender> {
             write_on_change: None,
             handle: Some(self.rt_handle.clone()),
             mnemonic: None,
+            retry_policy: None,
             // If it was test it will just use same tempfile as state
             is_test: false,
             // Uses same ChainInfo
             load_network: false,
+            network_overrides: None,
+            registry_name: None,
         }
     }
 }
@@ -242,10 +284,14 @@ impl<Sender: TxSender> TxHandler for DaemonBase<Sender> {
         receiver: &Addr,
         amount: &[cosmwasm_std::Coin],
     ) -> Result<Self::Response, Self::Error> {
-        self.rt_handle
+        let result = self
+            .rt_handle
             .block_on(self.sender().bank_send(receiver, amount))
-            .map_err(Into::into)
-            .map(Into::into)
+            .map_err(Into::into)?;
+        self.daemon
+            .accounting
+            .record(TxTarget::None, TxAction::Send, &result);
+        Ok(result)
     }
 }
 
@@ -255,7 +301,8 @@ impl<Sender: TxSender> Stargate for DaemonBase<Sender> {
         msgs: Vec<prost_types::Any>,
         memo: Option<&str>,
     ) -> Result<Self::Response, Self::Error> {
-        self.rt_handle
+        let result = self
+            .rt_handle
             .block_on(
                 self.sender().commit_tx_any(
                     msgs.iter()
@@ -267,7 +314,11 @@ impl<Sender: TxSender> Stargate for DaemonBase<Sender> {
                     memo,
                 ),
             )
-            .map_err(Into::into)
+            .map_err(Into::into)?;
+        self.daemon
+            .accounting
+            .record(TxTarget::None, TxAction::Other, &result);
+        Ok(result)
     }
 }
 
@@ -293,6 +344,49 @@ impl<Sender: QuerySender> QueryHandler for DaemonBase<Sender> {
     }
 }
 
+impl<Sender: TxSender> Delegator for DaemonBase<Sender> {
+    fn delegate(&self, validator: &str, amount: &Coin) -> Result<Self::Response, Self::Error> {
+        self.rt_handle
+            .block_on(self.daemon.delegate(validator, amount))
+    }
+
+    fn undelegate(&self, validator: &str, amount: &Coin) -> Result<Self::Response, Self::Error> {
+        self.rt_handle
+            .block_on(self.daemon.undelegate(validator, amount))
+    }
+
+    fn redelegate(
+        &self,
+        src_validator: &str,
+        dst_validator: &str,
+        amount: &Coin,
+    ) -> Result<Self::Response, Self::Error> {
+        self.rt_handle
+            .block_on(self.daemon.redelegate(src_validator, dst_validator, amount))
+    }
+
+    fn withdraw_rewards(&self, validator: &str) -> Result<Self::Response, Self::Error> {
+        self.rt_handle
+            .block_on(self.daemon.withdraw_rewards(validator))
+    }
+
+    fn withdraw_all_rewards(&self) -> Result<Self::Response, Self::Error> {
+        self.rt_handle.block_on(self.daemon.withdraw_all_rewards())
+    }
+
+    fn set_withdraw_address(&self, address: &Addr) -> Result<Self::Response, Self::Error> {
+        self.rt_handle
+            .block_on(self.daemon.set_withdraw_address(address))
+    }
+}
+
+impl<Sender: TxSender> Voter for DaemonBase<Sender> {
+    fn vote(&self, proposal_id: u64, option: VoteOption) -> Result<Self::Response, Self::Error> {
+        self.rt_handle
+            .block_on(self.daemon.vote(proposal_id, option))
+    }
+}
+
 impl<Sender: QuerySender> DefaultQueriers for DaemonBase<Sender> {
     type Bank = Bank;
     type Wasm = CosmWasmBase<Sender>;
@@ -0,0 +1,145 @@
+//! `tracing` integration, enabled with the `tracing` feature.
+//!
+//! Every contract action runs inside a [`CONTRACT_ACTION_SPAN`] span (contract id, action, msg).
+//! The daemon records the chain id, gas, fee and hash of the committed transaction on that span
+//! and emits a [`TX_EVENT_TARGET`] event for every transaction.
+//! [`json_subscriber`] turns these into a JSON event stream, one line per event.
+
+use std::io;
+
+use cw_orch_core::log::CONTRACT_ACTION_SPAN;
+use tracing::Subscriber;
+use tracing_subscriber::fmt::MakeWriter;
+
+use crate::{CosmTxResponse, DaemonError};
+
+/// Target of the events emitted for every committed transaction
+pub const TX_EVENT_TARGET: &str = "cw_orch::tx";
+
+/// Subscriber writing every event as a JSON line to `make_writer`.
+/// Events carry the fields of the [`CONTRACT_ACTION_SPAN`] they were emitted in.
+pub fn json_subscriber<W>(make_writer: W) -> impl Subscriber + Send + Sync
+where
+    W: for<'a> MakeWriter<'a> + Send + Sync + 'static,
+{
+    tracing_subscriber::fmt()
+        .json()
+        .with_current_span(true)
+        .with_span_list(false)
+        .with_writer(make_writer)
+        .finish()
+}
+
+/// Sets [`json_subscriber`] writing to stdout as the global subscriber
+pub fn init_json_events() -> Result<(), DaemonError> {
+    tracing::subscriber::set_global_default(json_subscriber(io::stdout))
+        .map_err(|e| DaemonError::StdErr(e.to_string()))
+}
+
+/// Records a committed transaction on the current contract action span and emits its event
+pub(crate) fn record_tx(chain_id: &str, response: &CosmTxResponse) {
+    let fee = response
+        .fee()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let span = tracing::Span::current();
+    if span
+        .metadata()
+        .is_some_and(|metadata| metadata.name() == CONTRACT_ACTION_SPAN)
+    {
+        span.record("chain_id", chain_id)
+            .record("gas_wanted", response.gas_wanted)
+            .record("gas_used", response.gas_used)
+            .record("fee", fee.as_str())
+            .record("tx_hash", response.txhash.as_str());
+    }
+
+    tracing::info!(
+        target: TX_EVENT_TARGET,
+        chain_id,
+        tx_hash = %response.txhash,
+        height = response.height,
+        code = response.code,
+        gas_wanted = response.gas_wanted,
+        gas_used = response.gas_used,
+        fee = fee.as_str(),
+        "transaction committed"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::cosmos_modules::tendermint_abci::{Event, EventAttribute};
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for Buffer {
+        type Writer = Buffer;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn tx_event_carries_contract_action() -> anyhow::Result<()> {
+        let buffer = Buffer::default();
+        let response = CosmTxResponse {
+            txhash: "ABCD".to_string(),
+            gas_wanted: 200_000,
+            gas_used: 150_000,
+            events: vec![Event {
+                r#type: "tx".to_string(),
+                attributes: vec![EventAttribute {
+                    key: "fee".into(),
+                    value: "5000ujuno".into(),
+                    index: true,
+                }],
+            }],
+            ..Default::default()
+        };
+
+        tracing::subscriber::with_default(json_subscriber(buffer.clone()), || {
+            let _span = tracing::info_span!(
+                CONTRACT_ACTION_SPAN,
+                contract_id = "counter",
+                action = "execute",
+                chain_id = tracing::field::Empty,
+                gas_wanted = tracing::field::Empty,
+                gas_used = tracing::field::Empty,
+                fee = tracing::field::Empty,
+                tx_hash = tracing::field::Empty,
+            )
+            .entered();
+            record_tx("juno-1", &response);
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone())?;
+        let event: serde_json::Value = serde_json::from_str(output.lines().next().unwrap())?;
+        assert_eq!(event["target"], TX_EVENT_TARGET);
+        assert_eq!(event["fields"]["tx_hash"], "ABCD");
+        assert_eq!(event["fields"]["gas_used"], 150_000);
+        assert_eq!(event["fields"]["fee"], "5000ujuno");
+        assert_eq!(event["span"]["contract_id"], "counter");
+        assert_eq!(event["span"]["tx_hash"], "ABCD");
+        Ok(())
+    }
+}
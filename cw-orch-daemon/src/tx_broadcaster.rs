@@ -1,59 +1,223 @@
+use std::{future::Future, sync::Arc, time::Duration};
+
 use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
 use cw_orch_core::log::transaction_target;
+use tonic::Code;
 
 use crate::senders::tx::TxSender;
 use crate::{queriers::Node, senders::sign::Signer, CosmTxResponse, DaemonError, TxBuilder};
 
-pub type StrategyAction =
-    fn(&mut TxBuilder, &Result<TxResponse, DaemonError>) -> Result<(), DaemonError>;
-
+/// Maximum number of retries on account sequence errors
+pub const ACCOUNT_SEQUENCE_RETRIES: u64 = 10;
+/// Maximum number of retries when the mempool of the node is full
+pub const MEMPOOL_FULL_RETRIES: u64 = 5;
+/// Maximum number of retries when the transaction is already in the mempool cache
+pub const TX_IN_CACHE_RETRIES: u64 = 3;
+/// Maximum number of retries on transient gRPC/transport errors
+pub const TRANSIENT_ERROR_RETRIES: u64 = 5;
+
+pub type BroadcastCondition = Arc<dyn Fn(&TxResponse) -> bool + Send + Sync>;
+pub type SimulationCondition = Arc<dyn Fn(&DaemonError) -> bool + Send + Sync>;
+pub type StrategyAction = Arc<
+    dyn Fn(&mut TxBuilder, &Result<TxResponse, DaemonError>) -> Result<(), DaemonError>
+        + Send
+        + Sync,
+>;
+
+#[derive(Clone)]
 pub struct RetryStrategy {
-    /// This function is called right after a transaction has been submitted to a node.
+    /// This function is called right after a transaction has been submitted to a node and when the transaction failed during execution.
     /// It is used to check if the transaction has been submitted successfully or if an error occurred
     /// This function returns true if an error corresponding to the current strategy is detected
-    pub broadcast_condition: fn(&TxResponse) -> bool,
-    /// This function is called when a simulation error occurs
+    pub broadcast_condition: BroadcastCondition,
+    /// This function is called when building (simulating) or submitting the transaction returns an error
     /// This function return true if an error corresponding to the current strategy is detected
-    pub simulation_condition: fn(&DaemonError) -> bool,
+    pub simulation_condition: SimulationCondition,
     /// Once the algorithm detects an error when broadcasting/simulating a transaction, it triggers this action before re-trying tx submission
     /// This action takes the transaction builder and the transaction response result as arguments
     /// It should make changes to the tx builder object (which is passed as a mutable reference)
     /// The tx_builder object will be used after that to re-try submitting the transaction
     pub action: Option<StrategyAction>,
     pub max_retries: BroadcastRetry,
+    /// Delay before retrying. Defaults to the backoff of the [`RetryPolicy`]
+    pub backoff: Option<Backoff>,
     pub(crate) current_retries: u64,
     pub reason: String,
 }
 
 impl RetryStrategy {
     pub fn new(
-        broadcast_condition: fn(&TxResponse) -> bool,
-        simulation_condition: fn(&DaemonError) -> bool,
-        action: Option<StrategyAction>,
+        broadcast_condition: impl Fn(&TxResponse) -> bool + Send + Sync + 'static,
+        simulation_condition: impl Fn(&DaemonError) -> bool + Send + Sync + 'static,
         max_retries: BroadcastRetry,
-        reason: String,
+        reason: impl Into<String>,
     ) -> Self {
         RetryStrategy {
-            broadcast_condition,
-            simulation_condition,
-            action,
+            broadcast_condition: Arc::new(broadcast_condition),
+            simulation_condition: Arc::new(simulation_condition),
+            action: None,
             max_retries,
+            backoff: None,
             current_retries: 0,
-            reason,
+            reason: reason.into(),
         }
     }
-}
 
-#[derive(Default)]
-pub struct TxBroadcaster {
-    strategies: Vec<RetryStrategy>,
+    /// Sets the action applied to the transaction before retrying
+    pub fn action(
+        mut self,
+        action: impl Fn(&mut TxBuilder, &Result<TxResponse, DaemonError>) -> Result<(), DaemonError>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.action = Some(Arc::new(action));
+        self
+    }
+
+    /// Sets the maximum number of retries of this strategy
+    pub fn max_retries(mut self, max_retries: BroadcastRetry) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before retrying, overriding the backoff of the [`RetryPolicy`]
+    pub fn backoff(mut self, backoff: Backoff) -> Self {
+        self.backoff = Some(backoff);
+        self
+    }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum BroadcastRetry {
     Infinite,
     Finite(u64),
 }
 
+/// Delay between two broadcasts of the same transaction
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Backoff {
+    /// Wait for the average block time of the chain
+    #[default]
+    BlockTime,
+    /// Wait for a fixed duration
+    Fixed(Duration),
+    /// Wait `initial * factor^(retry - 1)`, capped to `max`
+    Exponential {
+        initial: Duration,
+        factor: f64,
+        max: Duration,
+    },
+}
+
+impl Backoff {
+    /// Delay before the `retry`-th retry. Returns `None` for [`Backoff::BlockTime`], which depends on the chain.
+    pub fn delay(&self, retry: u64) -> Option<Duration> {
+        match self {
+            Backoff::BlockTime => None,
+            Backoff::Fixed(delay) => Some(*delay),
+            Backoff::Exponential {
+                initial,
+                factor,
+                max,
+            } => {
+                let exponent = retry.saturating_sub(1).min(i32::MAX as u64) as i32;
+                let delay = initial.as_secs_f64() * factor.powi(exponent);
+                Some(Duration::try_from_secs_f64(delay).map_or(*max, |delay| delay.min(*max)))
+            }
+        }
+    }
+}
+
+/// Strategies used to retry failed transaction broadcasts.
+///
+/// The default policy retries on insufficient fees, account sequence errors, full mempools,
+/// transactions already in the mempool cache and transient gRPC/transport errors.
+/// ```
+/// use cw_orch_daemon::tx_broadcaster::{out_of_gas_strategy, Backoff, RetryPolicy};
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::default()
+///     .strategy(out_of_gas_strategy(1.5))
+///     .backoff(Backoff::Fixed(Duration::from_secs(2)))
+///     .max_total_retries(10);
+/// ```
+#[derive(Clone)]
+pub struct RetryPolicy {
+    strategies: Vec<RetryStrategy>,
+    backoff: Backoff,
+    max_total_retries: Option<u64>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        // 1. In case there is an insufficient_fee error, we retry once (costs fee to the user everytime we submit this kind of tx)
+        // 2. In case there is an `incorrect account sequence` error, we retry a bounded number of times (doesn't cost anything to the user)
+        // 3. Full mempools, cached txs and transport errors are retried as they don't cost anything either
+        Self::none()
+            .strategy(insufficient_fee_strategy())
+            .strategy(account_sequence_strategy())
+            .strategy(mempool_full_strategy())
+            .strategy(tx_in_cache_strategy())
+            .strategy(transient_error_strategy())
+    }
+}
+
+impl RetryPolicy {
+    /// Policy without any strategy, failed transactions are never retried
+    pub fn none() -> Self {
+        Self {
+            strategies: vec![],
+            backoff: Backoff::default(),
+            max_total_retries: None,
+        }
+    }
+
+    /// Adds a retry strategy to the policy
+    /// Order of strategy addition matters, strategy conditions are tested in order of addition.
+    pub fn strategy(mut self, strategy: RetryStrategy) -> Self {
+        self.strategies.push(strategy);
+        self
+    }
+
+    /// Delay between retries for strategies that don't define their own backoff.
+    /// Defaults to [`Backoff::BlockTime`]
+    pub fn backoff(mut self, backoff: Backoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Maximum number of retries of a transaction, over all strategies
+    pub fn max_total_retries(mut self, max_total_retries: u64) -> Self {
+        self.max_total_retries = Some(max_total_retries);
+        self
+    }
+
+    /// Strategies of this policy, in the order they are tested
+    pub fn strategies(&self) -> &[RetryStrategy] {
+        &self.strategies
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct TxBroadcaster {
+    strategies: Vec<RetryStrategy>,
+    backoff: Backoff,
+    max_total_retries: Option<u64>,
+    total_retries: u64,
+}
+
+impl From<RetryPolicy> for TxBroadcaster {
+    fn from(policy: RetryPolicy) -> Self {
+        Self {
+            strategies: policy.strategies,
+            backoff: policy.backoff,
+            max_total_retries: policy.max_total_retries,
+            total_retries: 0,
+        }
+    }
+}
+
 impl TxBroadcaster {
     /// Adds a retry strategy to the broadcaster
     /// Order of strategy addition matters, strategy conditions are tested in order of addition.
@@ -69,45 +233,105 @@ impl TxBroadcaster {
         mut tx_builder: TxBuilder,
         signer: &impl Signer,
     ) -> Result<TxResponse, DaemonError> {
-        let mut tx_retry = true;
+        self.broadcast_with_retries(&mut tx_builder, signer).await
+    }
 
+    /// Broadcasts a transaction with the given signer and waits for its inclusion with `confirm`.
+    /// Transactions that fail during execution are retried as well if a strategy matches the execution error.
+    pub async fn broadcast_and_confirm<F, Fut>(
+        mut self,
+        mut tx_builder: TxBuilder,
+        signer: &impl Signer,
+        confirm: F,
+    ) -> Result<CosmTxResponse, DaemonError>
+    where
+        F: Fn(String) -> Fut,
+        Fut: Future<Output = Result<CosmTxResponse, DaemonError>>,
+    {
+        loop {
+            let tx_response = self.broadcast_with_retries(&mut tx_builder, signer).await?;
+            let resp = confirm(tx_response.txhash).await?;
+            if resp.code == 0 {
+                return Ok(resp);
+            }
+
+            let execution_result = Ok(execution_response(&resp));
+            if !self
+                .retry(&mut tx_builder, &execution_result, signer)
+                .await?
+            {
+                return assert_broadcast_code_cosm_response(resp);
+            }
+        }
+    }
+
+    async fn broadcast_with_retries(
+        &mut self,
+        tx_builder: &mut TxBuilder,
+        signer: &impl Signer,
+    ) -> Result<TxResponse, DaemonError> {
         // We try and broadcast once
-        let mut tx_response = broadcast_helper(&mut tx_builder, signer).await;
+        let mut tx_response = broadcast_helper(tx_builder, signer).await;
         log::info!(
             target: &transaction_target(),
             "Awaiting TX inclusion in block..."
         );
-        while tx_retry {
-            tx_retry = false;
-
-            // On error, we verify it matches the retry strategies. If it does, we update the retry counts and start over
-            for s in self.strategies.iter_mut() {
-                if strategy_condition_met(s, &tx_response) && can_retry(s) {
-                    // We update the tx and resubmit
-                    if let Some(action) = s.action {
-                        action(&mut tx_builder, &tx_response)?;
-                    }
-                    tx_retry = true;
-
-                    // We still await for the next block, to avoid spamming retry when an error occurs
-                    let block_speed = Node::new_async(signer.channel())
-                        ._average_block_speed(None)
-                        .await?;
-                    log::warn!(
-                        target: &transaction_target(),
-                        "Retrying broadcasting TX in {:?} milliseconds because of {}",
-                        block_speed.as_millis(),
-                        s.reason
-                    );
-                    tokio::time::sleep(block_speed).await;
-
-                    tx_response = broadcast_helper(&mut tx_builder, signer).await;
-                    continue;
-                }
-            }
+        // On error, we verify it matches the retry strategies. If it does, we start over
+        while self.retry(tx_builder, &tx_response, signer).await? {
+            tx_response = broadcast_helper(tx_builder, signer).await;
         }
         tx_response
     }
+
+    /// Applies the first strategy matching `tx_response` and waits before the next attempt.
+    /// Returns `false` if the transaction shouldn't be retried.
+    async fn retry(
+        &mut self,
+        tx_builder: &mut TxBuilder,
+        tx_response: &Result<TxResponse, DaemonError>,
+        signer: &impl Signer,
+    ) -> Result<bool, DaemonError> {
+        if self
+            .max_total_retries
+            .is_some_and(|max_total_retries| self.total_retries >= max_total_retries)
+        {
+            return Ok(false);
+        }
+        let Some(index) = self
+            .strategies
+            .iter_mut()
+            .position(|s| strategy_condition_met(s, tx_response) && can_retry(s))
+        else {
+            return Ok(false);
+        };
+        self.total_retries += 1;
+        let s = &self.strategies[index];
+
+        // We update the tx and resubmit
+        if let Some(action) = &s.action {
+            action(tx_builder, tx_response)?;
+        }
+
+        // We still wait before retrying, to avoid spamming retries when an error occurs
+        let backoff = s.backoff.as_ref().unwrap_or(&self.backoff);
+        let delay = match backoff.delay(s.current_retries) {
+            Some(delay) => delay,
+            None => {
+                Node::new_async(signer.channel())
+                    ._average_block_speed(None)
+                    .await?
+            }
+        };
+        log::warn!(
+            target: &transaction_target(),
+            "Retrying broadcasting TX in {:?} milliseconds because of {}",
+            delay.as_millis(),
+            s.reason
+        );
+        tokio::time::sleep(delay).await;
+
+        Ok(true)
+    }
 }
 
 fn strategy_condition_met(
@@ -120,14 +344,61 @@ fn strategy_condition_met(
     }
 }
 
+/// Converts the result of an included transaction to be tested against the strategy conditions
+fn execution_response(resp: &CosmTxResponse) -> TxResponse {
+    TxResponse {
+        height: resp.height as i64,
+        txhash: resp.txhash.clone(),
+        codespace: resp.codespace.clone(),
+        code: resp.code as u32,
+        raw_log: resp.raw_log.clone(),
+        gas_wanted: resp.gas_wanted as i64,
+        gas_used: resp.gas_used as i64,
+        ..Default::default()
+    }
+}
+
 async fn broadcast_helper(
     tx_builder: &mut TxBuilder,
     signer: &impl Signer,
 ) -> Result<TxResponse, DaemonError> {
-    let tx = tx_builder.build(signer).await?;
-    let tx_response = signer.broadcast_tx(tx).await?;
+    let tx = match tx_builder.build(signer).await {
+        Ok(tx) => tx,
+        Err(e) => {
+            let error = e.to_string();
+            if has_account_sequence_error(&error) {
+                signer
+                    .on_sequence_mismatch(parse_expected_sequence(&error))
+                    .await;
+            }
+            return Err(e);
+        }
+    };
+    // The sequence reserved when building the tx is given back if the node doesn't accept the tx
+    let reserved_sequence = tx_builder.reserved_sequence.take();
+    let tx_response = match signer.broadcast_tx(tx).await {
+        Ok(tx_response) => tx_response,
+        Err(e) => {
+            if let Some(sequence) = reserved_sequence {
+                signer.release_sequence(sequence).await;
+            }
+            return Err(e);
+        }
+    };
     log::debug!(target: &transaction_target(), "TX broadcast response: {:?}", tx_response);
 
+    if has_account_sequence_error(&tx_response.raw_log) {
+        signer
+            .on_sequence_mismatch(parse_expected_sequence(&tx_response.raw_log))
+            .await;
+    } else if let Some(sequence) = reserved_sequence {
+        if tx_response.code == 0 {
+            signer.on_sequence_accepted(sequence).await;
+        } else {
+            signer.release_sequence(sequence).await;
+        }
+    }
+
     assert_broadcast_code_response(tx_response)
 }
 
@@ -164,12 +435,10 @@ pub fn assert_broadcast_code_cosm_response(
 }
 
 fn can_retry(s: &mut RetryStrategy) -> bool {
+    s.current_retries += 1;
     match s.max_retries {
         BroadcastRetry::Infinite => true,
-        BroadcastRetry::Finite(max_retries) => {
-            s.current_retries += 1;
-            s.current_retries <= max_retries
-        }
+        BroadcastRetry::Finite(max_retries) => s.current_retries <= max_retries,
     }
 }
 
@@ -177,83 +446,167 @@ fn has_insufficient_fee(raw_log: &str) -> bool {
     raw_log.contains("insufficient fees")
 }
 
+/// Parses a coin formatted as `{amount}{denom}`. Decimal amounts are rounded up.
+pub(crate) fn parse_coin_str(coin: &str) -> Option<(u128, String)> {
+    let coin = coin.trim();
+    let denom_start = coin.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (amount, denom) = coin.split_at(denom_start);
+
+    let amount = match amount.parse::<u128>() {
+        Ok(amount) => amount,
+        Err(_) => amount.parse::<f64>().ok()?.ceil() as u128,
+    };
+    Some((amount, denom.to_string()))
+}
+
 // from logs: "insufficient fees; got: 14867ujuno
 // required: 17771ibc/C4CFF46FD6DE35CA4CF4CE031E643C8FDC9BA4B99AE598E9B0ED98FE3A2319F9,444255ujuno: insufficient fee"
-fn parse_suggested_fee(raw_log: &str) -> Option<u128> {
-    // Step 1: Split the log message into "got" and "required" parts.
-    let parts: Vec<&str> = raw_log.split("required: ").collect();
-
-    // Make sure the log message is in the expected format.
-    if parts.len() != 2 {
-        return None;
-    }
-
-    // Step 2: Split the "got" part to extract the paid fee and denomination.
-    let got_parts: Vec<&str> = parts[0].split_whitespace().collect();
-
-    // Extract the paid fee and denomination.
-    let paid_fee_with_denom = got_parts.last()?;
-    let (_, denomination) =
-        paid_fee_with_denom.split_at(paid_fee_with_denom.find(|c: char| !c.is_numeric())?);
-
-    eprintln!("denom: {}", denomination);
-
-    // Step 3: Iterate over each fee in the "required" part.
-    let required_fees: Vec<&str> = parts[1].split(denomination).collect();
-
-    eprintln!("required fees: {:?}", required_fees);
-
-    // read until the first non-numeric character backwards on the first string
-    let (_, suggested_fee) =
-        required_fees[0].split_at(required_fees[0].rfind(|c: char| !c.is_numeric())?);
-    eprintln!("suggested fee: {}", suggested_fee);
-
-    // remove the first character if parsing errors, which can be a comma
-    suggested_fee
-        .parse::<u128>()
-        .ok()
-        .or(suggested_fee[1..].parse::<u128>().ok())
+/// Returns the fee suggested by the node.
+/// The required fee in the denom that was paid is preferred, otherwise the first accepted denom is used.
+fn parse_suggested_fee(raw_log: &str) -> Option<(u128, String)> {
+    let (got, required) = raw_log.split_once("required: ")?;
+
+    // Drop the trailing error message
+    let required = required.split(':').next()?;
+    let required_fees: Vec<(u128, String)> =
+        required.split(',').filter_map(parse_coin_str).collect();
+
+    let paid_denom = got
+        .split_once("got: ")
+        .and_then(|(_, got)| got.split(',').next())
+        .and_then(parse_coin_str)
+        .map(|(_, denom)| denom);
+
+    required_fees
+        .iter()
+        .find(|(_, denom)| Some(denom) == paid_denom.as_ref())
+        .or(required_fees.first())
+        .cloned()
 }
 
 pub fn insufficient_fee_strategy() -> RetryStrategy {
     RetryStrategy::new(
         |tx_response| has_insufficient_fee(&tx_response.raw_log),
         |_| false, // Simulation doesn't have gas issues
-        Some(|tx_builder, tx_response| {
-            // get the suggested fee from the error message
-            // If we enter this function for this specific strategy, it's because something was detected in the tx response object
-            let suggested_fee = parse_suggested_fee(&tx_response.as_ref().unwrap().raw_log);
-
-            let Some(new_fee) = suggested_fee else {
-                return Err(DaemonError::InsufficientFee(
-                    tx_response.as_ref().unwrap().raw_log.clone(),
-                ));
-            };
-
-            // update the fee and try again
-            tx_builder.fee_amount(new_fee);
-
-            Ok(())
-        }),
         BroadcastRetry::Finite(1),
-        "an insufficient fee error".to_string(),
+        "an insufficient fee error",
     )
+    .action(|tx_builder, tx_response| {
+        // get the suggested fee from the error message
+        // If we enter this function for this specific strategy, it's because something was detected in the tx response object
+        let suggested_fee = parse_suggested_fee(&tx_response.as_ref().unwrap().raw_log);
+
+        let Some((new_fee, fee_denom)) = suggested_fee else {
+            return Err(DaemonError::InsufficientFee(
+                tx_response.as_ref().unwrap().raw_log.clone(),
+            ));
+        };
+
+        // update the fee and try again
+        tx_builder.fee_amount(new_fee).fee_denom(fee_denom);
+
+        Ok(())
+    })
 }
 
 fn has_account_sequence_error(raw_log: &str) -> bool {
     raw_log.contains("incorrect account sequence")
 }
 
+// from logs: "account sequence mismatch, expected 12, got 11: incorrect account sequence"
+fn parse_expected_sequence(raw_log: &str) -> Option<u64> {
+    let (_, expected) = raw_log.split_once("expected ")?;
+    let digits_end = expected
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(expected.len());
+
+    expected[..digits_end].parse().ok()
+}
+
 pub fn account_sequence_strategy() -> RetryStrategy {
     RetryStrategy::new(
         |tx_response| has_account_sequence_error(&tx_response.raw_log),
         |simulation_error| has_account_sequence_error(&simulation_error.to_string()),
-        None,
-        BroadcastRetry::Infinite,
-        "an account sequence error".to_string(),
+        BroadcastRetry::Finite(ACCOUNT_SEQUENCE_RETRIES),
+        "an account sequence error",
+    )
+}
+
+fn has_out_of_gas_error(raw_log: &str) -> bool {
+    raw_log.contains("out of gas")
+}
+
+/// Multiplies the gas of the transaction by `gas_multiplier` and simulates it again when it ran out of gas.
+/// Retrying costs fees when the transaction ran out of gas during execution, so it is retried only once.
+pub fn out_of_gas_strategy(gas_multiplier: f64) -> RetryStrategy {
+    RetryStrategy::new(
+        |tx_response| has_out_of_gas_error(&tx_response.raw_log),
+        |_| false, // Simulation is not gas limited
+        BroadcastRetry::Finite(1),
+        "an out of gas error",
+    )
+    .action(move |tx_builder, _| {
+        tx_builder.raise_gas_limit(gas_multiplier);
+        Ok(())
+    })
+}
+
+fn has_mempool_full_error(raw_log: &str) -> bool {
+    raw_log.contains("mempool is full")
+}
+
+pub fn mempool_full_strategy() -> RetryStrategy {
+    RetryStrategy::new(
+        |tx_response| has_mempool_full_error(&tx_response.raw_log),
+        |error| has_mempool_full_error(&error.to_string()),
+        BroadcastRetry::Finite(MEMPOOL_FULL_RETRIES),
+        "a full mempool",
+    )
+}
+
+// CometBFT: "tx already exists in cache", Cosmos SDK: "tx already in mempool"
+fn has_tx_in_cache_error(raw_log: &str) -> bool {
+    raw_log.contains("tx already exists in cache") || raw_log.contains("tx already in mempool")
+}
+
+/// Waits before broadcasting a transaction that is already in the mempool of the node.
+/// Once it is included, the next attempt fails with an account sequence error and is re-signed.
+pub fn tx_in_cache_strategy() -> RetryStrategy {
+    RetryStrategy::new(
+        |tx_response| has_tx_in_cache_error(&tx_response.raw_log),
+        |error| has_tx_in_cache_error(&error.to_string()),
+        BroadcastRetry::Finite(TX_IN_CACHE_RETRIES),
+        "a transaction already in the mempool cache",
     )
 }
 
+fn is_transient_error(error: &DaemonError) -> bool {
+    match error {
+        DaemonError::TransportError(_) => true,
+        DaemonError::Status(status) => match status.code() {
+            Code::Unavailable | Code::DeadlineExceeded | Code::Aborted => true,
+            Code::Unknown => status.message().contains("transport error"),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Retries with an exponential backoff when the node couldn't be reached
+pub fn transient_error_strategy() -> RetryStrategy {
+    RetryStrategy::new(
+        |_| false,
+        is_transient_error,
+        BroadcastRetry::Finite(TRANSIENT_ERROR_RETRIES),
+        "a transient gRPC error",
+    )
+    .backoff(Backoff::Exponential {
+        initial: Duration::from_millis(500),
+        factor: 2.0,
+        max: Duration::from_secs(10),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -262,6 +615,85 @@ mod tests {
     fn test_parse_suggested_fee() {
         let log = "insufficient fees; got: 14867ujuno required: 17771ibc/C4CFF46FD6DE35CA4CF4CE031E643C8FDC9BA4B99AE598E9B0ED98FE3A2319F9,444255ujuno: insufficient fee";
         let fee = parse_suggested_fee(log).unwrap();
-        assert_eq!(fee, 444255);
+        assert_eq!(fee, (444255, "ujuno".to_string()));
+    }
+
+    #[test]
+    fn test_parse_suggested_fee_other_denom() {
+        let log = "insufficient fees; got: 1000uatom required: 17771ibc/C4CFF46FD6DE35CA4CF4CE031E643C8FDC9BA4B99AE598E9B0ED98FE3A2319F9,444255.5ujuno: insufficient fee";
+        let fee = parse_suggested_fee(log).unwrap();
+        assert_eq!(
+            fee,
+            (
+                17771,
+                "ibc/C4CFF46FD6DE35CA4CF4CE031E643C8FDC9BA4B99AE598E9B0ED98FE3A2319F9".to_string()
+            )
+        );
+
+        let log = "insufficient fees; got:  required: 444255.5ujuno: insufficient fee";
+        assert_eq!(
+            parse_suggested_fee(log),
+            Some((444256, "ujuno".to_string()))
+        );
+    }
+
+    #[test]
+    fn exponential_backoff() {
+        let backoff = Backoff::Exponential {
+            initial: Duration::from_millis(500),
+            factor: 2.0,
+            max: Duration::from_secs(3),
+        };
+        assert_eq!(backoff.delay(1), Some(Duration::from_millis(500)));
+        assert_eq!(backoff.delay(3), Some(Duration::from_secs(2)));
+        assert_eq!(backoff.delay(4), Some(Duration::from_secs(3)));
+        assert_eq!(backoff.delay(u64::MAX), Some(Duration::from_secs(3)));
+        assert_eq!(Backoff::BlockTime.delay(1), None);
+    }
+
+    #[test]
+    fn strategy_limits() {
+        let mut strategy = account_sequence_strategy();
+        for _ in 0..ACCOUNT_SEQUENCE_RETRIES {
+            assert!(can_retry(&mut strategy));
+        }
+        assert!(!can_retry(&mut strategy));
+    }
+
+    #[test]
+    fn transient_errors() {
+        let unavailable = DaemonError::Status(tonic::Status::unavailable("connection refused"));
+        let not_found = DaemonError::Status(tonic::Status::not_found("account not found"));
+        assert!(is_transient_error(&unavailable));
+        assert!(!is_transient_error(&not_found));
+
+        let strategy = transient_error_strategy();
+        assert!(strategy_condition_met(&strategy, &Err(unavailable)));
+        assert!(!strategy_condition_met(&strategy, &Err(not_found)));
+    }
+
+    #[test]
+    fn out_of_gas_raises_gas_limit() -> anyhow::Result<()> {
+        let strategy = out_of_gas_strategy(1.5);
+        let tx_response = Ok(TxResponse {
+            code: 11,
+            raw_log: "out of gas in location: wasm contract; gasWanted: 200000, gasUsed: 200123: out of gas".to_string(),
+            ..Default::default()
+        });
+        assert!(strategy_condition_met(&strategy, &tx_response));
+
+        let mut tx_builder = TxBuilder::new(TxBuilder::build_body(vec![], None, 0));
+        tx_builder.fee_amount(5000).gas_limit(200_000);
+        (strategy.action.unwrap())(&mut tx_builder, &tx_response)?;
+        assert_eq!(tx_builder.gas_adjustment, 1.5);
+        assert_eq!(tx_builder.fee_amount, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_expected_sequence() {
+        let log = "account sequence mismatch, expected 12, got 11: incorrect account sequence";
+        assert_eq!(parse_expected_sequence(log), Some(12));
+        assert_eq!(parse_expected_sequence("incorrect account sequence"), None);
     }
 }
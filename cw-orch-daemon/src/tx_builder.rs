@@ -1,4 +1,5 @@
 use std::str::FromStr;
+use std::time::SystemTime;
 
 use cosmrs::AccountId;
 use cosmrs::{
@@ -8,8 +9,11 @@ use cosmrs::{
 };
 use cosmwasm_std::Addr;
 use cw_orch_core::log::transaction_target;
+use prost::Message;
 
 use crate::env::DaemonEnvVars;
+use crate::proto::tx::TxBody;
+use crate::queriers::Node;
 use crate::senders::sign::{Signer, SigningAccount};
 
 use super::DaemonError;
@@ -26,8 +30,18 @@ pub struct TxBuilder {
     // # Optional
     pub(crate) fee_amount: Option<u128>,
     pub(crate) gas_limit: Option<u64>,
+    // multiplier applied to the simulated gas
+    pub(crate) gas_adjustment: f64,
+    // denom of the fee amount, if not defined the default denom of the signer is used
+    pub(crate) fee_denom: Option<String>,
     // if defined, use this sequence, else get it from the node
     pub(crate) sequence: Option<SequenceNumber>,
+    // if defined, the tx is unordered and valid until this time
+    pub(crate) unordered_timeout: Option<SystemTime>,
+    // if defined, the tx times out this number of blocks after the latest block when it's built
+    pub(crate) timeout_blocks: Option<u64>,
+    // sequence reserved on the signer for the last built tx, given back if the tx isn't accepted
+    pub(crate) reserved_sequence: Option<u64>,
 }
 
 impl TxBuilder {
@@ -37,7 +51,12 @@ impl TxBuilder {
             body,
             fee_amount: None,
             gas_limit: None,
+            gas_adjustment: 1.0,
+            fee_denom: None,
             sequence: None,
+            unordered_timeout: None,
+            timeout_blocks: None,
+            reserved_sequence: None,
         }
     }
     /// Set a fixed fee amount for the tx
@@ -50,12 +69,42 @@ impl TxBuilder {
         self.gas_limit = Some(gas_limit);
         self
     }
+    /// Set a multiplier applied to the simulated gas, on top of the gas buffer
+    pub fn gas_adjustment(&mut self, gas_adjustment: f64) -> &mut Self {
+        self.gas_adjustment = gas_adjustment;
+        self
+    }
+    /// Multiplies the gas adjustment and drops the fixed fee so the tx is simulated again
+    pub(crate) fn raise_gas_limit(&mut self, multiplier: f64) -> &mut Self {
+        self.gas_adjustment *= multiplier;
+        self.fee_amount = None;
+        self
+    }
+    /// Set the denom of the fixed fee amount
+    pub fn fee_denom(&mut self, fee_denom: impl Into<String>) -> &mut Self {
+        self.fee_denom = Some(fee_denom.into());
+        self
+    }
     /// Set a sequence number for the tx
     pub fn sequence(&mut self, sequence: u64) -> &mut Self {
         self.sequence = Some(sequence);
         self
     }
 
+    /// Make the tx unordered (Cosmos SDK 0.53), valid until `timeout_timestamp`.
+    /// Unordered txs don't depend on the account sequence.
+    pub fn unordered(&mut self, timeout_timestamp: SystemTime) -> &mut Self {
+        self.unordered_timeout = Some(timeout_timestamp);
+        self
+    }
+
+    /// Make the tx time out `blocks` blocks after the latest block.
+    /// The timeout height is computed again every time the tx is built, so retries get a fresh timeout.
+    pub fn timeout_blocks(&mut self, blocks: u64) -> &mut Self {
+        self.timeout_blocks = Some(blocks);
+        self
+    }
+
     /// Builds the body of the tx with a given memo and timeout.
     pub fn build_body(msgs: Vec<Any>, memo: Option<&str>, timeout: u64) -> tx::Body {
         tx::Body::new(
@@ -80,6 +129,7 @@ impl TxBuilder {
     }
 
     /// Simulates the transaction and returns the necessary gas fee returned by the simulation on a node
+    /// The sequence of the signer isn't reserved by the simulation.
     pub async fn simulate(&self, wallet: &impl Signer) -> Result<u64, DaemonError> {
         // get the account number of the wallet
         let SigningAccount {
@@ -97,17 +147,15 @@ impl TxBuilder {
 
     /// Builds the raw tx with a given body and fee and signs it.
     /// Sets the TxBuilder's gas limit to its simulated amount for later use.
+    ///
+    /// The sequence of the signer is only reserved once the tx is simulated, see [`Signer::reserve_signing_account`].
+    /// Unordered txs don't use a sequence and don't reserve one.
     pub async fn build(&mut self, wallet: &impl Signer) -> Result<Raw, DaemonError> {
-        // get the account number of the wallet
-        let SigningAccount {
-            account_number,
-            sequence,
-        } = wallet.signing_account().await?;
-
-        // overwrite sequence if set (can be used for concurrent txs)
-        let sequence = self.sequence.unwrap_or(sequence);
+        if let Some(blocks) = self.timeout_blocks {
+            let latest_height = Node::new_async(wallet.channel())._block_height().await?;
+            self.body.timeout_height = ((latest_height + blocks) as u32).into();
+        }
 
-        //
         let (tx_fee, gas_limit) = if let (Some(fee), Some(gas_limit)) =
             (self.fee_amount, self.gas_limit)
         {
@@ -119,24 +167,56 @@ impl TxBuilder {
             );
             (fee, gas_limit)
         } else {
-            let sim_gas_used = wallet
-                .calculate_gas(&self.body, sequence, account_number)
-                .await?;
+            let sim_gas_used = self.simulate(wallet).await?;
+            let sim_gas_used = (sim_gas_used as f64 * self.gas_adjustment) as u64;
             log::debug!(target: &transaction_target(), "Simulated gas needed {:?}", sim_gas_used);
 
-            let (gas_expected, fee_amount) =
-                TxBuilder::get_fee_from_gas(sim_gas_used, wallet.gas_price()?)?;
+            let (gas_expected, fee_amount, fee_denom) = wallet.fee_from_gas(sim_gas_used).await?;
 
-            log::debug!(target: &transaction_target(), "Calculated fee needed: {:?}", fee_amount);
-            // set the gas limit of self for future txs
+            log::debug!(target: &transaction_target(), "Calculated fee needed: {:?} {:?}", fee_amount, fee_denom);
+            // set the gas limit and fee denom of self for future txs
             // there's no way to change the tx_builder body so simulation gas should remain the same as well
             self.gas_limit = Some(gas_expected);
+            self.fee_denom = fee_denom;
 
             (fee_amount, gas_expected)
         };
 
-        let fee = wallet.build_fee(tx_fee, gas_limit)?;
+        let fee = wallet.build_fee_with_denom(tx_fee, self.fee_denom.as_deref(), gas_limit)?;
 
+        // overwrite sequence if set (can be used for concurrent txs)
+        let SigningAccount {
+            account_number,
+            sequence,
+        } = match self.sequence {
+            Some(sequence) => SigningAccount {
+                sequence,
+                ..wallet.signing_account().await?
+            },
+            None if self.unordered_timeout.is_some() => wallet.signing_account().await?,
+            None => {
+                let account = wallet.reserve_signing_account().await?;
+                self.reserved_sequence = Some(account.sequence);
+                account
+            }
+        };
+
+        let raw = self.sign(wallet, fee, account_number, sequence).await;
+        if raw.is_err() {
+            if let Some(sequence) = self.reserved_sequence.take() {
+                wallet.release_sequence(sequence).await;
+            }
+        }
+        raw
+    }
+
+    async fn sign(
+        &self,
+        wallet: &impl Signer,
+        fee: Fee,
+        account_number: u64,
+        sequence: u64,
+    ) -> Result<Raw, DaemonError> {
         log::debug!(
             target: &transaction_target(),
             "submitting TX: \n fee: {:?}\naccount_nr: {:?}\nsequence: {:?}",
@@ -147,13 +227,18 @@ impl TxBuilder {
 
         let auth_info = wallet.signer_info(sequence).auth_info(fee);
 
-        let sign_doc = SignDoc::new(
+        let mut sign_doc = SignDoc::new(
             &self.body,
             &auth_info,
             &Id::try_from(wallet.chain_id())?,
             account_number,
         )?;
-        wallet.sign(sign_doc).map_err(Into::into)
+        // The unordered fields are not part of the body of cosmrs, the signed bytes are replaced instead
+        // Simulation uses the ordered body, the gas difference is absorbed by the gas buffer
+        if let Some(timeout_timestamp) = self.unordered_timeout {
+            sign_doc.body_bytes = TxBody::unordered(&self.body, timeout_timestamp).encode_to_vec();
+        }
+        wallet.sign(sign_doc).await.map_err(Into::into)
     }
 
     /// Compute the gas fee from the expected gas in the transaction
@@ -175,3 +260,281 @@ impl TxBuilder {
         Ok((gas_expected as u64, fee_amount as u128))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cosmos_modules::tendermint::{GetLatestBlockRequest, GetLatestBlockResponse},
+        senders::{builder::SenderBuilder, query::QuerySender, SequenceManager},
+    };
+    use cosmrs::{
+        proto::{
+            cosmos::tx::v1beta1::TxRaw,
+            tendermint::{
+                google::protobuf::Timestamp,
+                v0_34::{
+                    types::{Data, Header},
+                    version::Consensus,
+                },
+            },
+        },
+        tx::SignerInfo,
+    };
+    use cw_orch_core::environment::ChainInfoOwned;
+    use hyper::body::Incoming;
+    use hyper_util::rt::{TokioExecutor, TokioIo};
+    use std::{
+        convert::Infallible,
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Arc,
+        },
+        time::{Duration, UNIX_EPOCH},
+    };
+    use tokio::net::TcpListener;
+    use tonic::{
+        body::BoxBody,
+        codec::ProstCodec,
+        server::Grpc,
+        transport::{Channel, Endpoint},
+        Status,
+    };
+
+    const ACCOUNT_SEQUENCE: u64 = 10;
+
+    /// Signer tracking its sequence locally, signing with an empty signature
+    #[derive(Clone)]
+    struct TestSigner {
+        channel: Channel,
+        sequence_manager: SequenceManager,
+    }
+
+    impl TestSigner {
+        fn new(channel: Channel) -> Self {
+            Self {
+                channel,
+                sequence_manager: SequenceManager::default(),
+            }
+        }
+
+        /// Signer without a node, the txs must not query it
+        fn offline() -> Self {
+            Self::new(Endpoint::from_static("http://127.0.0.1:1").connect_lazy())
+        }
+
+        async fn fetch_signing_account() -> Result<SigningAccount, DaemonError> {
+            Ok(SigningAccount {
+                account_number: 1,
+                sequence: ACCOUNT_SEQUENCE,
+            })
+        }
+    }
+
+    struct TestSignerOptions;
+
+    impl SenderBuilder for TestSignerOptions {
+        type Error = DaemonError;
+        type Sender = TestSigner;
+
+        async fn build(
+            &self,
+            _chain_info: &Arc<ChainInfoOwned>,
+        ) -> Result<TestSigner, DaemonError> {
+            Ok(TestSigner::offline())
+        }
+    }
+
+    impl QuerySender for TestSigner {
+        type Error = DaemonError;
+        type Options = TestSignerOptions;
+
+        fn channel(&self) -> Channel {
+            self.channel.clone()
+        }
+    }
+
+    impl Signer for TestSigner {
+        fn chain_id(&self) -> String {
+            "testing".to_string()
+        }
+
+        fn account_id(&self) -> AccountId {
+            AccountId::new("juno", &[1; 20]).unwrap()
+        }
+
+        async fn signing_account(&self) -> Result<SigningAccount, DaemonError> {
+            self.sequence_manager
+                .current_account(Self::fetch_signing_account())
+                .await
+        }
+
+        async fn reserve_signing_account(&self) -> Result<SigningAccount, DaemonError> {
+            self.sequence_manager
+                .next_account(Self::fetch_signing_account())
+                .await
+        }
+
+        async fn sign(&self, sign_doc: SignDoc) -> Result<Raw, DaemonError> {
+            Ok(TxRaw {
+                body_bytes: sign_doc.body_bytes,
+                auth_info_bytes: sign_doc.auth_info_bytes,
+                signatures: vec![vec![]],
+            }
+            .into())
+        }
+
+        fn signer_info(&self, sequence: u64) -> SignerInfo {
+            SignerInfo::single_direct(None, sequence)
+        }
+
+        fn build_fee(&self, amount: impl Into<u128>, gas_limit: u64) -> Result<Fee, DaemonError> {
+            TxBuilder::build_fee(amount, "ujuno", gas_limit, None)
+        }
+
+        fn gas_price(&self) -> Result<f64, DaemonError> {
+            Ok(0.025)
+        }
+    }
+
+    /// Node serving its latest block, at `height`
+    #[derive(Clone, Default)]
+    struct MockNode {
+        height: Arc<AtomicU64>,
+    }
+
+    impl MockNode {
+        /// Serves the node and returns a channel connected to it
+        async fn spawn(self) -> anyhow::Result<Channel> {
+            let listener = TcpListener::bind("127.0.0.1:0").await?;
+            let url = format!("http://{}", listener.local_addr()?);
+            tokio::spawn(async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    let node = self.clone();
+                    let service = hyper::service::service_fn(move |request| {
+                        let node = node.clone();
+                        async move { Ok::<_, Infallible>(node.handle(request).await) }
+                    });
+                    tokio::spawn(
+                        hyper::server::conn::http2::Builder::new(TokioExecutor::new())
+                            .serve_connection(TokioIo::new(stream), service),
+                    );
+                }
+            });
+            Ok(Endpoint::from_shared(url)?.connect().await?)
+        }
+
+        async fn handle(self, request: http::Request<Incoming>) -> http::Response<BoxBody> {
+            let path = request.uri().path().to_string();
+            if path != "/cosmos.base.tendermint.v1beta1.Service/GetLatestBlock" {
+                return Status::unimplemented(path).into_http();
+            }
+            let service = tower::service_fn(move |_: tonic::Request<GetLatestBlockRequest>| {
+                let response = self.latest_block();
+                async move { Ok::<_, Status>(tonic::Response::new(response)) }
+            });
+            Grpc::new(ProstCodec::<GetLatestBlockResponse, GetLatestBlockRequest>::default())
+                .unary(service, request)
+                .await
+        }
+
+        fn latest_block(&self) -> GetLatestBlockResponse {
+            let header = Header {
+                version: Some(Consensus::default()),
+                chain_id: "testing".to_string(),
+                height: self.height.load(Ordering::SeqCst) as i64,
+                time: Some(Timestamp::default()),
+                proposer_address: vec![0; 20],
+                ..Default::default()
+            };
+            GetLatestBlockResponse {
+                block: Some(cosmrs::proto::tendermint::v0_34::types::Block {
+                    header: Some(header),
+                    data: Some(Data::default()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }
+        }
+    }
+
+    /// Tx builder with a fixed fee, which doesn't need a simulation
+    fn tx_builder(timeout_height: u64) -> TxBuilder {
+        let mut tx_builder = TxBuilder::new(TxBuilder::build_body(vec![], None, timeout_height));
+        tx_builder.fee_amount(5000).gas_limit(200_000);
+        tx_builder
+    }
+
+    fn signed_body(raw: Raw) -> anyhow::Result<TxBody> {
+        let raw: TxRaw = raw.into();
+        Ok(TxBody::decode(raw.body_bytes.as_slice())?)
+    }
+
+    #[tokio::test]
+    async fn height_timeout() -> anyhow::Result<()> {
+        let signer = TestSigner::offline();
+
+        let body = signed_body(tx_builder(1234).build(&signer).await?)?;
+        assert_eq!(body.timeout_height, 1234);
+        assert!(!body.unordered);
+        assert_eq!(body.timeout_timestamp, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn blocks_timeout() -> anyhow::Result<()> {
+        let node = MockNode::default();
+        node.height.store(100, Ordering::SeqCst);
+        let signer = TestSigner::new(node.clone().spawn().await?);
+
+        let mut tx_builder = tx_builder(0);
+        tx_builder.timeout_blocks(10);
+        let body = signed_body(tx_builder.build(&signer).await?)?;
+        assert_eq!(body.timeout_height, 110);
+
+        // The timeout is computed again from the latest block when the tx is built again
+        node.height.store(105, Ordering::SeqCst);
+        let body = signed_body(tx_builder.build(&signer).await?)?;
+        assert_eq!(body.timeout_height, 115);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unordered_sets_timeout_timestamp() -> anyhow::Result<()> {
+        let signer = TestSigner::offline();
+        let timeout = UNIX_EPOCH + Duration::new(1_700_000_000, 42);
+
+        let mut tx_builder = tx_builder(0);
+        tx_builder.unordered(timeout);
+        let body = signed_body(tx_builder.build(&signer).await?)?;
+        assert!(body.unordered);
+        assert_eq!(
+            body.timeout_timestamp,
+            Some(Timestamp {
+                seconds: 1_700_000_000,
+                nanos: 42,
+            })
+        );
+        assert_eq!(body.timeout_height, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unordered_skips_sequence_manager() -> anyhow::Result<()> {
+        let signer = TestSigner::offline();
+
+        let mut unordered = tx_builder(0);
+        unordered.unordered(SystemTime::now() + Duration::from_secs(60));
+        unordered.build(&signer).await?;
+        unordered.build(&signer).await?;
+        assert_eq!(unordered.reserved_sequence, None);
+
+        // No sequence was reserved by the unordered txs
+        let mut ordered = tx_builder(0);
+        ordered.build(&signer).await?;
+        assert_eq!(ordered.reserved_sequence, Some(ACCOUNT_SEQUENCE));
+        ordered.build(&signer).await?;
+        assert_eq!(ordered.reserved_sequence, Some(ACCOUNT_SEQUENCE + 1));
+        Ok(())
+    }
+}
@@ -6,10 +6,11 @@ use super::{
         tendermint_abci::Event,
     },
     error::DaemonError,
+    tx_broadcaster::parse_coin_str,
 };
 use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
 
-use cosmwasm_std::{to_json_binary, Binary, StdError, StdResult};
+use cosmwasm_std::{coin, to_json_binary, Binary, Coin, StdError, StdResult};
 use cw_orch_core::environment::IndexResponse;
 use serde::{Deserialize, Serialize};
 
@@ -88,6 +89,18 @@ impl CosmTxResponse {
         response
     }
 
+    /// Fee paid by the transaction, read from its `tx` event
+    pub fn fee(&self) -> Vec<Coin> {
+        self.event_attr_value("tx", "fee")
+            .map(|fee| {
+                fee.split(',')
+                    .filter_map(parse_coin_str)
+                    .map(|(amount, denom)| coin(amount, denom))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// get the list of event types from a TX record
     pub fn get_events(&self, event_type: &str) -> Vec<TxResultBlockEvent> {
         let log_events = self.get_events_from_logs(event_type);
@@ -0,0 +1,226 @@
+//! Event subscriptions over the CometBFT RPC websocket.
+//!
+//! ## Usage
+//!
+//! ```no_run
+//! use cw_orch_daemon::websocket::EventSubscription;
+//! # tokio_test::block_on(async {
+//! let mut subscription = EventSubscription::connect(
+//!     "ws://localhost:26657/websocket",
+//!     "tm.event='Tx' AND wasm._contract_address='juno1...'",
+//! )
+//! .await
+//! .unwrap();
+//!
+//! while let Some(event) = subscription.next().await {
+//!     println!("{:?}", event.unwrap().events);
+//! }
+//! # })
+//! ```
+
+use std::collections::HashMap;
+
+use cw_orch_core::log::connectivity_target;
+use futures_util::{SinkExt, StreamExt};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::net::TcpStream;
+use tokio_tungstenite::{tungstenite::Message, MaybeTlsStream, WebSocketStream};
+
+use crate::DaemonError;
+
+/// Event received on a CometBFT subscription
+#[derive(Debug, Clone, Deserialize)]
+pub struct SubscriptionEvent {
+    /// Query the event matched
+    pub query: String,
+    /// Raw event data, for tx events this contains the `TxResult`
+    pub data: Value,
+    /// Flattened events, indexed by `{event_type}.{attribute_key}`
+    #[serde(default)]
+    pub events: HashMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcResponse {
+    result: Option<Value>,
+    error: Option<Value>,
+}
+
+/// Subscription to CometBFT events matching a query.
+pub struct EventSubscription {
+    query: String,
+    socket: WebSocketStream<MaybeTlsStream<TcpStream>>,
+}
+
+impl EventSubscription {
+    /// Connects to the CometBFT RPC websocket at `url` and subscribes to the events matching `query`.
+    /// `url` can either be the websocket endpoint (`ws://localhost:26657/websocket`) or the RPC address (`http://localhost:26657`).
+    pub async fn connect(url: &str, query: impl Into<String>) -> Result<Self, DaemonError> {
+        let query = query.into();
+        let url = websocket_endpoint(url);
+        log::debug!(target: &connectivity_target(), "Subscribing to `{}` on {}", query, url);
+
+        let (mut socket, _) = tokio_tungstenite::connect_async(url.as_str()).await?;
+
+        let request = json!({
+            "jsonrpc": "2.0",
+            "method": "subscribe",
+            "id": 0,
+            "params": { "query": query },
+        });
+        socket
+            .send(Message::Text(request.to_string().into()))
+            .await?;
+
+        Ok(Self { query, socket })
+    }
+
+    /// Subscribes to the inclusion of the transaction with the given hash
+    pub async fn tx(url: &str, hash: &str) -> Result<Self, DaemonError> {
+        Self::connect(url, format!("tm.event='Tx' AND tx.hash='{hash}'")).await
+    }
+
+    /// The query this subscription is listening to
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Waits for the next event.
+    /// Returns `None` when the connection was closed by the node.
+    pub async fn next(&mut self) -> Option<Result<SubscriptionEvent, DaemonError>> {
+        while let Some(message) = self.socket.next().await {
+            let text = match message {
+                Ok(Message::Text(text)) => text,
+                Ok(Message::Close(_)) => return None,
+                // Pings are answered by the websocket stream itself
+                Ok(_) => continue,
+                Err(e) => return Some(Err(e.into())),
+            };
+
+            match parse_event(&text) {
+                Ok(Some(event)) => return Some(Ok(event)),
+                // Subscription acknowledgement
+                Ok(None) => continue,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        None
+    }
+
+    /// Converts the subscription into a [`futures_util::Stream`] of events
+    pub fn into_stream(
+        self,
+    ) -> impl futures_util::Stream<Item = Result<SubscriptionEvent, DaemonError>> {
+        futures_util::stream::unfold(self, |mut subscription| async move {
+            subscription.next().await.map(|event| (event, subscription))
+        })
+    }
+
+    /// Closes the websocket connection
+    pub async fn close(mut self) -> Result<(), DaemonError> {
+        self.socket.close(None).await?;
+        Ok(())
+    }
+}
+
+fn parse_event(text: &str) -> Result<Option<SubscriptionEvent>, DaemonError> {
+    let response: RpcResponse = serde_json::from_str(text)?;
+    if let Some(error) = response.error {
+        return Err(DaemonError::StdErr(format!(
+            "websocket subscription error: {error}"
+        )));
+    }
+
+    match response.result {
+        Some(result) if result.get("query").is_some() => Ok(Some(serde_json::from_value(result)?)),
+        _ => Ok(None),
+    }
+}
+
+/// Turns an RPC address into its websocket endpoint
+fn websocket_endpoint(url: &str) -> String {
+    let url = url.trim_end_matches('/');
+    let url = if let Some(address) = url.strip_prefix("http://") {
+        format!("ws://{address}")
+    } else if let Some(address) = url.strip_prefix("https://") {
+        format!("wss://{address}")
+    } else {
+        url.to_string()
+    };
+
+    if url.ends_with("/websocket") {
+        url
+    } else {
+        format!("{url}/websocket")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    #[test]
+    fn websocket_endpoints() {
+        assert_eq!(
+            websocket_endpoint("http://localhost:26657"),
+            "ws://localhost:26657/websocket"
+        );
+        assert_eq!(
+            websocket_endpoint("https://rpc.juno.com/"),
+            "wss://rpc.juno.com/websocket"
+        );
+        assert_eq!(
+            websocket_endpoint("ws://localhost:26657/websocket"),
+            "ws://localhost:26657/websocket"
+        );
+    }
+
+    #[tokio::test]
+    async fn receives_events_from_mock_server() -> anyhow::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+            let request = socket.next().await.unwrap().unwrap();
+            let request: Value = serde_json::from_str(request.to_text().unwrap()).unwrap();
+            let query = request["params"]["query"].clone();
+
+            let ack = json!({ "jsonrpc": "2.0", "id": 0, "result": {} });
+            socket
+                .send(Message::Text(ack.to_string().into()))
+                .await
+                .unwrap();
+
+            let event = json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "result": {
+                    "query": query,
+                    "data": { "type": "tendermint/event/Tx", "value": { "TxResult": { "height": "12" } } },
+                    "events": { "tx.hash": ["ABCD"], "tx.height": ["12"] },
+                },
+            });
+            socket
+                .send(Message::Text(event.to_string().into()))
+                .await
+                .unwrap();
+            socket.close(None).await.unwrap();
+        });
+
+        let mut subscription = EventSubscription::tx(&format!("http://{address}"), "ABCD").await?;
+        assert_eq!(subscription.query(), "tm.event='Tx' AND tx.hash='ABCD'");
+
+        let event = subscription.next().await.unwrap()?;
+        assert_eq!(event.query, "tm.event='Tx' AND tx.hash='ABCD'");
+        assert_eq!(event.events["tx.height"], vec!["12".to_string()]);
+
+        assert!(subscription.next().await.is_none());
+        server.await?;
+        Ok(())
+    }
+}
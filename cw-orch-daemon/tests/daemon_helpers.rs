@@ -135,4 +135,48 @@ mod tests {
             .that(&contract.upload_if_needed().unwrap())
             .is_none();
     }
+
+    #[test]
+    #[serial_test::serial]
+    fn delegator() {
+        use cosmwasm_std::coin;
+        use cw_orch_daemon::queriers::{Distribution, StakingBondStatus};
+        use cw_orch_networks::networks;
+        use cw_orch_traits::Delegator;
+
+        let daemon = Daemon::builder(networks::LOCAL_JUNO)
+            .is_test(true)
+            .build()
+            .unwrap();
+        let sender = daemon.sender_addr();
+
+        let staking = cw_orch_daemon::queriers::Staking::new(&daemon);
+        let validator = daemon
+            .rt_handle
+            .block_on(staking._validators(StakingBondStatus::Bonded))
+            .unwrap()
+            .remove(0)
+            .address;
+
+        asserting!("delegate is ok")
+            .that(&daemon.delegate(&validator, &coin(1_000, networks::LOCAL_JUNO.gas_denom)))
+            .is_ok();
+
+        let distribution = Distribution::new(&daemon);
+        let validators = daemon
+            .rt_handle
+            .block_on(distribution._delegator_validators(&sender))
+            .unwrap();
+        asserting!("delegation is registered")
+            .that(&validators)
+            .contains(&validator);
+
+        asserting!("withdraw_all_rewards is ok")
+            .that(&daemon.withdraw_all_rewards())
+            .is_ok();
+
+        asserting!("undelegate is ok")
+            .that(&daemon.undelegate(&validator, &coin(1_000, networks::LOCAL_JUNO.gas_denom)))
+            .is_ok();
+    }
 }
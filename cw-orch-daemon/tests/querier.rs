@@ -8,12 +8,12 @@ mod queriers {
     use cw_orch_daemon::{parse_cw_coins, queriers::Bank, GrpcChannel};
     use cw_orch_networks::networks;
     use mock_contract::InstantiateMsg;
-    use speculoos::{asserting, result::ResultAssertions};
+    use speculoos::{asserting, option::OptionAssertions, result::ResultAssertions};
     use std::str::FromStr;
 
     use cw_orch_daemon::{
         queriers::StakingBondStatus,
-        queriers::{CosmWasm, Gov, Ibc, Node, Staking},
+        queriers::{CosmWasm, Distribution, Gov, Ibc, Mint, Node, Slashing, Staking, Upgrade},
         Daemon,
     };
     use tokio::runtime::Runtime;
@@ -72,6 +72,97 @@ mod queriers {
             .is_equal_to(1);
     }
 
+    /*
+        Querier - Distribution
+    */
+    #[test]
+    fn distribution() {
+        let rt = Runtime::new().unwrap();
+        let channel = rt.block_on(build_channel());
+
+        let distribution = Distribution::new_async(channel);
+
+        let params = rt.block_on(distribution._params());
+        asserting!("params is ok").that(&params).is_ok();
+
+        let community_pool = rt.block_on(distribution._community_pool());
+        asserting!("community_pool is ok")
+            .that(&community_pool)
+            .is_ok();
+
+        let withdraw_address = rt.block_on(distribution._delegator_withdraw_address(
+            &Addr::unchecked("juno16g2rahf5846rxzp3fwlswy08fz8ccuwk03k57y"),
+        ));
+        asserting!("withdraw_address is ok")
+            .that(&withdraw_address)
+            .is_ok();
+    }
+
+    /*
+        Querier - Slashing
+    */
+    #[test]
+    fn slashing() {
+        let rt = Runtime::new().unwrap();
+        let channel = rt.block_on(build_channel());
+
+        let slashing = Slashing::new_async(channel);
+
+        let params = rt.block_on(slashing._params());
+        asserting!("params is ok").that(&params).is_ok();
+
+        let signing_infos = rt.block_on(slashing._signing_infos(None));
+        asserting!("signing_infos is ok")
+            .that(&signing_infos)
+            .is_ok();
+    }
+
+    /*
+        Querier - Mint
+    */
+    #[test]
+    fn mint() {
+        let rt = Runtime::new().unwrap();
+        let channel = rt.block_on(build_channel());
+
+        let mint = Mint::new_async(channel);
+
+        let params = rt.block_on(mint._params());
+        asserting!("params is ok").that(&params).is_ok();
+
+        let inflation = rt.block_on(mint._inflation());
+        asserting!("inflation is ok").that(&inflation).is_ok();
+
+        let annual_provisions = rt.block_on(mint._annual_provisions());
+        asserting!("annual_provisions is ok")
+            .that(&annual_provisions)
+            .is_ok();
+    }
+
+    /*
+        Querier - Upgrade
+    */
+    #[test]
+    fn upgrade() {
+        let rt = Runtime::new().unwrap();
+        let channel = rt.block_on(build_channel());
+
+        let upgrade = Upgrade::new_async(channel);
+
+        let current_plan = rt.block_on(upgrade._current_plan());
+        asserting!("current_plan is ok").that(&current_plan).is_ok();
+
+        let applied_plan = rt.block_on(upgrade._applied_plan("not-an-upgrade"));
+        asserting!("applied_plan is ok, but not applied")
+            .that(&applied_plan.unwrap())
+            .is_none();
+
+        let module_versions = rt.block_on(upgrade._module_versions(Some("bank".to_string())));
+        asserting!("module_versions is ok")
+            .that(&module_versions.unwrap().len())
+            .is_equal_to(1);
+    }
+
     /*
         Querier - Gov
     */
@@ -239,4 +330,41 @@ mod queriers {
             .that(&contract_info)
             .is_ok();
     }
+
+    #[test]
+    #[serial_test::serial]
+    fn balance_at_height() {
+        use cw_orch_core::environment::{QueryHandler, TxHandler};
+        use cw_orch_networks::networks;
+
+        let daemon = Daemon::builder(networks::LOCAL_JUNO)
+            .is_test(true)
+            .build()
+            .unwrap();
+        let recipient = Addr::unchecked("juno16g2rahf5846rxzp3fwlswy08fz8ccuwk03k57y");
+        let denom = Some(networks::LOCAL_JUNO.gas_denom.to_string());
+
+        let balance_before = daemon.balance(&recipient, denom.clone()).unwrap();
+        let height = daemon.block_info().unwrap().height;
+        daemon.next_block().unwrap();
+
+        daemon
+            .bank_send(
+                &recipient,
+                &cosmwasm_std::coins(100, networks::LOCAL_JUNO.gas_denom),
+            )
+            .unwrap();
+        assert_ne!(
+            daemon.balance(&recipient, denom.clone()).unwrap(),
+            balance_before
+        );
+
+        let historical = daemon.at_height(height);
+        assert_eq!(historical.query_height(), Some(height));
+        assert_eq!(historical.block_info().unwrap().height, height);
+        assert_eq!(
+            historical.balance(&recipient, denom).unwrap(),
+            balance_before
+        );
+    }
 }
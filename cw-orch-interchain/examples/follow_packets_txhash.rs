@@ -1,6 +1,6 @@
 use cw_orch::{
     daemon::networks::ARCHWAY_1,
-    environment::{ChainInfo, NetworkInfo},
+    environment::{ChainInfo, KeyAlgorithm, NetworkInfo},
     prelude::networks::osmosis::OSMOSIS_1,
 };
 use cw_orch_interchain::prelude::*;
@@ -9,6 +9,7 @@ pub const NOBLE: NetworkInfo = NetworkInfo {
     chain_name: "noble",
     pub_address_prefix: "noble",
     coin_type: 118,
+    key_algorithm: KeyAlgorithm::Secp256k1,
 };
 pub const NOBLE_1: ChainInfo = ChainInfo {
     chain_id: "noble-1",
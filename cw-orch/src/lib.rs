@@ -4,7 +4,7 @@
 
 // macros
 pub use cw_orch_contract_derive::interface;
-pub use cw_orch_fns_derive::{ExecuteFns, QueryFns};
+pub use cw_orch_fns_derive::{ExecuteFns, QueryFns, SudoFns};
 
 // prelude
 #[cfg(not(target_arch = "wasm32"))]
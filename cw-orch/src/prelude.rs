@@ -12,9 +12,10 @@
 
 // Contract traits
 pub use crate::contract::interface_traits::{
-    CallAs, ConditionalMigrate, ConditionalUpload, ContractInstance, CwOrchExecute,
-    CwOrchInstantiate, CwOrchMigrate, CwOrchQuery, CwOrchUpload, ExecutableContract,
-    InstantiableContract, MigratableContract, QueryableContract, Uploadable,
+    CallAs, ConditionalMigrate, ConditionalUpload, ContractInstance, CustomUploadable,
+    CwOrchExecute, CwOrchInstantiate, CwOrchMigrate, CwOrchQuery, CwOrchSudo, CwOrchUpload,
+    ExecutableContract, InstantiableContract, MigratableContract, QueryableContract,
+    SudoableContract, Uploadable,
 };
 
 pub use cw_orch_core::contract::Deploy;
@@ -28,7 +29,7 @@ pub use crate::environment::IndexResponse;
 // Environment
 pub use crate::environment::{
     BankQuerier, BankSetter, CwEnv, DefaultQueriers, EnvironmentInfo, EnvironmentQuerier,
-    NodeQuerier, QuerierGetter, QueryHandler, TxHandler, TxResponse, WasmQuerier,
+    NodeQuerier, QuerierGetter, QueryHandler, SudoHandler, TxHandler, TxResponse, WasmQuerier,
 };
 
 pub use cw_orch_core::environment::Environment;
@@ -0,0 +1,98 @@
+use cosmwasm_std::coins;
+use counter_contract::{
+    msg::{ExecuteMsgFns, InstantiateMsg, QueryMsgFns},
+    CounterContract,
+};
+use cw_orch::prelude::*;
+
+fn setup() -> cw_orch::anyhow::Result<(Mock, CounterContract<Mock>)> {
+    let chain = Mock::new("sender");
+    chain.set_balance(&chain.sender_addr(), coins(100, "ujuno"))?;
+    let counter = CounterContract::new(chain.clone());
+    counter.upload()?;
+    counter.instantiate(&InstantiateMsg { count: 0 }, None, &[])?;
+    counter.increment()?;
+    chain.wait_blocks(10)?;
+    Ok((chain, counter))
+}
+
+#[test]
+fn restore_snapshot() -> cw_orch::anyhow::Result<()> {
+    let (chain, counter) = setup()?;
+    let snapshot = chain.snapshot();
+
+    counter.increment()?;
+    chain.wait_blocks(1)?;
+    chain.bank_send(&chain.addr_make("receiver"), &coins(10, "ujuno"))?;
+
+    chain.restore(&snapshot);
+    assert_eq!(counter.get_count()?.count, 1);
+    assert_eq!(
+        chain.query_balance(&chain.sender_addr(), "ujuno")?.u128(),
+        100
+    );
+    assert_eq!(chain.block_info()?, snapshot.block);
+    Ok(())
+}
+
+#[test]
+fn save_and_load_state() -> cw_orch::anyhow::Result<()> {
+    let (chain, counter) = setup()?;
+    let path = std::env::temp_dir().join(format!("cw-orch-mock-state-{}.json", std::process::id()));
+    chain.save_state(&path)?;
+
+    let loaded = Mock::new("sender");
+    loaded.load_state(&path)?;
+    std::fs::remove_file(&path)?;
+    let loaded_counter = CounterContract::new(loaded.clone());
+    assert_eq!(loaded.restore_code(&loaded_counter)?, counter.code_id()?);
+
+    assert_eq!(loaded_counter.address()?, counter.address()?);
+    assert_eq!(loaded_counter.get_count()?.count, 1);
+    assert_eq!(
+        loaded.query_balance(&loaded.sender_addr(), "ujuno")?.u128(),
+        100
+    );
+    assert_eq!(loaded.block_info()?, chain.block_info()?);
+
+    // The restored code can be used
+    loaded_counter.increment()?;
+    assert_eq!(loaded_counter.get_count()?.count, 2);
+    loaded_counter.instantiate(&InstantiateMsg { count: 5 }, None, &[])?;
+    assert_ne!(loaded_counter.address()?, counter.address()?);
+    Ok(())
+}
+
+#[test]
+fn fork() -> cw_orch::anyhow::Result<()> {
+    let (chain, counter) = setup()?;
+    let fork = chain.fork()?;
+    let forked_counter = CounterContract::new(fork.clone());
+
+    forked_counter.increment()?;
+    fork.bank_send(&fork.addr_make("receiver"), &coins(10, "ujuno"))?;
+    assert_eq!(forked_counter.get_count()?.count, 2);
+    assert_eq!(fork.query_balance(&fork.sender_addr(), "ujuno")?.u128(), 90);
+
+    // The codes keep their creator and checksum
+    let code_id = counter.code_id()?;
+    assert_eq!(
+        fork.wasm_querier().code(code_id)?,
+        chain.wasm_querier().code(code_id)?
+    );
+
+    // The original environment is untouched
+    assert_eq!(counter.get_count()?.count, 1);
+    assert_eq!(
+        chain.query_balance(&chain.sender_addr(), "ujuno")?.u128(),
+        100
+    );
+
+    // Codes uploaded with `upload_custom` can't be forked
+    let code_id = chain
+        .upload_custom("custom", CounterContract::<Mock>::wrapper())?
+        .uploaded_code_id()?;
+    let err = chain.fork().err().unwrap();
+    assert!(err.to_string().contains(&format!("Code {code_id}")));
+    Ok(())
+}
@@ -1,11 +1,12 @@
 // ANCHOR: NEW_NETWORK_INFO
 
-use cw_orch::environment::{ChainInfo, ChainKind, NetworkInfo};
+use cw_orch::environment::{ChainInfo, ChainKind, KeyAlgorithm, NetworkInfo};
 
 pub const NEW_NETWORK_INFO: NetworkInfo = NetworkInfo {
     chain_name: "osmosis",
     pub_address_prefix: "osmo",
     coin_type: 118,
+    key_algorithm: KeyAlgorithm::Secp256k1,
 };
 
 pub const NEW_CHAIN_INFO: ChainInfo = ChainInfo {
@@ -0,0 +1,70 @@
+use cosmwasm_std::{DepsMut, Env, Response, StdError, StdResult};
+use cw_orch::{interface, prelude::*};
+use mock_contract::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+
+#[cosmwasm_schema::cw_serde]
+#[derive(cw_orch::SudoFns)]
+pub enum SudoMsg {
+    BeginBlock {},
+    SetParam { key: String, value: u64 },
+}
+
+pub fn sudo(_deps: DepsMut, _env: Env, msg: SudoMsg) -> StdResult<Response> {
+    match msg {
+        SudoMsg::BeginBlock {} => Ok(Response::new().add_attribute("action", "begin block")),
+        SudoMsg::SetParam { key, value } => {
+            if value == 0 {
+                return Err(StdError::generic_err("zero param"));
+            }
+            Ok(Response::new()
+                .add_attribute("action", "set param")
+                .add_attribute(key, value.to_string()))
+        }
+    }
+}
+
+#[interface(InstantiateMsg, ExecuteMsg, QueryMsg, MigrateMsg, SudoMsg)]
+pub struct SudoContract;
+
+impl<Chain> Uploadable for SudoContract<Chain> {
+    fn wrapper() -> <Mock as TxHandler>::ContractSource {
+        Box::new(
+            ContractWrapper::new_with_empty(
+                mock_contract::execute,
+                mock_contract::instantiate,
+                mock_contract::query,
+            )
+            .with_sudo(sudo),
+        )
+    }
+}
+
+#[test]
+fn test_sudo() -> cw_orch::anyhow::Result<()> {
+    let chain = Mock::new("sender");
+    let contract = SudoContract::new("test:sudo_contract", chain.clone());
+    contract.upload()?;
+    contract.instantiate(&InstantiateMsg {}, None, &[])?;
+
+    let response = contract.sudo(&SudoMsg::BeginBlock {})?;
+    assert_eq!(response.event_attr_value("wasm", "action")?, "begin block");
+
+    let response = contract.set_param("fee".to_string(), 10)?;
+    assert_eq!(response.event_attr_value("wasm", "fee")?, "10");
+    contract.set_param("fee".to_string(), 0).unwrap_err();
+    Ok(())
+}
+
+#[test]
+fn test_pin_code() -> cw_orch::anyhow::Result<()> {
+    let chain = Mock::new("sender");
+    let contract = SudoContract::new("test:sudo_contract", chain.clone());
+    contract.upload()?;
+
+    let code_id = contract.code_id()?;
+    chain.pin_code(code_id)?;
+    assert!(chain.is_pinned(code_id)?);
+    chain.unpin_code(code_id)?;
+    assert!(!chain.is_pinned(code_id)?);
+    Ok(())
+}
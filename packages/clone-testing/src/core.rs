@@ -13,11 +13,12 @@ use cw_orch_core::{
     contract::interface_traits::{ContractInstance, Uploadable},
     environment::{
         AccessConfig, BankQuerier, BankSetter, ChainInfoOwned, ChainState, DefaultQueriers,
-        IndexResponse, StateInterface, TxHandler,
+        IndexResponse, StateInterface, SudoHandler, TxHandler,
     },
     CwEnvError,
 };
 use cw_orch_daemon::{queriers::Node, read_network_config, DEFAULT_DEPLOYMENT, RUNTIME};
+use cw_orch_mock::pinned_codes;
 use cw_utils::NativeBalance;
 use serde::Serialize;
 use tokio::runtime::Runtime;
@@ -209,7 +210,7 @@ impl<S: StateInterface> CloneTesting<S> {
         custom_state: S,
     ) -> Result<Self, CwEnvError> {
         let chain: ChainInfoOwned = chain.into();
-        let chain = if let Some(chain_info) = read_network_config(&chain.chain_id) {
+        let chain = if let Some(chain_info) = read_network_config(&chain.chain_id)? {
             chain.overwrite_with(chain_info)
         } else {
             chain
@@ -509,6 +510,43 @@ impl BankSetter for CloneTesting {
     }
 }
 
+/// Pinning has no effect on the cloned chain, the pinned codes are only recorded locally.
+impl<S: StateInterface> SudoHandler for CloneTesting<S> {
+    fn sudo<M: Serialize + Debug>(
+        &self,
+        sudo_msg: &M,
+        contract_address: &Addr,
+    ) -> Result<Self::Response, Self::Error> {
+        self.app
+            .borrow_mut()
+            .wasm_sudo(contract_address.clone(), sudo_msg)
+            .map_err(From::from)
+            .map(Into::into)
+    }
+
+    fn pin_code(&self, code_id: u64) -> Result<(), Self::Error> {
+        let mut app = self.app.borrow_mut();
+        // Fails if the code doesn't exist locally or on the cloned chain
+        app.wrap().query_wasm_code_info(code_id)?;
+        pinned_codes::pin(app.storage_mut(), code_id);
+        Ok(())
+    }
+
+    fn unpin_code(&self, code_id: u64) -> Result<(), Self::Error> {
+        let mut app = self.app.borrow_mut();
+        app.wrap().query_wasm_code_info(code_id)?;
+        pinned_codes::unpin(app.storage_mut(), code_id);
+        Ok(())
+    }
+
+    fn is_pinned(&self, code_id: u64) -> Result<bool, Self::Error> {
+        Ok(pinned_codes::is_pinned(
+            self.app.borrow().storage(),
+            code_id,
+        ))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::core::*;
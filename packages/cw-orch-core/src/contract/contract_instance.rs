@@ -3,7 +3,8 @@ use super::interface_traits::Uploadable;
 use crate::{
     env::CoreEnvVars,
     environment::{
-        AsyncWasmQuerier, ChainState, IndexResponse, StateInterface, TxHandler, TxResponse,
+        AsyncWasmQuerier, ChainState, IndexResponse, StateInterface, SudoHandler, TxHandler,
+        TxResponse,
     },
     error::CwEnvError,
     log::{contract_target, transaction_target},
@@ -57,6 +58,24 @@ impl<Chain> Contract<Chain> {
     pub fn set_default_code_id(&mut self, code_id: u64) {
         self.default_code_id = Some(code_id);
     }
+
+    /// Span of a contract action, the transaction details are recorded by the environment
+    #[cfg(feature = "tracing")]
+    fn action_span(&self, action: &str, msg: Option<String>) -> tracing::Span {
+        use tracing::field::Empty;
+
+        tracing::info_span!(
+            crate::log::CONTRACT_ACTION_SPAN,
+            contract_id = %self.id,
+            action,
+            msg = msg.as_deref(),
+            chain_id = Empty,
+            gas_wanted = Empty,
+            gas_used = Empty,
+            fee = Empty,
+            tx_hash = Empty,
+        )
+    }
 }
 
 // State interfaces
@@ -109,6 +128,9 @@ impl<Chain: TxHandler> Contract<Chain> {
         source: &impl Uploadable,
         access_config: Option<AccessConfig>,
     ) -> Result<TxResponse<Chain>, CwEnvError> {
+        #[cfg(feature = "tracing")]
+        let _span = self.action_span("upload", None).entered();
+
         log::info!(
             target: &contract_target(),
             "[{}][Upload]",
@@ -147,6 +169,11 @@ impl<Chain: TxHandler> Contract<Chain> {
         msg: &E,
         coins: &[Coin],
     ) -> Result<TxResponse<Chain>, CwEnvError> {
+        #[cfg(feature = "tracing")]
+        let _span = self
+            .action_span("execute", Some(serde_json::to_string(msg)?))
+            .entered();
+
         log::info!(
             target: &contract_target(),
             "[{}][Execute][{}] {}",
@@ -188,6 +215,11 @@ impl<Chain: TxHandler> Contract<Chain> {
         admin: Option<&Addr>,
         coins: &[Coin],
     ) -> Result<TxResponse<Chain>, CwEnvError> {
+        #[cfg(feature = "tracing")]
+        let _span = self
+            .action_span("instantiate", Some(serde_json::to_string(msg)?))
+            .entered();
+
         log::info!(
             target: &contract_target(),
             "[{}][Instantiate]",
@@ -233,6 +265,11 @@ impl<Chain: TxHandler> Contract<Chain> {
         coins: &[Coin],
         salt: Binary,
     ) -> Result<TxResponse<Chain>, CwEnvError> {
+        #[cfg(feature = "tracing")]
+        let _span = self
+            .action_span("instantiate", Some(serde_json::to_string(msg)?))
+            .entered();
+
         log::info!(
             target: &contract_target(),
             "[{}][Instantiate]",
@@ -276,6 +313,11 @@ impl<Chain: TxHandler> Contract<Chain> {
         migrate_msg: &M,
         new_code_id: u64,
     ) -> Result<TxResponse<Chain>, CwEnvError> {
+        #[cfg(feature = "tracing")]
+        let _span = self
+            .action_span("migrate", Some(serde_json::to_string(migrate_msg)?))
+            .entered();
+
         log::info!(
             target: &contract_target(),
             "[{}][Migrate][{}]",
@@ -313,6 +355,49 @@ impl<Chain: TxHandler> Contract<Chain> {
     }
 }
 
+impl<Chain: SudoHandler> Contract<Chain> {
+    /// Calls the sudo entry point of the contract
+    pub fn sudo<S: Serialize + Debug>(&self, msg: &S) -> Result<TxResponse<Chain>, CwEnvError> {
+        #[cfg(feature = "tracing")]
+        let _span = self
+            .action_span("sudo", Some(serde_json::to_string(msg)?))
+            .entered();
+
+        log::info!(
+            target: &contract_target(),
+            "[{}][Sudo][{}] {}",
+            self.id,
+            self.address()?,
+            get_struct_name(msg)?
+        );
+
+        log::debug!(
+            target: &contract_target(),
+            "[{}][Sudo] {}",
+            self.id,
+            log_serialize_message(msg)?
+        );
+
+        let resp = self.chain.sudo(msg, &self.address()?);
+
+        log::info!(
+            target: &contract_target(),
+            "[{}][Sudo executed][{}] {}",
+            self.id,
+            self.address()?,
+            get_struct_name(msg)?
+        );
+        log::debug!(
+            target: &transaction_target(),
+            "[{}][Sudo executed] response: {:?}",
+            self.id,
+            resp
+        );
+
+        resp.map_err(Into::into)
+    }
+}
+
 impl<Chain: ChainState + QueryHandler> Contract<Chain> {
     /// Query the contract
     pub fn query<Q: Serialize + Debug, T: Serialize + DeserializeOwned + Debug>(
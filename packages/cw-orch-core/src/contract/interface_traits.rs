@@ -2,8 +2,8 @@ use super::{Contract, WasmPath};
 use crate::environment::AccessConfig;
 use crate::{
     environment::{
-        AsyncWasmQuerier, ChainInfoOwned, ChainState, CwEnv, Environment, QueryHandler, TxHandler,
-        TxResponse, WasmQuerier,
+        AsyncWasmQuerier, ChainInfoOwned, ChainState, CwEnv, Environment, QueryHandler,
+        SudoHandler, TxHandler, TxResponse, WasmQuerier,
     },
     error::CwEnvError,
     log::contract_target,
@@ -102,6 +102,12 @@ pub trait MigratableContract {
     type MigrateMsg: Serialize + Debug;
 }
 
+/// Trait that indicates that the contract has a sudo entry point with the associated message.
+pub trait SudoableContract {
+    /// Sudo message for the contract.
+    type SudoMsg: Serialize + Debug;
+}
+
 /// Smart contract execute entry point.
 pub trait CwOrchExecute<Chain: TxHandler>: ExecutableContract + ContractInstance<Chain> {
     /// Send a ExecuteMsg to the contract.
@@ -241,6 +247,16 @@ pub trait CwOrchMigrate<Chain: TxHandler>: MigratableContract + ContractInstance
 
 impl<T: MigratableContract + ContractInstance<Chain>, Chain: TxHandler> CwOrchMigrate<Chain> for T {}
 
+/// Smart contract sudo entry point, only callable on environments that implement [`SudoHandler`].
+pub trait CwOrchSudo<Chain: SudoHandler>: SudoableContract + ContractInstance<Chain> {
+    /// Send a SudoMsg to the contract.
+    fn sudo(&self, sudo_msg: &Self::SudoMsg) -> Result<Chain::Response, CwEnvError> {
+        self.as_instance().sudo(sudo_msg)
+    }
+}
+
+impl<T: SudoableContract + ContractInstance<Chain>, Chain: SudoHandler> CwOrchSudo<Chain> for T {}
+
 /// Trait to implement on the contract to enable it to be uploaded
 ///
 /// Should return [`WasmPath`](crate::contract::interface_traits::WasmPath) for `Chain = Daemon`
@@ -257,6 +273,13 @@ pub trait Uploadable {
     }
 }
 
+/// Trait to implement on contracts with custom messages and queries, to upload them on mock environments
+/// with a custom module. `ExecC` and `QueryC` are the custom message and query types of the environment.
+pub trait CustomUploadable<ExecC, QueryC> {
+    /// Return the wrapper object for the contract, with the custom types of the environment
+    fn custom_wrapper() -> Box<dyn MockContract<ExecC, QueryC>>;
+}
+
 /// Trait that indicates that the contract can be uploaded.
 pub trait CwOrchUpload<Chain: TxHandler>: ContractInstance<Chain> + Uploadable + Sized {
     /// upload the contract to the configured environment.
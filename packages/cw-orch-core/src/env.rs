@@ -15,6 +15,7 @@ use cosmwasm_std::StdError;
 pub const ARTIFACTS_DIR_ENV_NAME: &str = "ARTIFACTS_DIR";
 pub const SERIALIZE_ENV_NAME: &str = "CW_ORCH_SERIALIZE_JSON";
 pub const MANUAL_INTERACTION_ENV_NAME: &str = "CW_ORCH_MANUAL_INTERACTION";
+pub const CHAIN_REGISTRY_ENV_NAME: &str = "CW_ORCH_CHAIN_REGISTRY";
 
 pub struct CoreEnvVars;
 
@@ -37,6 +38,16 @@ impl CoreEnvVars {
         }
     }
 
+    /// Optional - Path
+    /// Local checkout of the cosmos chain-registry, used to load chains by their registry name
+    pub fn chain_registry() -> Option<PathBuf> {
+        if let Ok(str_value) = env::var(CHAIN_REGISTRY_ENV_NAME) {
+            Some(parse_with_log(str_value, CHAIN_REGISTRY_ENV_NAME))
+        } else {
+            None
+        }
+    }
+
     /// Optional - Boolean
     /// Defaults to false
     /// If equals to true, will serialize the blockchain messages as json (for easy copying) instead of Rust Debug formatting
@@ -8,10 +8,26 @@ pub type ChainInfoOwned = ChainInfoBase<String, Vec<String>>;
 pub type NetworkInfo = NetworkInfoBase<&'static str>;
 pub type NetworkInfoOwned = NetworkInfoBase<String>;
 
+pub type KeyAlgorithm = KeyAlgorithmBase<&'static str>;
+pub type KeyAlgorithmOwned = KeyAlgorithmBase<String>;
+
+/// Type url of the public keys of Ethermint based chains (Evmos, Cronos, Dymension, etc)
+pub const ETHERMINT_PUB_KEY_TYPE_URL: &str = "/ethermint.crypto.v1.ethsecp256k1.PubKey";
+/// Type url of the public keys of Injective
+pub const INJECTIVE_PUB_KEY_TYPE_URL: &str = "/injective.crypto.v1beta1.ethsecp256k1.PubKey";
+/// Coin type of Ethereum, used by the chains with eth_secp256k1 keys
+pub const ETHEREUM_COIN_TYPE: u32 = 60;
+
 /// Information about a chain.
 /// This is used to connect to a chain and to generate transactions.
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
-#[serde(default)]
+#[serde(
+    default,
+    deny_unknown_fields,
+    bound(
+        deserialize = "StringType: Deserialize<'de> + AsRef<str> + From<&'static str>, StringArrayType: Deserialize<'de> + Default"
+    )
+)]
 pub struct ChainInfoBase<StringType: Into<String> + Default, StringArrayType: AsRef<[StringType]>> {
     /// Identifier for the network ex. phoenix-2, pisco-1
     pub chain_id: StringType,
@@ -32,8 +48,15 @@ pub struct ChainInfoBase<StringType: Into<String> + Default, StringArrayType: As
 }
 
 /// Information about the underlying network, used for key derivation
+///
+/// When deserialized without `key_algorithm`, the key algorithm is inferred from the coin type, see [`KeyAlgorithmBase::from_coin_type`].
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
-#[serde(default)]
+#[serde(
+    from = "NetworkInfoFields<StringType>",
+    bound(
+        deserialize = "StringType: Deserialize<'de> + Default + AsRef<str> + From<&'static str>"
+    )
+)]
 pub struct NetworkInfoBase<StringType: Into<String>> {
     /// network identifier (ex. juno, terra2, osmosis, etc)
     pub chain_name: StringType,
@@ -41,6 +64,114 @@ pub struct NetworkInfoBase<StringType: Into<String>> {
     pub pub_address_prefix: StringType,
     /// coin type for key derivation
     pub coin_type: u32,
+    /// algorithm of the account keys, used for address derivation and signing
+    pub key_algorithm: KeyAlgorithmBase<StringType>,
+}
+
+/// Deserialized fields of [`NetworkInfoBase`], the key algorithm is optional
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct NetworkInfoFields<StringType: Into<String>> {
+    chain_name: StringType,
+    pub_address_prefix: StringType,
+    coin_type: u32,
+    key_algorithm: Option<KeyAlgorithmBase<StringType>>,
+}
+
+impl<StringType: Into<String> + Default> Default for NetworkInfoFields<StringType> {
+    fn default() -> Self {
+        let NetworkInfoBase {
+            chain_name,
+            pub_address_prefix,
+            coin_type,
+            ..
+        } = NetworkInfoBase::default();
+        Self {
+            chain_name,
+            pub_address_prefix,
+            coin_type,
+            key_algorithm: None,
+        }
+    }
+}
+
+impl<StringType: Into<String> + AsRef<str> + From<&'static str>> From<NetworkInfoFields<StringType>>
+    for NetworkInfoBase<StringType>
+{
+    fn from(fields: NetworkInfoFields<StringType>) -> Self {
+        let NetworkInfoFields {
+            chain_name,
+            pub_address_prefix,
+            coin_type,
+            key_algorithm,
+        } = fields;
+        let key_algorithm = key_algorithm.unwrap_or_else(|| {
+            KeyAlgorithmBase::from_coin_type(coin_type, pub_address_prefix.as_ref())
+        });
+        Self {
+            chain_name,
+            pub_address_prefix,
+            coin_type,
+            key_algorithm,
+        }
+    }
+}
+
+/// Algorithm of the account keys of a network
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyAlgorithmBase<StringType: Into<String>> {
+    /// Cosmos SDK secp256k1 keys
+    #[default]
+    Secp256k1,
+    /// Ethereum style secp256k1 keys, addresses are derived with keccak256 and sign docs are hashed with keccak256
+    EthSecp256k1 {
+        /// Type url of the public key, differs between chains
+        pub_key_type_url: StringType,
+    },
+}
+
+impl KeyAlgorithm {
+    /// eth_secp256k1 keys of Ethermint based chains
+    pub const ETHERMINT: Self = Self::EthSecp256k1 {
+        pub_key_type_url: ETHERMINT_PUB_KEY_TYPE_URL,
+    };
+    /// eth_secp256k1 keys of Injective
+    pub const INJECTIVE: Self = Self::EthSecp256k1 {
+        pub_key_type_url: INJECTIVE_PUB_KEY_TYPE_URL,
+    };
+}
+
+impl<StringType: Into<String>> KeyAlgorithmBase<StringType> {
+    /// Wether the keys are Ethereum style keys
+    pub fn is_eth(&self) -> bool {
+        matches!(self, Self::EthSecp256k1 { .. })
+    }
+}
+
+impl<StringType: Into<String> + From<&'static str>> KeyAlgorithmBase<StringType> {
+    /// eth_secp256k1 keys of the chain with this address prefix, with the Injective public key type on Injective
+    /// and the Ethermint one otherwise
+    pub fn eth_secp256k1(pub_address_prefix: &str) -> Self {
+        let pub_key_type_url = if pub_address_prefix == "inj" {
+            INJECTIVE_PUB_KEY_TYPE_URL
+        } else {
+            ETHERMINT_PUB_KEY_TYPE_URL
+        };
+        Self::EthSecp256k1 {
+            pub_key_type_url: pub_key_type_url.into(),
+        }
+    }
+
+    /// Key algorithm of a network that doesn't specify one:
+    /// eth_secp256k1 for the Ethereum coin type ([`ETHEREUM_COIN_TYPE`]), secp256k1 otherwise
+    pub fn from_coin_type(coin_type: u32, pub_address_prefix: &str) -> Self {
+        if coin_type == ETHEREUM_COIN_TYPE {
+            Self::eth_secp256k1(pub_address_prefix)
+        } else {
+            Self::Secp256k1
+        }
+    }
 }
 
 impl<StringType: Into<String> + Default> Default for NetworkInfoBase<StringType> {
@@ -50,6 +181,7 @@ impl<StringType: Into<String> + Default> Default for NetworkInfoBase<StringType>
             pub_address_prefix: StringType::default(),
             // Default cosmos coin
             coin_type: 118,
+            key_algorithm: KeyAlgorithmBase::Secp256k1,
         }
     }
 }
@@ -91,6 +223,18 @@ impl From<NetworkInfo> for NetworkInfoOwned {
             chain_name: value.chain_name.to_string(),
             pub_address_prefix: value.pub_address_prefix.to_string(),
             coin_type: value.coin_type,
+            key_algorithm: value.key_algorithm.into(),
+        }
+    }
+}
+
+impl From<KeyAlgorithm> for KeyAlgorithmOwned {
+    fn from(value: KeyAlgorithm) -> Self {
+        match value {
+            KeyAlgorithm::Secp256k1 => KeyAlgorithmOwned::Secp256k1,
+            KeyAlgorithm::EthSecp256k1 { pub_key_type_url } => KeyAlgorithmOwned::EthSecp256k1 {
+                pub_key_type_url: pub_key_type_url.to_string(),
+            },
         }
     }
 }
@@ -159,6 +303,7 @@ impl ChainInfoOwned {
                     chain_name,
                     pub_address_prefix,
                     coin_type,
+                    key_algorithm,
                 },
             kind,
         } = chain_info;
@@ -190,6 +335,9 @@ impl ChainInfoOwned {
         if coin_type != 118 {
             self.network_info.coin_type = coin_type;
         }
+        if key_algorithm != KeyAlgorithmOwned::Secp256k1 {
+            self.network_info.key_algorithm = key_algorithm;
+        }
         if kind != ChainKind::Unspecified {
             self.kind = kind;
         }
@@ -7,6 +7,8 @@ use super::{
 };
 use cosmwasm_std::{Addr, Coin};
 use cw_utils::NativeBalance;
+use serde::Serialize;
+use std::fmt::Debug;
 
 /// Describes a structure that contains an underlying execution environment
 pub trait Environment<Chain> {
@@ -43,3 +45,23 @@ pub trait BankSetter: TxHandler + QuerierGetter<Self::T> {
         Ok(())
     }
 }
+
+/// Privileged wasm operations that are only available through governance on a live chain.
+/// Implemented by the local environments that own their chain.
+pub trait SudoHandler: TxHandler {
+    /// Calls the `sudo` entry point of a contract.
+    fn sudo<S: Serialize + Debug>(
+        &self,
+        sudo_msg: &S,
+        contract_address: &Addr,
+    ) -> Result<Self::Response, <Self as TxHandler>::Error>;
+
+    /// Pins a code in the wasm cache, like a `PinCodes` proposal.
+    fn pin_code(&self, code_id: u64) -> Result<(), <Self as TxHandler>::Error>;
+
+    /// Unpins a code from the wasm cache, like an `UnpinCodes` proposal.
+    fn unpin_code(&self, code_id: u64) -> Result<(), <Self as TxHandler>::Error>;
+
+    /// Whether the code is pinned.
+    fn is_pinned(&self, code_id: u64) -> Result<bool, <Self as TxHandler>::Error>;
+}
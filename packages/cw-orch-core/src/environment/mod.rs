@@ -5,8 +5,11 @@ mod queriers;
 mod state;
 mod tx_handler;
 
-pub use chain_info::{ChainInfo, ChainInfoOwned, ChainKind, NetworkInfo, NetworkInfoOwned};
-pub use envs::{BankSetter, CwEnv, Environment, MutCwEnv};
+pub use chain_info::{
+    ChainInfo, ChainInfoOwned, ChainKind, KeyAlgorithm, KeyAlgorithmOwned, NetworkInfo,
+    NetworkInfoOwned, ETHEREUM_COIN_TYPE, ETHERMINT_PUB_KEY_TYPE_URL, INJECTIVE_PUB_KEY_TYPE_URL,
+};
+pub use envs::{BankSetter, CwEnv, Environment, MutCwEnv, SudoHandler};
 pub use index_response::IndexResponse;
 pub use queriers::{
     bank::BankQuerier,
@@ -4,6 +4,10 @@ const CONTRACT_LOGS: &str = "Contract";
 const TRANSACTION_LOGS: &str = "Transaction";
 const LOCAL_LOGS: &str = "Local";
 
+/// Name of the `tracing` span wrapping each contract action (upload, instantiate, execute, migrate).
+/// Its `chain_id`, `gas_wanted`, `gas_used`, `fee` and `tx_hash` fields are recorded by the environment.
+pub const CONTRACT_ACTION_SPAN: &str = "contract_action";
+
 fn format_aligned(a: &str) -> String {
     format!("{:>12}", a)
 }
@@ -1,14 +1,19 @@
 use std::{cell::RefCell, rc::Rc};
 
 use cosmwasm_std::{testing::MockApi, Addr, Coin, Uint128};
-use cw_multi_test::{AppBuilder, MockApiBech32};
+use cw_multi_test::{AppBuilder, FailingModule, MockApiBech32, StargateFailing};
 use cw_orch_core::{
     environment::{BankQuerier, BankSetter, DefaultQueriers, StateInterface, TxHandler},
     CwEnvError,
 };
 use cw_utils::NativeBalance;
 
-use crate::{queriers::bank::MockBankQuerier, MockBase, MockBech32, MockState};
+use crate::{
+    gas::MeteredStorage,
+    queriers::bank::MockBankQuerier,
+    stargate::{MockStargate, MockStargateModule},
+    FailingCustomModule, MockBase, MockBech32, MockCustomModule, MockState,
+};
 
 impl MockBase<MockApiBech32, MockState> {
     /// Create a mock environment with the default mock state.
@@ -27,7 +32,21 @@ impl MockBase<MockApiBech32, MockState> {
     }
 }
 
-impl<S: StateInterface> MockBase<MockApiBech32, S> {
+impl MockBech32<MockState, FailingCustomModule, MockStargate> {
+    /// Create a mock environment with the default mock state that handles stargate messages with the [`MockStargate`] module.
+    pub fn new_with_stargate(prefix: &'static str) -> Self {
+        MockBech32::new_with_modules(
+            prefix,
+            MockState::new(),
+            FailingModule::new(),
+            MockStargate::new(),
+        )
+    }
+}
+
+impl<S: StateInterface, M: MockCustomModule, G: MockStargateModule>
+    MockBase<MockApiBech32, S, M, G>
+{
     pub fn addr_make(&self, account_name: impl Into<String>) -> Addr {
         self.app.borrow().api().addr_make(&account_name.into())
     }
@@ -43,7 +62,7 @@ impl<S: StateInterface> MockBase<MockApiBech32, S> {
     }
 }
 
-impl<S: StateInterface> MockBase<MockApi, S> {
+impl<S: StateInterface, M: MockCustomModule, G: MockStargateModule> MockBase<MockApi, S, M, G> {
     pub fn addr_make(&self, account_name: impl Into<String>) -> Addr {
         self.app.borrow().api().addr_make(&account_name.into())
     }
@@ -70,21 +89,51 @@ impl<S: StateInterface> MockBase<MockApiBech32, S> {
     /// Create a mock environment with a custom mock state.
     /// The state is customizable by implementing the `StateInterface` trait on a custom struct and providing it on the custom constructor.
     pub fn new_custom(prefix: &'static str, custom_state: S) -> Self {
+        MockBech32::new_custom_module(prefix, custom_state, FailingModule::new())
+    }
+}
+
+impl<S: StateInterface, M: MockCustomModule> MockBase<MockApiBech32, S, M> {
+    /// Create a mock environment with a custom mock state and a custom module.
+    /// The custom module handles the `CosmosMsg::Custom` messages and `QueryRequest::Custom` queries of the contracts.
+    pub fn new_custom_module(prefix: &'static str, custom_state: S, custom_module: M) -> Self {
+        MockBech32::new_with_modules(prefix, custom_state, custom_module, StargateFailing)
+    }
+}
+
+impl<S: StateInterface, M: MockCustomModule, G: MockStargateModule>
+    MockBase<MockApiBech32, S, M, G>
+{
+    /// Create a mock environment with a custom mock state, a custom module and a stargate module.
+    pub fn new_with_modules(
+        prefix: &'static str,
+        custom_state: S,
+        custom_module: M,
+        stargate: G,
+    ) -> Self {
         let state = Rc::new(RefCell::new(custom_state));
         let app = Rc::new(RefCell::new(
             AppBuilder::new_custom()
                 .with_api(MockApiBech32::new(prefix))
+                .with_custom(custom_module)
+                .with_stargate(stargate)
+                .with_storage(MeteredStorage::default())
                 .build(|_, _, _| {}),
         ));
 
         // We create an address internally
         let sender = app.borrow().api().addr_make("sender");
 
-        Self { sender, state, app }
+        Self {
+            sender,
+            state,
+            app,
+            code_factories: Default::default(),
+        }
     }
 }
 
-impl<S: StateInterface> MockBech32<S> {
+impl<S: StateInterface, M: MockCustomModule, G: MockStargateModule> MockBech32<S, M, G> {
     /// Set the bank balance of an address.
     pub fn set_balance(
         &self,
@@ -151,8 +200,10 @@ impl<S: StateInterface> MockBech32<S> {
     }
 }
 
-impl<S: StateInterface> BankSetter for MockBech32<S> {
-    type T = MockBankQuerier<MockApiBech32>;
+impl<S: StateInterface, M: MockCustomModule, G: MockStargateModule> BankSetter
+    for MockBech32<S, M, G>
+{
+    type T = MockBankQuerier<MockApiBech32, M, G>;
 
     fn set_balance(
         &mut self,
@@ -1,33 +1,135 @@
 use std::{cell::RefCell, fmt::Debug, rc::Rc};
 
 use cosmwasm_std::{
-    testing::{MockApi, MockStorage},
-    to_json_binary, Addr, Api, BankMsg, Binary, CosmosMsg, Empty, Event, WasmMsg,
+    testing::MockApi, to_json_binary, to_json_vec, Addr, Api, BankMsg, Binary, CosmosMsg,
+    CustomMsg, CustomQuery, Deps, DepsMut, Empty, Env, Event, MessageInfo, Reply, Response,
+    WasmMsg,
 };
+#[cfg(feature = "staking")]
+use cosmwasm_std::{Coin, DistributionMsg, StakingMsg};
 use cw_multi_test::{
-    ibc::IbcSimpleModule, App, AppResponse, BankKeeper, Contract, DistributionKeeper, Executor,
-    FailingModule, GovFailingModule, MockApiBech32, StakeKeeper, StargateFailing, WasmKeeper,
+    error::{anyhow, AnyResult},
+    ibc::IbcSimpleModule,
+    App, AppResponse, BankKeeper, Contract, DistributionKeeper, Executor, FailingModule,
+    GovFailingModule, MockApiBech32, Module, StakeKeeper, StargateFailing, WasmKeeper,
 };
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Serialize};
 
-use super::state::MockState;
+use super::{
+    gas::{GasAction, MeteredStorage},
+    pinned_codes,
+    snapshot::{CodeFactories, CodeFactory},
+    stargate::{MockStargate, MockStargateModule, StargateHandler},
+    state::MockState,
+};
 use cw_orch_core::{
-    contract::interface_traits::Uploadable,
-    environment::{AccessConfig, ChainState, IndexResponse, StateInterface, TxHandler},
+    contract::interface_traits::{ContractInstance, CustomUploadable, Uploadable},
+    environment::{
+        AccessConfig, ChainState, IndexResponse, StateInterface, SudoHandler, TxHandler,
+    },
     CwEnvError,
 };
+#[cfg(feature = "staking")]
+use cw_orch_traits::Delegator;
+#[cfg(feature = "stargate")]
+use cw_orch_traits::Stargate;
+
+/// Custom module of the mock environments.
+/// It handles the `CosmosMsg::Custom` messages and `QueryRequest::Custom` queries of the contracts,
+/// chain specific bindings (Osmosis, Neutron, ...) can be emulated by a cw-multi-test [`Module`].
+///
+/// ```ignore
+/// impl MockCustomModule for OsmosisBindings {}
+/// ```
+pub trait MockCustomModule:
+    Module<
+        ExecT: CustomMsg + DeserializeOwned + 'static,
+        QueryT: CustomQuery + std::fmt::Debug + DeserializeOwned + 'static,
+    > + 'static
+{
+    /// Wraps a contract without custom messages and queries ([`Uploadable::wrapper`]) for this environment
+    fn plain_wrapper(contract: Box<dyn Contract<Empty, Empty>>) -> MockContractSource<Self> {
+        Box::new(PlainContract(contract))
+    }
+}
+
+/// Default custom module, custom messages and queries are not supported
+pub type FailingCustomModule = FailingModule<Empty, Empty, Empty>;
+
+impl MockCustomModule for FailingCustomModule {
+    fn plain_wrapper(contract: Box<dyn Contract<Empty, Empty>>) -> MockContractSource<Self> {
+        contract
+    }
+}
+
+/// Contract without custom messages and queries, uploaded on an environment with a custom module.
+/// Only the entry points of [`Contract`] without default implementation are forwarded.
+struct PlainContract(Box<dyn Contract<Empty, Empty>>);
+
+impl PlainContract {
+    fn response<C>(response: Response<Empty>) -> AnyResult<Response<C>> {
+        response.change_custom().ok_or_else(|| {
+            anyhow!("Contracts without custom messages can't return a custom message")
+        })
+    }
+}
+
+impl<C, Q> Contract<C, Q> for PlainContract
+where
+    C: CustomMsg + DeserializeOwned + 'static,
+    Q: CustomQuery + DeserializeOwned + 'static,
+{
+    fn execute(
+        &self,
+        deps: DepsMut<Q>,
+        env: Env,
+        info: MessageInfo,
+        msg: Vec<u8>,
+    ) -> AnyResult<Response<C>> {
+        Self::response(self.0.execute(deps.into_empty(), env, info, msg)?)
+    }
+
+    fn instantiate(
+        &self,
+        deps: DepsMut<Q>,
+        env: Env,
+        info: MessageInfo,
+        msg: Vec<u8>,
+    ) -> AnyResult<Response<C>> {
+        Self::response(self.0.instantiate(deps.into_empty(), env, info, msg)?)
+    }
+
+    fn query(&self, deps: Deps<Q>, env: Env, msg: Vec<u8>) -> AnyResult<Binary> {
+        self.0.query(deps.into_empty(), env, msg)
+    }
+
+    fn sudo(&self, deps: DepsMut<Q>, env: Env, msg: Vec<u8>) -> AnyResult<Response<C>> {
+        Self::response(self.0.sudo(deps.into_empty(), env, msg)?)
+    }
+
+    fn reply(&self, deps: DepsMut<Q>, env: Env, msg: Reply) -> AnyResult<Response<C>> {
+        Self::response(self.0.reply(deps.into_empty(), env, msg)?)
+    }
+
+    fn migrate(&self, deps: DepsMut<Q>, env: Env, msg: Vec<u8>) -> AnyResult<Response<C>> {
+        Self::response(self.0.migrate(deps.into_empty(), env, msg)?)
+    }
+}
 
-pub type MockApp<A = MockApi> = App<
+/// Contract wrapper of a mock environment with the custom module `M`
+pub type MockContractSource<M> = Box<dyn Contract<<M as Module>::ExecT, <M as Module>::QueryT>>;
+
+pub type MockApp<A = MockApi, M = FailingCustomModule, G = StargateFailing> = App<
     BankKeeper,
     A,
-    MockStorage,
-    FailingModule<Empty, Empty, Empty>,
-    WasmKeeper<Empty, Empty>,
+    MeteredStorage,
+    M,
+    WasmKeeper<<M as Module>::ExecT, <M as Module>::QueryT>,
     StakeKeeper,
     DistributionKeeper,
     IbcSimpleModule,
     GovFailingModule,
-    StargateFailing,
+    G,
 >;
 
 /// Wrapper around a cw-multi-test [`App`](cw_multi_test::App) backend.
@@ -36,6 +138,10 @@ pub type MockApp<A = MockApi> = App<
 ///
 /// The state is customizable by implementing the [`StateInterface`] trait on a custom struct and providing it on the custom constructor.
 ///
+/// Stargate messages are rejected by default, like on cw-multi-test. Environments created with [`MockStargate`]
+/// (`Mock::new_with_stargate`) dispatch them to the registered [`StargateHandler`]s.
+/// The default module is the only one supported by the cw-multi-test IBC relayer.
+///
 /// The addresses used inside this environment are bech32 addresses. For instance, when creating a mock environment
 /// let chain = Mock::new("sender");
 /// the actual sender address can be generated using
@@ -67,29 +173,41 @@ pub type MockApp<A = MockApi> = App<
 ///
 /// let mock: Mock = Mock::new_custom("sender", CustomState::new());
 /// ```
-pub struct MockBase<A: Api = MockApi, S: StateInterface = MockState> {
+pub struct MockBase<
+    A: Api = MockApi,
+    S: StateInterface = MockState,
+    M: MockCustomModule = FailingCustomModule,
+    G: MockStargateModule = StargateFailing,
+> {
     /// Address used for the operations.
     pub sender: Addr,
     /// Inner mutable state storage for contract addresses and code-ids
     pub state: Rc<RefCell<S>>,
     /// Inner mutable cw-multi-test app backend
-    pub app: Rc<RefCell<MockApp<A>>>,
+    pub app: Rc<RefCell<MockApp<A, M, G>>>,
+    /// Wrappers of the uploaded codes, to fork the environment
+    pub(crate) code_factories: CodeFactories<M>,
 }
 
-pub type Mock<S = MockState> = MockBase<MockApi, S>;
-pub type MockBech32<S = MockState> = MockBase<MockApiBech32, S>;
+pub type Mock<S = MockState, M = FailingCustomModule, G = StargateFailing> =
+    MockBase<MockApi, S, M, G>;
+pub type MockBech32<S = MockState, M = FailingCustomModule, G = StargateFailing> =
+    MockBase<MockApiBech32, S, M, G>;
 
-impl<A: Api, S: StateInterface> Clone for MockBase<A, S> {
+impl<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule> Clone
+    for MockBase<A, S, M, G>
+{
     fn clone(&self) -> Self {
         Self {
             sender: self.sender.clone(),
             state: self.state.clone(),
             app: self.app.clone(),
+            code_factories: self.code_factories.clone(),
         }
     }
 }
 
-impl<A: Api> MockBase<A, MockState> {
+impl<A: Api, M: MockCustomModule, G: MockStargateModule> MockBase<A, MockState, M, G> {
     pub fn with_chain_id(&mut self, chain_id: &str) {
         self.state.borrow_mut().set_chain_id(chain_id);
         self.app
@@ -98,31 +216,51 @@ impl<A: Api> MockBase<A, MockState> {
     }
 }
 
-impl<A: Api, S: StateInterface> MockBase<A, S> {
+impl<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule> MockBase<A, S, M, G> {
     /// Upload a custom contract wrapper.
     /// Support for this is limited.
     pub fn upload_custom(
         &self,
         contract_id: &str,
-        wrapper: Box<dyn Contract<Empty, Empty>>,
+        wrapper: MockContractSource<M>,
     ) -> Result<AppResponse, CwEnvError> {
-        let code_id = self
-            .app
-            .borrow_mut()
-            .store_code_with_creator(self.sender_addr(), wrapper);
-        // add contract code_id to events manually
-        let mut event = Event::new("store_code");
-        event = event.add_attribute("code_id", code_id.to_string());
-        let resp = AppResponse {
-            events: vec![event],
-            ..Default::default()
-        };
+        let resp = self.store_code(wrapper, None);
         let code_id = IndexResponse::uploaded_code_id(&resp)?;
         self.state.borrow_mut().set_code_id(contract_id, code_id);
         Ok(resp)
     }
+
+    /// Uploads a contract with the custom messages and queries of this environment
+    /// and registers its code id for the contract.
+    ///
+    /// ```ignore
+    /// let chain = Mock::new_custom_module("sender", MockState::new(), OsmosisBindings);
+    /// let pool = PoolContract::new(chain.clone());
+    /// chain.upload_custom_contract(&pool)?;
+    /// pool.instantiate(&InstantiateMsg {}, None, &[])?;
+    /// ```
+    pub fn upload_custom_contract<T>(&self, contract: &T) -> Result<AppResponse, CwEnvError>
+    where
+        T: CustomUploadable<M::ExecT, M::QueryT> + ContractInstance<Self>,
+    {
+        let resp = self.store_code(T::custom_wrapper(), Some(T::custom_wrapper));
+        contract.set_code_id(IndexResponse::uploaded_code_id(&resp)?);
+        Ok(resp)
+    }
 }
-impl<A: Api, S: StateInterface> ChainState for MockBase<A, S> {
+
+impl<A: Api, S: StateInterface, M: MockCustomModule> MockBase<A, S, M, MockStargate> {
+    /// Registers a handler for stargate messages and queries.
+    /// It replaces the handlers previously registered for the same type urls and query paths.
+    pub fn add_stargate_handler(&self, handler: impl StargateHandler + 'static) {
+        self.app
+            .borrow_mut()
+            .init_modules(|router, _, _| router.stargate.add_handler(handler));
+    }
+}
+impl<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule> ChainState
+    for MockBase<A, S, M, G>
+{
     type Out = Rc<RefCell<S>>;
 
     fn state(&self) -> Self::Out {
@@ -131,10 +269,12 @@ impl<A: Api, S: StateInterface> ChainState for MockBase<A, S> {
 }
 
 // Execute on the test chain, returns test response type
-impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
+impl<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule> TxHandler
+    for MockBase<A, S, M, G>
+{
     type Response = AppResponse;
     type Error = CwEnvError;
-    type ContractSource = Box<dyn Contract<Empty, Empty>>;
+    type ContractSource = MockContractSource<M>;
     type Sender = Addr;
 
     fn sender(&self) -> &Self::Sender {
@@ -150,18 +290,7 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
     }
 
     fn upload<T: Uploadable>(&self, _contract: &T) -> Result<Self::Response, CwEnvError> {
-        let code_id = self
-            .app
-            .borrow_mut()
-            .store_code_with_creator(self.sender_addr(), T::wrapper());
-        // add contract code_id to events manually
-        let mut event = Event::new("store_code");
-        event = event.add_attribute("code_id", code_id.to_string());
-        let resp = AppResponse {
-            events: vec![event],
-            ..Default::default()
-        };
-        Ok(resp)
+        Ok(self.store_code(plain_wrapper::<M, T>(), Some(plain_wrapper::<M, T>)))
     }
 
     fn execute<E: Serialize + Debug>(
@@ -170,15 +299,22 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
         coins: &[cosmwasm_std::Coin],
         contract_address: &Addr,
     ) -> Result<Self::Response, CwEnvError> {
-        self.app
-            .borrow_mut()
-            .execute_contract(
-                self.sender.clone(),
-                contract_address.to_owned(),
-                exec_msg,
-                coins,
-            )
-            .map_err(From::from)
+        self.metered(
+            GasAction::Execute,
+            Some(contract_address),
+            &to_json_vec(exec_msg)?,
+            || {
+                self.app
+                    .borrow_mut()
+                    .execute_contract(
+                        self.sender.clone(),
+                        contract_address.to_owned(),
+                        exec_msg,
+                        coins,
+                    )
+                    .map_err(From::from)
+            },
+        )
     }
 
     fn instantiate<I: Serialize + Debug>(
@@ -189,23 +325,25 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
         admin: Option<&Addr>,
         coins: &[cosmwasm_std::Coin],
     ) -> Result<Self::Response, CwEnvError> {
+        let msg_bytes = to_json_binary(init_msg)?;
         let msg = WasmMsg::Instantiate {
             admin: admin.map(|a| a.to_string()),
             code_id,
             label: label.unwrap_or("contract_init").to_string(),
-            msg: to_json_binary(init_msg)?,
+            msg: msg_bytes.clone(),
             funds: coins.to_vec(),
         };
-        let app = self
-            .app
-            .borrow_mut()
-            .execute(self.sender.clone(), CosmosMsg::Wasm(msg))?;
+        self.metered(GasAction::Instantiate, None, &msg_bytes, || {
+            let app = self
+                .app
+                .borrow_mut()
+                .execute(self.sender.clone(), CosmosMsg::Wasm(msg))?;
 
-        let resp = AppResponse {
-            events: app.events,
-            data: app.data,
-        };
-        Ok(resp)
+            Ok(AppResponse {
+                events: app.events,
+                data: app.data,
+            })
+        })
     }
 
     fn instantiate2<I: Serialize + Debug>(
@@ -217,42 +355,51 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
         coins: &[cosmwasm_std::Coin],
         salt: Binary,
     ) -> Result<Self::Response, CwEnvError> {
+        let msg_bytes = to_json_binary(init_msg)?;
         let msg = WasmMsg::Instantiate2 {
             admin: admin.map(|a| a.to_string()),
             code_id,
             label: label.unwrap_or("contract_init").to_string(),
-            msg: to_json_binary(init_msg)?,
+            msg: msg_bytes.clone(),
             funds: coins.to_vec(),
             salt,
         };
 
-        let app = self
-            .app
-            .borrow_mut()
-            .execute(self.sender.clone(), CosmosMsg::Wasm(msg))?;
+        self.metered(GasAction::Instantiate, None, &msg_bytes, || {
+            let app = self
+                .app
+                .borrow_mut()
+                .execute(self.sender.clone(), CosmosMsg::Wasm(msg))?;
 
-        let resp = AppResponse {
-            events: app.events,
-            data: app.data,
-        };
-        Ok(resp)
+            Ok(AppResponse {
+                events: app.events,
+                data: app.data,
+            })
+        })
     }
 
-    fn migrate<M: Serialize + Debug>(
+    fn migrate<MigrateMsg: Serialize + Debug>(
         &self,
-        migrate_msg: &M,
+        migrate_msg: &MigrateMsg,
         new_code_id: u64,
         contract_address: &Addr,
     ) -> Result<Self::Response, CwEnvError> {
-        self.app
-            .borrow_mut()
-            .migrate_contract(
-                self.sender.clone(),
-                contract_address.clone(),
-                migrate_msg,
-                new_code_id,
-            )
-            .map_err(From::from)
+        self.metered(
+            GasAction::Migrate,
+            Some(contract_address),
+            &to_json_vec(migrate_msg)?,
+            || {
+                self.app
+                    .borrow_mut()
+                    .migrate_contract(
+                        self.sender.clone(),
+                        contract_address.clone(),
+                        migrate_msg,
+                        new_code_id,
+                    )
+                    .map_err(From::from)
+            },
+        )
     }
 
     fn upload_with_access_config<T: Uploadable>(
@@ -269,17 +416,217 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
         receiver: &Addr,
         amount: &[cosmwasm_std::Coin],
     ) -> Result<Self::Response, Self::Error> {
+        let msg = BankMsg::Send {
+            to_address: receiver.to_string(),
+            amount: amount.to_vec(),
+        };
+        self.metered(GasAction::BankSend, None, &to_json_vec(&msg)?, || {
+            self.app
+                .borrow_mut()
+                .execute(self.sender.clone(), msg.into())
+                .map_err(From::from)
+        })
+    }
+}
+
+impl<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule> MockBase<A, S, M, G> {
+    fn store_code(
+        &self,
+        wrapper: MockContractSource<M>,
+        factory: Option<CodeFactory<M>>,
+    ) -> AppResponse {
+        let code_id = self
+            .app
+            .borrow_mut()
+            .store_code_with_creator(self.sender_addr(), wrapper);
+        self.code_factories.borrow_mut().insert(code_id, factory);
+        // add contract code_id to events manually
+        let mut event = Event::new("store_code");
+        event = event.add_attribute("code_id", code_id.to_string());
+        AppResponse {
+            events: vec![event],
+            ..Default::default()
+        }
+    }
+
+    /// Stores the code returned by `factory` with the given creator and code id
+    pub(crate) fn store_code_with_id(
+        &self,
+        creator: Addr,
+        code_id: u64,
+        factory: CodeFactory<M>,
+    ) -> Result<(), CwEnvError> {
         self.app
             .borrow_mut()
-            .execute(
-                self.sender.clone(),
-                BankMsg::Send {
-                    to_address: receiver.to_string(),
-                    amount: amount.to_vec(),
-                }
-                .into(),
-            )
-            .map_err(From::from)
+            .store_code_with_id(creator, code_id, factory())?;
+        self.code_factories
+            .borrow_mut()
+            .insert(code_id, Some(factory));
+        Ok(())
+    }
+
+    #[cfg(any(feature = "staking", feature = "stargate"))]
+    fn execute_msgs(&self, msgs: Vec<CosmosMsg<M::ExecT>>) -> Result<AppResponse, CwEnvError> {
+        self.metered(GasAction::Messages, None, &to_json_vec(&msgs)?, || {
+            let responses = self
+                .app
+                .borrow_mut()
+                .execute_multi(self.sender.clone(), msgs)?;
+            let mut response = AppResponse::default();
+            for r in responses {
+                response.events.extend(r.events);
+                response.data = r.data.or(response.data);
+            }
+            Ok(response)
+        })
+    }
+}
+
+/// Wrapper of a contract uploaded with [`TxHandler::upload`], also used to fork the environment
+pub(crate) fn plain_wrapper<M: MockCustomModule, T: Uploadable>() -> MockContractSource<M> {
+    M::plain_wrapper(T::wrapper())
+}
+
+/// Staking actions are handled by the cw-multi-test staking and distribution modules.
+/// Validators need to be registered on the staking module beforehand.
+#[cfg(feature = "staking")]
+impl<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule> Delegator
+    for MockBase<A, S, M, G>
+{
+    fn delegate(&self, validator: &str, amount: &Coin) -> Result<Self::Response, Self::Error> {
+        self.execute_msgs(vec![StakingMsg::Delegate {
+            validator: validator.to_string(),
+            amount: amount.clone(),
+        }
+        .into()])
+    }
+
+    fn undelegate(&self, validator: &str, amount: &Coin) -> Result<Self::Response, Self::Error> {
+        self.execute_msgs(vec![StakingMsg::Undelegate {
+            validator: validator.to_string(),
+            amount: amount.clone(),
+        }
+        .into()])
+    }
+
+    fn redelegate(
+        &self,
+        src_validator: &str,
+        dst_validator: &str,
+        amount: &Coin,
+    ) -> Result<Self::Response, Self::Error> {
+        self.execute_msgs(vec![StakingMsg::Redelegate {
+            src_validator: src_validator.to_string(),
+            dst_validator: dst_validator.to_string(),
+            amount: amount.clone(),
+        }
+        .into()])
+    }
+
+    fn withdraw_rewards(&self, validator: &str) -> Result<Self::Response, Self::Error> {
+        self.execute_msgs(vec![DistributionMsg::WithdrawDelegatorReward {
+            validator: validator.to_string(),
+        }
+        .into()])
+    }
+
+    fn withdraw_all_rewards(&self) -> Result<Self::Response, Self::Error> {
+        let delegations = self
+            .app
+            .borrow()
+            .wrap()
+            .query_all_delegations(&self.sender)?;
+        if delegations.is_empty() {
+            return Err(CwEnvError::StdErr(format!(
+                "{} has no delegations to withdraw rewards from",
+                self.sender
+            )));
+        }
+        self.execute_msgs(
+            delegations
+                .into_iter()
+                .map(|delegation| {
+                    DistributionMsg::WithdrawDelegatorReward {
+                        validator: delegation.validator,
+                    }
+                    .into()
+                })
+                .collect(),
+        )
+    }
+
+    fn set_withdraw_address(&self, address: &Addr) -> Result<Self::Response, Self::Error> {
+        self.execute_msgs(vec![DistributionMsg::SetWithdrawAddress {
+            address: address.to_string(),
+        }
+        .into()])
+    }
+}
+
+/// Stargate messages are dispatched on their type url to the registered [`StargateHandler`]s, the memo is ignored.
+#[cfg(feature = "stargate")]
+impl<A: Api, S: StateInterface, M: MockCustomModule> Stargate for MockBase<A, S, M, MockStargate> {
+    fn commit_any(
+        &self,
+        msgs: Vec<prost_types::Any>,
+        _memo: Option<&str>,
+    ) -> Result<Self::Response, Self::Error> {
+        self.execute_msgs(
+            msgs.into_iter()
+                .map(|msg| {
+                    #[allow(deprecated)]
+                    CosmosMsg::Stargate {
+                        type_url: msg.type_url,
+                        value: msg.value.into(),
+                    }
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Pinning has no effect on the cw-multi-test backend, the pinned codes are only recorded.
+impl<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule> SudoHandler
+    for MockBase<A, S, M, G>
+{
+    fn sudo<SudoMsg: Serialize + Debug>(
+        &self,
+        sudo_msg: &SudoMsg,
+        contract_address: &Addr,
+    ) -> Result<Self::Response, Self::Error> {
+        self.metered(
+            GasAction::Sudo,
+            Some(contract_address),
+            &to_json_vec(sudo_msg)?,
+            || {
+                self.app
+                    .borrow_mut()
+                    .wasm_sudo(contract_address.clone(), sudo_msg)
+                    .map_err(From::from)
+            },
+        )
+    }
+
+    fn pin_code(&self, code_id: u64) -> Result<(), Self::Error> {
+        let mut app = self.app.borrow_mut();
+        // Fails if the code doesn't exist
+        app.wrap().query_wasm_code_info(code_id)?;
+        pinned_codes::pin(app.storage_mut(), code_id);
+        Ok(())
+    }
+
+    fn unpin_code(&self, code_id: u64) -> Result<(), Self::Error> {
+        let mut app = self.app.borrow_mut();
+        app.wrap().query_wasm_code_info(code_id)?;
+        pinned_codes::unpin(app.storage_mut(), code_id);
+        Ok(())
+    }
+
+    fn is_pinned(&self, code_id: u64) -> Result<bool, Self::Error> {
+        Ok(pinned_codes::is_pinned(
+            self.app.borrow().storage(),
+            code_id,
+        ))
     }
 }
 
@@ -491,4 +838,278 @@ mod test {
 
         Ok(())
     }
+
+    #[cfg(feature = "staking")]
+    #[test]
+    fn delegator() -> anyhow::Result<()> {
+        use cosmwasm_std::{coin, Decimal, Validator};
+
+        let chain = Mock::new(SENDER);
+        let sender = chain.sender_addr();
+        let validator_1 = chain.addr_make("validator_1").to_string();
+        let validator_2 = chain.addr_make("validator_2").to_string();
+        chain.set_balance(&sender, coins(1_000, "TOKEN"))?;
+
+        let block = chain.app.borrow().block_info();
+        chain
+            .app
+            .borrow_mut()
+            .init_modules(|router, api, storage| {
+                for validator in [&validator_1, &validator_2] {
+                    router.staking.add_validator(
+                        api,
+                        storage,
+                        &block,
+                        Validator::new(
+                            validator.clone(),
+                            Decimal::percent(10),
+                            Decimal::percent(20),
+                            Decimal::percent(1),
+                        ),
+                    )?;
+                }
+                anyhow::Ok(())
+            })?;
+
+        chain.delegate(&validator_1, &coin(600, "TOKEN"))?;
+        chain.redelegate(&validator_1, &validator_2, &coin(200, "TOKEN"))?;
+        chain.undelegate(&validator_1, &coin(100, "TOKEN"))?;
+
+        let delegation = |validator: &str| {
+            chain
+                .app
+                .borrow()
+                .wrap()
+                .query_delegation(&sender, validator)
+                .unwrap()
+                .unwrap()
+                .amount
+        };
+        assert_eq!(delegation(&validator_1), coin(300, "TOKEN"));
+        assert_eq!(delegation(&validator_2), coin(200, "TOKEN"));
+
+        let withdraw_address = chain.addr_make("withdraw");
+        chain.set_withdraw_address(&withdraw_address)?;
+        chain.wait_seconds(60 * 60 * 24 * 365)?;
+        chain.withdraw_all_rewards()?;
+        assert!(!chain.query_all_balances(&withdraw_address)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn sudo_handler() -> anyhow::Result<()> {
+        let chain = MockBech32::new(SENDER);
+
+        fn sudo(_deps: DepsMut, _env: Env, _msg: Empty) -> StdResult<Response> {
+            Ok(Response::new().add_attribute("action", "sudo"))
+        }
+        let contract_source = Box::new(
+            ContractWrapper::new(execute, cw20_base::contract::instantiate, query).with_sudo(sudo),
+        );
+        chain.upload_custom("cw20", contract_source)?;
+        let init_msg = cw20_base::msg::InstantiateMsg {
+            name: String::from("Token"),
+            symbol: String::from("TOK"),
+            decimals: 6u8,
+            initial_balances: vec![],
+            mint: None,
+            marketing: None,
+        };
+        let contract_address = chain
+            .instantiate(1, &init_msg, None, None, &[])?
+            .instantiated_contract_address()?;
+
+        let sudo_res = chain.sudo(&Empty {}, &contract_address)?;
+        assert_eq!(sudo_res.event_attr_value("wasm", "action")?, "sudo");
+
+        assert!(!chain.is_pinned(1)?);
+        chain.pin_code(1)?;
+        assert!(chain.is_pinned(1)?);
+        chain.unpin_code(1)?;
+        assert!(!chain.is_pinned(1)?);
+        // Unknown code
+        assert!(chain.pin_code(2).is_err());
+        Ok(())
+    }
+
+    #[cosmwasm_schema::cw_serde]
+    enum CounterMsg {
+        Increment { amount: u64 },
+    }
+    impl CustomMsg for CounterMsg {}
+
+    #[cosmwasm_schema::cw_serde]
+    enum CounterQuery {
+        Count {},
+    }
+    impl CustomQuery for CounterQuery {}
+
+    /// Chain module keeping a counter, incremented by custom messages
+    struct CounterModule;
+
+    impl CounterModule {
+        fn count(storage: &dyn cosmwasm_std::Storage) -> u64 {
+            storage
+                .get(b"count")
+                .map(|count| u64::from_be_bytes(count.try_into().unwrap()))
+                .unwrap_or_default()
+        }
+    }
+
+    impl Module for CounterModule {
+        type ExecT = CounterMsg;
+        type QueryT = CounterQuery;
+        type SudoT = Empty;
+
+        fn execute<ExecC, QueryC>(
+            &self,
+            _api: &dyn Api,
+            storage: &mut dyn cosmwasm_std::Storage,
+            _router: &dyn cw_multi_test::CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+            _block: &cosmwasm_std::BlockInfo,
+            _sender: Addr,
+            msg: CounterMsg,
+        ) -> anyhow::Result<AppResponse>
+        where
+            ExecC: CustomMsg + DeserializeOwned + 'static,
+            QueryC: CustomQuery + DeserializeOwned + 'static,
+        {
+            let CounterMsg::Increment { amount } = msg;
+            let count = Self::count(storage) + amount;
+            storage.set(b"count", &count.to_be_bytes());
+            Ok(AppResponse::default())
+        }
+
+        fn query(
+            &self,
+            _api: &dyn Api,
+            storage: &dyn cosmwasm_std::Storage,
+            _querier: &dyn cosmwasm_std::Querier,
+            _block: &cosmwasm_std::BlockInfo,
+            _request: CounterQuery,
+        ) -> anyhow::Result<Binary> {
+            Ok(to_json_binary(&Self::count(storage))?)
+        }
+
+        fn sudo<ExecC, QueryC>(
+            &self,
+            _api: &dyn Api,
+            _storage: &mut dyn cosmwasm_std::Storage,
+            _router: &dyn cw_multi_test::CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+            _block: &cosmwasm_std::BlockInfo,
+            _msg: Empty,
+        ) -> anyhow::Result<AppResponse>
+        where
+            ExecC: CustomMsg + DeserializeOwned + 'static,
+            QueryC: CustomQuery + DeserializeOwned + 'static,
+        {
+            anyhow::bail!("the counter module has no sudo messages")
+        }
+    }
+
+    fn counter_execute(
+        _deps: DepsMut<CounterQuery>,
+        _env: Env,
+        _info: MessageInfo,
+        amount: u64,
+    ) -> StdResult<Response<CounterMsg>> {
+        Ok(Response::new().add_message(CosmosMsg::Custom(CounterMsg::Increment { amount })))
+    }
+
+    fn counter_instantiate(
+        _deps: DepsMut<CounterQuery>,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response<CounterMsg>> {
+        Ok(Response::new())
+    }
+
+    fn counter_query(deps: Deps<CounterQuery>, _env: Env, _msg: Empty) -> StdResult<Binary> {
+        let count: u64 = deps
+            .querier
+            .query(&cosmwasm_std::QueryRequest::Custom(CounterQuery::Count {}))?;
+        to_json_binary(&count)
+    }
+
+    impl MockCustomModule for CounterModule {}
+
+    type CounterMock = MockBech32<MockState, CounterModule>;
+
+    struct CounterContract(cw_orch_core::contract::Contract<CounterMock>);
+
+    impl ContractInstance<CounterMock> for CounterContract {
+        fn as_instance(&self) -> &cw_orch_core::contract::Contract<CounterMock> {
+            &self.0
+        }
+
+        fn as_instance_mut(&mut self) -> &mut cw_orch_core::contract::Contract<CounterMock> {
+            &mut self.0
+        }
+    }
+
+    impl CustomUploadable<CounterMsg, CounterQuery> for CounterContract {
+        fn custom_wrapper() -> Box<dyn Contract<CounterMsg, CounterQuery>> {
+            Box::new(ContractWrapper::new(
+                counter_execute,
+                counter_instantiate,
+                counter_query,
+            ))
+        }
+    }
+
+    struct Cw20Contract;
+
+    impl Uploadable for Cw20Contract {
+        fn wrapper() -> Box<dyn Contract<Empty, Empty>> {
+            Box::new(ContractWrapper::new(
+                execute,
+                cw20_base::contract::instantiate,
+                query,
+            ))
+        }
+    }
+
+    #[test]
+    fn custom_module() -> anyhow::Result<()> {
+        let chain = MockBech32::new_custom_module(SENDER, MockState::new(), CounterModule);
+
+        let counter = CounterContract(cw_orch_core::contract::Contract::new(
+            "counter",
+            chain.clone(),
+        ));
+        chain.upload_custom_contract(&counter)?;
+        let contract_address = chain
+            .instantiate(counter.code_id()?, &Empty {}, None, None, &[])?
+            .instantiated_contract_address()?;
+
+        chain.execute(&5u64, &[], &contract_address)?;
+        chain.execute(&2u64, &[], &contract_address)?;
+        let count: u64 = chain.query(&Empty {}, &contract_address)?;
+        assert_eq!(count, 7);
+
+        // Contracts without custom messages can be used on custom environments
+        let code_id = chain.upload(&Cw20Contract)?.uploaded_code_id()?;
+        let init_msg = cw20_base::msg::InstantiateMsg {
+            name: String::from("Token"),
+            symbol: String::from("TOK"),
+            decimals: 6u8,
+            initial_balances: vec![],
+            mint: None,
+            marketing: None,
+        };
+        let cw20_address = chain
+            .instantiate(code_id, &init_msg, None, None, &[])?
+            .instantiated_contract_address()?;
+        let mint_res = chain.execute(
+            &cw20::Cw20ExecuteMsg::Mint {
+                recipient: chain.sender_addr().to_string(),
+                amount: 100u128.into(),
+            },
+            &[],
+            &cw20_address,
+        )?;
+        assert_eq!(mint_res.event_attr_value("wasm", "action")?, "mint");
+        Ok(())
+    }
 }
@@ -0,0 +1,486 @@
+//! Approximate gas metering of the mock environments.
+//!
+//! cw-multi-test runs the contracts natively, so their wasm execution can't be metered.
+//! The gas of a call is approximated with the costs charged by the Cosmos SDK and wasmd:
+//! - the storage reads, writes, deletions and iterations of the call (Cosmos SDK KV store costs),
+//! - a flat instance cost for every contract called, including sub-messages and replies,
+//! - the attributes of the contract events,
+//! - the size of the message.
+//!
+//! Compute costs of the contracts are not accounted, compare the reports between versions of a contract
+//! rather than with the gas used on a chain.
+//!
+//! cw-multi-test executes a transaction on a cache of the storage: its writes reach [`MeteredStorage`] and are charged
+//! only when the transaction is committed, and reads of keys it already wrote are served by the cache and not charged.
+
+use std::{
+    cell::{Cell, RefCell},
+    fmt::{self, Display},
+};
+
+use cosmwasm_std::{testing::MockStorage, Addr, Api, Order, Record, Storage};
+use cw_multi_test::AppResponse;
+use cw_orch_core::{
+    environment::{IndexResponse, StateInterface},
+    CwEnvError,
+};
+use serde::Serialize;
+
+use crate::{core::MockCustomModule, stargate::MockStargateModule, MockBase};
+
+/// Gas costs of the mock environments, the defaults are the ones of the Cosmos SDK and wasmd
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GasConfig {
+    pub read_cost_flat: u64,
+    pub read_cost_per_byte: u64,
+    pub write_cost_flat: u64,
+    pub write_cost_per_byte: u64,
+    pub delete_cost: u64,
+    pub iter_next_cost_flat: u64,
+    /// Cost of every contract call
+    pub instance_cost: u64,
+    pub event_attribute_cost: u64,
+    pub event_attribute_byte_cost: u64,
+    /// Cost per byte of the JSON encoded message
+    pub msg_byte_cost: u64,
+}
+
+impl Default for GasConfig {
+    fn default() -> Self {
+        Self {
+            read_cost_flat: 1000,
+            read_cost_per_byte: 3,
+            write_cost_flat: 2000,
+            write_cost_per_byte: 30,
+            delete_cost: 1000,
+            iter_next_cost_flat: 30,
+            instance_cost: 60_000,
+            event_attribute_cost: 10,
+            event_attribute_byte_cost: 1,
+            msg_byte_cost: 10,
+        }
+    }
+}
+
+/// Kind of metered call
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GasAction {
+    Instantiate,
+    Execute,
+    Migrate,
+    Sudo,
+    BankSend,
+    /// Messages sent with the `Stargate` or `Delegator` traits
+    Messages,
+}
+
+impl Display for GasAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let action = match self {
+            GasAction::Instantiate => "instantiate",
+            GasAction::Execute => "execute",
+            GasAction::Migrate => "migrate",
+            GasAction::Sudo => "sudo",
+            GasAction::BankSend => "bank_send",
+            GasAction::Messages => "messages",
+        };
+        write!(f, "{action}")
+    }
+}
+
+#[derive(Clone, Debug)]
+struct GasRecord {
+    action: GasAction,
+    contract: Option<Addr>,
+    gas_used: u64,
+}
+
+/// Gas used by a call
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct GasReportEntry {
+    /// Contract id of the called contract, its address if it's not registered in the state
+    pub contract_id: String,
+    pub action: GasAction,
+    pub gas_used: u64,
+}
+
+/// Gas used by the metered calls, in call order
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct GasReport {
+    pub entries: Vec<GasReportEntry>,
+    pub total: u64,
+}
+
+impl Display for GasReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<30} {:<12} {:>14}", "contract", "action", "gas used")?;
+        for entry in &self.entries {
+            writeln!(
+                f,
+                "{:<30} {:<12} {:>14}",
+                entry.contract_id,
+                entry.action.to_string(),
+                entry.gas_used
+            )?;
+        }
+        writeln!(f, "{:<30} {:<12} {:>14}", "total", "", self.total)
+    }
+}
+
+/// Gas meter of the storage, disabled by default
+#[derive(Default)]
+pub(crate) struct GasMeter {
+    config: Cell<Option<GasConfig>>,
+    consumed: Cell<u64>,
+    records: RefCell<Vec<GasRecord>>,
+}
+
+impl GasMeter {
+    fn consume(&self, cost: impl FnOnce(&GasConfig) -> u64) {
+        if let Some(config) = self.config.get() {
+            self.consumed.set(self.consumed.get() + cost(&config));
+        }
+    }
+
+    /// Resets the gas consumed, returns whether metering is enabled
+    fn start(&self) -> bool {
+        self.consumed.set(0);
+        self.config.get().is_some()
+    }
+
+    fn finish(
+        &self,
+        action: GasAction,
+        contract: Option<Addr>,
+        msg_len: usize,
+        response: &AppResponse,
+    ) {
+        let Some(config) = self.config.get() else {
+            return;
+        };
+        let mut gas_used = self.consumed.get() + msg_len as u64 * config.msg_byte_cost;
+        for event in &response.events {
+            match event.ty.as_str() {
+                "instantiate" | "execute" | "migrate" | "sudo" | "reply" => {
+                    gas_used += config.instance_cost
+                }
+                ty if ty == "wasm" || ty.starts_with("wasm-") => {
+                    for attribute in &event.attributes {
+                        gas_used += config.event_attribute_cost
+                            + (attribute.key.len() + attribute.value.len()) as u64
+                                * config.event_attribute_byte_cost;
+                    }
+                }
+                _ => {}
+            }
+        }
+        self.records.borrow_mut().push(GasRecord {
+            action,
+            contract,
+            gas_used,
+        });
+    }
+}
+
+/// Storage of the mock environments, charging gas for its operations when gas metering is enabled
+#[derive(Default)]
+pub struct MeteredStorage {
+    storage: MockStorage,
+    pub(crate) meter: GasMeter,
+}
+
+impl Storage for MeteredStorage {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        let value = self.storage.get(key);
+        self.meter.consume(|config| {
+            config.read_cost_flat
+                + (key.len() + value.as_ref().map(Vec::len).unwrap_or_default()) as u64
+                    * config.read_cost_per_byte
+        });
+        value
+    }
+
+    fn range<'a>(
+        &'a self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'a> {
+        Box::new(
+            self.storage
+                .range(start, end, order)
+                .inspect(|(key, value)| {
+                    self.meter.consume(|config| {
+                        config.iter_next_cost_flat
+                            + (key.len() + value.len()) as u64 * config.read_cost_per_byte
+                    })
+                }),
+        )
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.meter.consume(|config| {
+            config.write_cost_flat + (key.len() + value.len()) as u64 * config.write_cost_per_byte
+        });
+        self.storage.set(key, value)
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.meter.consume(|config| config.delete_cost);
+        self.storage.remove(key)
+    }
+}
+
+impl<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule> MockBase<A, S, M, G> {
+    /// Meters the gas of the calls made on this environment and its clones.
+    /// See the [`gas`](crate::gas) module for the costs taken into account.
+    pub fn enable_gas_metering(&self, config: GasConfig) {
+        self.app.borrow().storage().meter.config.set(Some(config));
+    }
+
+    pub fn disable_gas_metering(&self) {
+        self.app.borrow().storage().meter.config.set(None);
+    }
+
+    /// Gas used by the calls metered so far
+    pub fn gas_report(&self) -> GasReport {
+        let app = self.app.borrow();
+        let records = app.storage().meter.records.borrow();
+        self.report(&records)
+    }
+
+    /// Clears the gas report
+    pub fn reset_gas_report(&self) {
+        self.app
+            .borrow()
+            .storage()
+            .meter
+            .records
+            .borrow_mut()
+            .clear();
+    }
+
+    /// Gas used by the last metered call
+    pub fn last_gas_used(&self) -> Option<u64> {
+        let app = self.app.borrow();
+        let records = app.storage().meter.records.borrow();
+        records.last().map(|record| record.gas_used)
+    }
+
+    /// Runs `f` and panics if the calls it made used more than `budget` gas.
+    ///
+    /// ```
+    /// # use cw_orch_mock::{gas::GasConfig, Mock};
+    /// # use cw_orch_core::environment::TxHandler;
+    /// # use cosmwasm_std::coins;
+    /// let mock = Mock::new("sender");
+    /// mock.enable_gas_metering(GasConfig::default());
+    /// mock.set_balance(&mock.sender_addr(), coins(100, "token")).unwrap();
+    ///
+    /// let receiver = mock.addr_make("receiver");
+    /// mock.assert_gas_budget(100_000, || {
+    ///     mock.bank_send(&receiver, &coins(10, "token")).unwrap();
+    /// });
+    /// ```
+    pub fn assert_gas_budget<T>(&self, budget: u64, f: impl FnOnce() -> T) -> T {
+        let first = {
+            let app = self.app.borrow();
+            let meter = &app.storage().meter;
+            assert!(
+                meter.config.get().is_some(),
+                "gas metering is not enabled, call `enable_gas_metering` first"
+            );
+            let records = meter.records.borrow();
+            records.len()
+        };
+        let result = f();
+        let report = {
+            let app = self.app.borrow();
+            let records = app.storage().meter.records.borrow();
+            self.report(&records[first.min(records.len())..])
+        };
+        assert!(
+            report.total <= budget,
+            "gas budget of {budget} exceeded, {} gas used:\n{report}",
+            report.total
+        );
+        result
+    }
+
+    /// Meters the gas of `call` if gas metering is enabled
+    pub(crate) fn metered(
+        &self,
+        action: GasAction,
+        contract: Option<&Addr>,
+        msg: &[u8],
+        call: impl FnOnce() -> Result<AppResponse, CwEnvError>,
+    ) -> Result<AppResponse, CwEnvError> {
+        if !self.app.borrow().storage().meter.start() {
+            return call();
+        }
+        let response = call()?;
+        let contract = match action {
+            GasAction::Instantiate => response.instantiated_contract_address().ok(),
+            _ => contract.cloned(),
+        };
+        self.app
+            .borrow()
+            .storage()
+            .meter
+            .finish(action, contract, msg.len(), &response);
+        Ok(response)
+    }
+
+    fn report(&self, records: &[GasRecord]) -> GasReport {
+        let addresses = self.state.get_all_addresses().unwrap_or_default();
+        let entries: Vec<_> = records
+            .iter()
+            .map(|record| GasReportEntry {
+                contract_id: match &record.contract {
+                    Some(contract) => addresses
+                        .iter()
+                        .find(|(_, address)| *address == contract)
+                        .map(|(contract_id, _)| contract_id.clone())
+                        .unwrap_or_else(|| contract.to_string()),
+                    None => "-".to_string(),
+                },
+                action: record.action,
+                gas_used: record.gas_used,
+            })
+            .collect();
+        let total = entries.iter().map(|entry| entry.gas_used).sum();
+        GasReport { entries, total }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{coins, Uint128};
+    use cw20::{Cw20Coin, Cw20ExecuteMsg};
+    use cw_multi_test::ContractWrapper;
+    use cw_orch_core::environment::{IndexResponse, StateInterface, TxHandler};
+
+    use super::{GasAction, GasConfig};
+    use crate::Mock;
+
+    fn cw20(chain: &Mock) -> anyhow::Result<cosmwasm_std::Addr> {
+        chain.upload_custom(
+            "cw20",
+            Box::new(ContractWrapper::new(
+                cw20_base::contract::execute,
+                cw20_base::contract::instantiate,
+                cw20_base::contract::query,
+            )),
+        )?;
+        let code_id = chain.state.borrow().get_code_id("cw20")?;
+        let response = chain.instantiate(
+            code_id,
+            &cw20_base::msg::InstantiateMsg {
+                name: "Token".to_string(),
+                symbol: "TOK".to_string(),
+                decimals: 6,
+                initial_balances: vec![Cw20Coin {
+                    address: chain.sender_addr().to_string(),
+                    amount: Uint128::new(1000),
+                }],
+                mint: None,
+                marketing: None,
+            },
+            None,
+            None,
+            &[],
+        )?;
+        let address = response.instantiated_contract_address()?;
+        chain.state.borrow_mut().set_address("cw20", &address);
+        Ok(address)
+    }
+
+    fn transfer(chain: &Mock, cw20: &cosmwasm_std::Addr, amount: u128) -> anyhow::Result<()> {
+        chain.execute(
+            &Cw20ExecuteMsg::Transfer {
+                recipient: chain.addr_make("receiver").to_string(),
+                amount: Uint128::new(amount),
+            },
+            &[],
+            cw20,
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn gas_report() -> anyhow::Result<()> {
+        let chain = Mock::new("sender");
+        let cw20 = cw20(&chain)?;
+        transfer(&chain, &cw20, 10)?;
+        assert!(chain.gas_report().entries.is_empty());
+        assert_eq!(chain.last_gas_used(), None);
+
+        chain.enable_gas_metering(GasConfig::default());
+        transfer(&chain, &cw20, 10)?;
+        transfer(&chain, &cw20, 10)?;
+        chain.set_balance(&chain.sender_addr(), coins(100, "token"))?;
+        chain.bank_send(&chain.addr_make("receiver"), &coins(10, "token"))?;
+
+        let report = chain.gas_report();
+        assert_eq!(report.entries.len(), 3);
+        assert_eq!(report.entries[0].contract_id, "cw20");
+        assert_eq!(report.entries[0].action, GasAction::Execute);
+        // Same storage accesses
+        assert_eq!(report.entries[0].gas_used, report.entries[1].gas_used);
+        assert!(report.entries[0].gas_used > GasConfig::default().instance_cost);
+        assert_eq!(report.entries[2].contract_id, "-");
+        assert_eq!(report.entries[2].action, GasAction::BankSend);
+        assert_eq!(
+            report.total,
+            report
+                .entries
+                .iter()
+                .map(|entry| entry.gas_used)
+                .sum::<u64>()
+        );
+        assert_eq!(chain.last_gas_used(), Some(report.entries[2].gas_used));
+        assert!(report.to_string().contains("cw20"));
+
+        // A more expensive instance cost is reflected in the gas used
+        chain.enable_gas_metering(GasConfig {
+            instance_cost: 100_000,
+            ..Default::default()
+        });
+        transfer(&chain, &cw20, 10)?;
+        assert_eq!(
+            chain.last_gas_used(),
+            Some(report.entries[0].gas_used + 40_000)
+        );
+
+        chain.reset_gas_report();
+        assert!(chain.gas_report().entries.is_empty());
+        chain.disable_gas_metering();
+        transfer(&chain, &cw20, 10)?;
+        assert!(chain.gas_report().entries.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn gas_budget() -> anyhow::Result<()> {
+        let chain = Mock::new("sender");
+        let cw20 = cw20(&chain)?;
+        chain.enable_gas_metering(GasConfig::default());
+
+        chain.assert_gas_budget(200_000, || transfer(&chain, &cw20, 10))?;
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "gas budget of 100000 exceeded")]
+    fn gas_budget_exceeded() {
+        let chain = Mock::new("sender");
+        let cw20 = cw20(&chain).unwrap();
+        chain.enable_gas_metering(GasConfig::default());
+
+        chain.assert_gas_budget(100_000, || {
+            transfer(&chain, &cw20, 10).unwrap();
+            transfer(&chain, &cw20, 10).unwrap();
+        });
+    }
+}
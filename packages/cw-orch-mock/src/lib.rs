@@ -6,11 +6,17 @@ pub extern crate cw_multi_test;
 
 mod bech32;
 mod core;
+pub mod gas;
+pub mod pinned_codes;
 pub mod queriers;
 mod simple;
+pub mod snapshot;
+pub mod stargate;
 mod state;
 
-pub use self::core::{Mock, MockBase, MockBech32};
+pub use self::core::{
+    FailingCustomModule, Mock, MockBase, MockBech32, MockContractSource, MockCustomModule,
+};
 
 pub type MockApp = self::core::MockApp<MockApi>;
 pub type MockAppBech32 = self::core::MockApp<MockApiBech32>;
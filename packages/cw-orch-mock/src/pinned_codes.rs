@@ -0,0 +1,25 @@
+//! Pinned codes of the local environments.
+//! cw-multi-test has no wasm cache, so the pinned code ids are only recorded in the app storage.
+
+use cosmwasm_std::Storage;
+
+const PINNED_CODES_PREFIX: &[u8] = b"cw-orch-pinned-codes";
+
+fn key(code_id: u64) -> Vec<u8> {
+    [PINNED_CODES_PREFIX, &code_id.to_be_bytes()].concat()
+}
+
+/// Records the code as pinned
+pub fn pin(storage: &mut dyn Storage, code_id: u64) {
+    storage.set(&key(code_id), &[1]);
+}
+
+/// Records the code as unpinned
+pub fn unpin(storage: &mut dyn Storage, code_id: u64) {
+    storage.remove(&key(code_id));
+}
+
+/// Whether the code is recorded as pinned
+pub fn is_pinned(storage: &dyn Storage, code_id: u64) -> bool {
+    storage.get(&key(code_id)).is_some()
+}
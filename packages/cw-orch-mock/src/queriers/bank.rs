@@ -1,6 +1,7 @@
 use std::{cell::RefCell, rc::Rc};
 
 use cosmwasm_std::{Addr, Api, Coin};
+use cw_multi_test::StargateFailing;
 use cw_orch_core::{
     environment::{
         QuerierGetter, StateInterface, {BankQuerier, Querier},
@@ -8,31 +9,41 @@ use cw_orch_core::{
     CwEnvError,
 };
 
-use crate::{core::MockApp, MockBase};
+use crate::{
+    core::{FailingCustomModule, MockApp},
+    stargate::MockStargateModule,
+    MockBase, MockCustomModule,
+};
 
-pub struct MockBankQuerier<A> {
-    app: Rc<RefCell<MockApp<A>>>,
+pub struct MockBankQuerier<
+    A,
+    M: MockCustomModule = FailingCustomModule,
+    G: MockStargateModule = StargateFailing,
+> {
+    app: Rc<RefCell<MockApp<A, M, G>>>,
 }
 
-impl<A: Api> MockBankQuerier<A> {
-    fn new<S: StateInterface>(mock: &MockBase<A, S>) -> Self {
+impl<A: Api, M: MockCustomModule, G: MockStargateModule> MockBankQuerier<A, M, G> {
+    fn new<S: StateInterface>(mock: &MockBase<A, S, M, G>) -> Self {
         Self {
             app: mock.app.clone(),
         }
     }
 }
 
-impl<A: Api, S: StateInterface> QuerierGetter<MockBankQuerier<A>> for MockBase<A, S> {
-    fn querier(&self) -> MockBankQuerier<A> {
+impl<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule>
+    QuerierGetter<MockBankQuerier<A, M, G>> for MockBase<A, S, M, G>
+{
+    fn querier(&self) -> MockBankQuerier<A, M, G> {
         MockBankQuerier::new(self)
     }
 }
 
-impl<A: Api> Querier for MockBankQuerier<A> {
+impl<A: Api, M: MockCustomModule, G: MockStargateModule> Querier for MockBankQuerier<A, M, G> {
     type Error = CwEnvError;
 }
 
-impl<A: Api> BankQuerier for MockBankQuerier<A> {
+impl<A: Api, M: MockCustomModule, G: MockStargateModule> BankQuerier for MockBankQuerier<A, M, G> {
     fn balance(
         &self,
         address: &Addr,
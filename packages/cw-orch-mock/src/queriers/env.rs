@@ -3,9 +3,11 @@ use cw_orch_core::environment::{
     EnvironmentInfo, EnvironmentQuerier, QueryHandler, StateInterface,
 };
 
-use crate::MockBase;
+use crate::{stargate::MockStargateModule, MockBase, MockCustomModule};
 
-impl<A: Api, S: StateInterface> EnvironmentQuerier for MockBase<A, S> {
+impl<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule> EnvironmentQuerier
+    for MockBase<A, S, M, G>
+{
     fn env_info(&self) -> EnvironmentInfo {
         let block_info = self.block_info().unwrap();
         let chain_id = block_info.chain_id.clone();
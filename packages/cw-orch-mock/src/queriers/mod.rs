@@ -1,4 +1,4 @@
-use crate::MockBase;
+use crate::{stargate::MockStargateModule, MockBase, MockCustomModule};
 
 use cosmwasm_std::Api;
 use cw_multi_test::next_block;
@@ -12,7 +12,9 @@ mod env;
 pub mod node;
 pub mod wasm;
 
-impl<A: Api, S: StateInterface> QueryHandler for MockBase<A, S> {
+impl<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule> QueryHandler
+    for MockBase<A, S, M, G>
+{
     type Error = CwEnvError;
 
     fn wait_blocks(&self, amount: u64) -> Result<(), CwEnvError> {
@@ -37,8 +39,10 @@ impl<A: Api, S: StateInterface> QueryHandler for MockBase<A, S> {
     }
 }
 
-impl<A: Api, S: StateInterface> DefaultQueriers for MockBase<A, S> {
-    type Bank = bank::MockBankQuerier<A>;
-    type Wasm = wasm::MockWasmQuerier<A, S>;
-    type Node = node::MockNodeQuerier<A>;
+impl<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule> DefaultQueriers
+    for MockBase<A, S, M, G>
+{
+    type Bank = bank::MockBankQuerier<A, M, G>;
+    type Wasm = wasm::MockWasmQuerier<A, S, M, G>;
+    type Node = node::MockNodeQuerier<A, M, G>;
 }
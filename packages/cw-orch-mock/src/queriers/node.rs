@@ -1,37 +1,47 @@
 use std::{cell::RefCell, rc::Rc};
 
 use cosmwasm_std::Api;
-use cw_multi_test::AppResponse;
+use cw_multi_test::{AppResponse, StargateFailing};
 use cw_orch_core::{
     environment::{NodeQuerier, Querier, QuerierGetter, StateInterface},
     CwEnvError,
 };
 
-use crate::{core::MockApp, MockBase};
+use crate::{
+    core::{FailingCustomModule, MockApp},
+    stargate::MockStargateModule,
+    MockBase, MockCustomModule,
+};
 
-pub struct MockNodeQuerier<A: Api> {
-    app: Rc<RefCell<MockApp<A>>>,
+pub struct MockNodeQuerier<
+    A: Api,
+    M: MockCustomModule = FailingCustomModule,
+    G: MockStargateModule = StargateFailing,
+> {
+    app: Rc<RefCell<MockApp<A, M, G>>>,
 }
 
-impl<A: Api> MockNodeQuerier<A> {
-    fn new<S: StateInterface>(mock: &MockBase<A, S>) -> Self {
+impl<A: Api, M: MockCustomModule, G: MockStargateModule> MockNodeQuerier<A, M, G> {
+    fn new<S: StateInterface>(mock: &MockBase<A, S, M, G>) -> Self {
         Self {
             app: mock.app.clone(),
         }
     }
 }
 
-impl<A: Api> Querier for MockNodeQuerier<A> {
+impl<A: Api, M: MockCustomModule, G: MockStargateModule> Querier for MockNodeQuerier<A, M, G> {
     type Error = CwEnvError;
 }
 
-impl<A: Api, S: StateInterface> QuerierGetter<MockNodeQuerier<A>> for MockBase<A, S> {
-    fn querier(&self) -> MockNodeQuerier<A> {
+impl<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule>
+    QuerierGetter<MockNodeQuerier<A, M, G>> for MockBase<A, S, M, G>
+{
+    fn querier(&self) -> MockNodeQuerier<A, M, G> {
         MockNodeQuerier::new(self)
     }
 }
 
-impl<A: Api> NodeQuerier for MockNodeQuerier<A> {
+impl<A: Api, M: MockCustomModule, G: MockStargateModule> NodeQuerier for MockNodeQuerier<A, M, G> {
     type Response = AppResponse;
 
     fn latest_block(&self) -> Result<cosmwasm_std::BlockInfo, Self::Error> {
@@ -5,6 +5,7 @@ use cosmwasm_std::{
     instantiate2_address, Addr, Api, Binary, Checksum, ContractResult, StdError, SystemResult,
 };
 use cosmwasm_std::{to_json_binary, ContractInfoResponse};
+use cw_multi_test::StargateFailing;
 use cw_orch_core::{
     contract::interface_traits::{ContractInstance, Uploadable},
     environment::{Querier, QuerierGetter, QueryHandler, StateInterface, TxHandler, WasmQuerier},
@@ -13,15 +14,26 @@ use cw_orch_core::{
 use serde::{de::DeserializeOwned, Serialize};
 use sha2::{Digest, Sha256};
 
-use crate::{core::MockApp, MockBase};
+use crate::{
+    core::{FailingCustomModule, MockApp},
+    stargate::MockStargateModule,
+    MockBase, MockCustomModule,
+};
 
-pub struct MockWasmQuerier<A: Api, S: StateInterface> {
-    app: Rc<RefCell<MockApp<A>>>,
+pub struct MockWasmQuerier<
+    A: Api,
+    S: StateInterface,
+    M: MockCustomModule = FailingCustomModule,
+    G: MockStargateModule = StargateFailing,
+> {
+    app: Rc<RefCell<MockApp<A, M, G>>>,
     _state: PhantomData<S>,
 }
 
-impl<A: Api, S: StateInterface> MockWasmQuerier<A, S> {
-    fn new(mock: &MockBase<A, S>) -> Self {
+impl<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule>
+    MockWasmQuerier<A, S, M, G>
+{
+    fn new(mock: &MockBase<A, S, M, G>) -> Self {
         Self {
             app: mock.app.clone(),
             _state: PhantomData,
@@ -29,26 +41,30 @@ impl<A: Api, S: StateInterface> MockWasmQuerier<A, S> {
     }
 }
 
-impl<A: Api, S: StateInterface> Querier for MockWasmQuerier<A, S> {
+impl<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule> Querier
+    for MockWasmQuerier<A, S, M, G>
+{
     type Error = CwEnvError;
 }
 
-impl<A: Api, S: StateInterface> QuerierGetter<MockWasmQuerier<A, S>> for MockBase<A, S> {
-    fn querier(&self) -> MockWasmQuerier<A, S> {
+impl<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule>
+    QuerierGetter<MockWasmQuerier<A, S, M, G>> for MockBase<A, S, M, G>
+{
+    fn querier(&self) -> MockWasmQuerier<A, S, M, G> {
         MockWasmQuerier::new(self)
     }
 }
 
-fn code_id_hash<A: Api, S: StateInterface>(
-    querier: &MockWasmQuerier<A, S>,
+fn code_id_hash<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule>(
+    querier: &MockWasmQuerier<A, S, M, G>,
     code_id: u64,
 ) -> Result<Checksum, CwEnvError> {
     let code_info = querier.app.borrow().wrap().query_wasm_code_info(code_id)?;
     Ok(code_info.checksum)
 }
 
-fn contract_info<A: Api, S: StateInterface>(
-    querier: &MockWasmQuerier<A, S>,
+fn contract_info<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule>(
+    querier: &MockWasmQuerier<A, S, M, G>,
     address: &Addr,
 ) -> Result<ContractInfoResponse, CwEnvError> {
     let info = querier
@@ -69,8 +85,8 @@ fn local_hash<Chain: TxHandler + QueryHandler, T: Uploadable + ContractInstance<
 }
 
 /// Copied implementation from [`cosmwasm_std::QuerierWrapper::query`] but without deserialization
-fn raw_query<A: Api, S: StateInterface>(
-    querier: &MockWasmQuerier<A, S>,
+fn raw_query<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule>(
+    querier: &MockWasmQuerier<A, S, M, G>,
     address: &Addr,
     query_data: Vec<u8>,
 ) -> Result<Vec<u8>, CwEnvError> {
@@ -95,8 +111,8 @@ fn raw_query<A: Api, S: StateInterface>(
     Ok(res?.to_vec())
 }
 
-fn smart_query<A: Api, S: StateInterface, Q, T>(
-    querier: &MockWasmQuerier<A, S>,
+fn smart_query<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule, Q, T>(
+    querier: &MockWasmQuerier<A, S, M, G>,
     address: &Addr,
     query_data: &Q,
 ) -> Result<T, CwEnvError>
@@ -116,8 +132,8 @@ where
         ))?)
 }
 
-fn code<A: Api, S: StateInterface>(
-    querier: &MockWasmQuerier<A, S>,
+fn code<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule>(
+    querier: &MockWasmQuerier<A, S, M, G>,
     code_id: u64,
 ) -> Result<cosmwasm_std::CodeInfoResponse, CwEnvError> {
     Ok(querier
@@ -129,8 +145,10 @@ fn code<A: Api, S: StateInterface>(
         ))?)
 }
 
-impl<A: Api, S: StateInterface> WasmQuerier for MockWasmQuerier<A, S> {
-    type Chain = MockBase<A, S>;
+impl<A: Api, S: StateInterface, M: MockCustomModule, G: MockStargateModule> WasmQuerier
+    for MockWasmQuerier<A, S, M, G>
+{
+    type Chain = MockBase<A, S, M, G>;
     /// Returns the hex-encoded checksum of the code.
     fn code_id_hash(&self, code_id: u64) -> Result<Checksum, CwEnvError> {
         code_id_hash(self, code_id)
@@ -3,7 +3,7 @@ use std::rc::Rc;
 
 use cosmwasm_std::testing::MockApi;
 use cosmwasm_std::{Addr, Coin, Uint128};
-use cw_multi_test::AppBuilder;
+use cw_multi_test::{AppBuilder, FailingModule, StargateFailing};
 use cw_orch_core::environment::{BankQuerier, BankSetter, TxHandler};
 use cw_orch_core::{
     environment::{DefaultQueriers, StateInterface},
@@ -11,10 +11,12 @@ use cw_orch_core::{
 };
 use cw_utils::NativeBalance;
 
+use crate::gas::MeteredStorage;
 use crate::queriers::bank::MockBankQuerier;
-use crate::{Mock, MockState};
+use crate::stargate::{MockStargate, MockStargateModule};
+use crate::{FailingCustomModule, Mock, MockCustomModule, MockState};
 
-impl<S: StateInterface> Mock<S> {
+impl<S: StateInterface, M: MockCustomModule, G: MockStargateModule> Mock<S, M, G> {
     /// Set the bank balance of an address.
     pub fn set_balance(
         &self,
@@ -100,22 +102,64 @@ impl Mock {
         chain
     }
 }
+
+impl Mock<MockState, FailingCustomModule, MockStargate> {
+    /// Create a mock environment with the default mock state that handles stargate messages with the [`MockStargate`] module.
+    pub fn new_with_stargate(sender: impl Into<String>) -> Self {
+        Mock::new_with_modules(
+            sender,
+            MockState::new(),
+            FailingModule::new(),
+            MockStargate::new(),
+        )
+    }
+}
+
 impl<S: StateInterface> Mock<S> {
     /// Create a mock environment with a custom mock state.
     /// The state is customizable by implementing the `StateInterface` trait on a custom struct and providing it on the custom constructor.
     pub fn new_custom(sender: impl Into<String>, custom_state: S) -> Self {
+        Mock::new_custom_module(sender, custom_state, FailingModule::new())
+    }
+}
+
+impl<S: StateInterface, M: MockCustomModule> Mock<S, M> {
+    /// Create a mock environment with a custom mock state and a custom module.
+    /// The custom module handles the `CosmosMsg::Custom` messages and `QueryRequest::Custom` queries of the contracts.
+    pub fn new_custom_module(sender: impl Into<String>, custom_state: S, custom_module: M) -> Self {
+        Mock::new_with_modules(sender, custom_state, custom_module, StargateFailing)
+    }
+}
+
+impl<S: StateInterface, M: MockCustomModule, G: MockStargateModule> Mock<S, M, G> {
+    /// Create a mock environment with a custom mock state, a custom module and a stargate module.
+    pub fn new_with_modules(
+        sender: impl Into<String>,
+        custom_state: S,
+        custom_module: M,
+        stargate: G,
+    ) -> Self {
         let state = Rc::new(RefCell::new(custom_state));
-        let app = AppBuilder::new_custom().build(|_, _, _| {});
+        let app = AppBuilder::new_custom()
+            .with_custom(custom_module)
+            .with_stargate(stargate)
+            .with_storage(MeteredStorage::default())
+            .build(|_, _, _| {});
         let sender: String = sender.into();
         let sender = app.api().addr_make(&sender);
         let app = Rc::new(RefCell::new(app));
 
-        Self { sender, state, app }
+        Self {
+            sender,
+            state,
+            app,
+            code_factories: Default::default(),
+        }
     }
 }
 
-impl<S: StateInterface> BankSetter for Mock<S> {
-    type T = MockBankQuerier<MockApi>;
+impl<S: StateInterface, M: MockCustomModule, G: MockStargateModule> BankSetter for Mock<S, M, G> {
+    type T = MockBankQuerier<MockApi, M, G>;
 
     fn set_balance(
         &mut self,
@@ -0,0 +1,189 @@
+//! Snapshots of the mock environments.
+//!
+//! A snapshot holds the cw-multi-test storage (contract stores and infos, balances, staking, ...), the block and the
+//! cw-orch [`MockState`]. It can be saved to disk and loaded in other tests to share an expensive setup.
+//!
+//! The contract codes are native Rust code and are not part of the snapshots. After loading a snapshot in a new
+//! environment, the codes are uploaded again with their original code ids by [`MockBase::restore_code`].
+
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::Path,
+    rc::Rc,
+};
+
+use cosmwasm_std::{Api, Binary, BlockInfo, Order, Storage};
+use cw_multi_test::AppBuilder;
+use cw_orch_core::{
+    contract::interface_traits::{ContractInstance, CustomUploadable, Uploadable},
+    environment::StateInterface,
+    CwEnvError,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{plain_wrapper, MockCustomModule},
+    gas::MeteredStorage,
+    stargate::MockStargateModule,
+    MockBase, MockContractSource, MockState,
+};
+
+/// Function returning the wrapper of a code, [`Uploadable::wrapper`] or [`CustomUploadable::custom_wrapper`]
+pub(crate) type CodeFactory<M> = fn() -> MockContractSource<M>;
+/// Code ids of an app with their wrapper, `None` for the codes uploaded with `upload_custom`
+pub(crate) type CodeFactories<M> = Rc<RefCell<BTreeMap<u64, Option<CodeFactory<M>>>>>;
+
+/// State of a mock environment, without the contract codes
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MockSnapshot {
+    pub block: BlockInfo,
+    pub state: MockState,
+    /// Raw key-values of the cw-multi-test storage
+    pub storage: Vec<(Binary, Binary)>,
+}
+
+impl MockSnapshot {
+    /// Writes the snapshot to a JSON file
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), CwEnvError> {
+        let file = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(file, self)?;
+        Ok(())
+    }
+
+    /// Reads a snapshot written by [`MockSnapshot::save`]
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, CwEnvError> {
+        let file = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(file)?)
+    }
+}
+
+impl<A: Api, M: MockCustomModule, G: MockStargateModule> MockBase<A, MockState, M, G> {
+    /// Snapshot of the storage, block and state of this environment
+    pub fn snapshot(&self) -> MockSnapshot {
+        let app = self.app.borrow();
+        MockSnapshot {
+            block: app.block_info(),
+            state: self.state.borrow().clone(),
+            storage: app
+                .storage()
+                .range(None, None, Order::Ascending)
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+        }
+    }
+
+    /// Replaces the storage, block and state of this environment and its clones with the ones of the snapshot.
+    /// The codes are left untouched, see [`MockBase::restore_code`].
+    pub fn restore(&self, snapshot: &MockSnapshot) {
+        let mut app = self.app.borrow_mut();
+        let storage = app.storage_mut();
+        let keys: Vec<_> = storage.range_keys(None, None, Order::Ascending).collect();
+        for key in keys {
+            storage.remove(&key);
+        }
+        for (key, value) in &snapshot.storage {
+            storage.set(key, value);
+        }
+        app.set_block(snapshot.block.clone());
+        *self.state.borrow_mut() = snapshot.state.clone();
+    }
+
+    /// Saves the snapshot of this environment to a JSON file
+    pub fn save_state(&self, path: impl AsRef<Path>) -> Result<(), CwEnvError> {
+        self.snapshot().save(path)
+    }
+
+    /// Restores the snapshot saved in a JSON file, see [`MockBase::restore`]
+    pub fn load_state(&self, path: impl AsRef<Path>) -> Result<(), CwEnvError> {
+        self.restore(&MockSnapshot::load(path)?);
+        Ok(())
+    }
+
+    /// Uploads the code of `contract` with the code id registered for it in the state, if it's not uploaded yet.
+    /// Used to upload the codes of a snapshot loaded in a new environment.
+    /// The snapshots don't hold the code creators, the code is stored with the sender as creator.
+    ///
+    /// ```ignore
+    /// let chain = Mock::new("sender");
+    /// chain.load_state("setup.json")?;
+    /// let counter = CounterContract::new(chain.clone());
+    /// chain.restore_code(&counter)?;
+    /// ```
+    pub fn restore_code<T: Uploadable + ContractInstance<Self>>(
+        &self,
+        contract: &T,
+    ) -> Result<u64, CwEnvError> {
+        self.restore_code_with(&contract.id(), plain_wrapper::<M, T>)
+    }
+
+    /// [`MockBase::restore_code`] for contracts uploaded with [`MockBase::upload_custom_contract`]
+    pub fn restore_custom_code<T>(&self, contract: &T) -> Result<u64, CwEnvError>
+    where
+        T: CustomUploadable<M::ExecT, M::QueryT> + ContractInstance<Self>,
+    {
+        self.restore_code_with(&contract.id(), T::custom_wrapper)
+    }
+
+    fn restore_code_with(
+        &self,
+        contract_id: &str,
+        factory: CodeFactory<M>,
+    ) -> Result<u64, CwEnvError> {
+        let code_id = self.state.borrow().get_code_id(contract_id)?;
+        if !self.code_factories.borrow().contains_key(&code_id) {
+            self.store_code_with_id(self.sender.clone(), code_id, factory)?;
+        }
+        Ok(code_id)
+    }
+}
+
+impl<A: Api + Clone, M: MockCustomModule + Default, G: MockStargateModule>
+    MockBase<A, MockState, M, G>
+{
+    /// Independent copy of this environment: storage, block, state, codes (with their creator) and stargate handlers.
+    /// Gas metering is disabled on the fork.
+    ///
+    /// The custom module of the fork is created with [`Default`]: only the state it keeps in the cw-multi-test storage
+    /// is copied, the state it holds in memory is lost.
+    ///
+    /// Fails if a code was uploaded with [`MockBase::upload_custom`], its wrapper can't be created again.
+    pub fn fork(&self) -> Result<Self, CwEnvError> {
+        let (api, stargate) = {
+            let app = self.app.borrow();
+            let stargate = app.read_module(|router, _, _| router.stargate.fork());
+            (app.api().clone(), stargate)
+        };
+        let app = AppBuilder::new_custom()
+            .with_api(api)
+            .with_custom(M::default())
+            .with_stargate(stargate)
+            .with_storage(MeteredStorage::default())
+            .build(|_, _, _| {});
+        let fork = Self {
+            sender: self.sender.clone(),
+            state: Rc::new(RefCell::new(MockState::new())),
+            app: Rc::new(RefCell::new(app)),
+            code_factories: Default::default(),
+        };
+
+        for (code_id, factory) in self.code_factories.borrow().iter() {
+            let Some(factory) = factory else {
+                return Err(CwEnvError::StdErr(format!(
+                    "Code {code_id} was uploaded with `upload_custom` and can't be forked, upload it with `upload_custom_contract` instead"
+                )));
+            };
+            let creator = self
+                .app
+                .borrow()
+                .wrap()
+                .query_wasm_code_info(*code_id)?
+                .creator;
+            fork.store_code_with_id(creator, *code_id, *factory)?;
+        }
+        fork.restore(&self.snapshot());
+        Ok(fork)
+    }
+}
@@ -0,0 +1,531 @@
+//! Stargate messages and gRPC queries of the mock environments.
+//!
+//! cw-multi-test has no Cosmos SDK modules besides bank, staking and distribution.
+//! Stargate messages (`CosmosMsg::Stargate` and [`Stargate::commit_any`](cw_orch_traits::Stargate::commit_any))
+//! are dispatched on their type url to the registered [`StargateHandler`]s, gRPC queries on their path.
+//! This requires an environment created with the [`MockStargate`] module, `Mock::new_with_stargate` for instance.
+//!
+//! With the `tokenfactory` feature, the Osmosis tokenfactory module is emulated by [`TokenFactory`].
+
+use std::{collections::BTreeMap, rc::Rc};
+
+use cosmwasm_std::{
+    Addr, Api, BankMsg, Binary, BlockInfo, CustomMsg, CustomQuery, Event, Querier, Storage,
+};
+use cw_multi_test::{
+    error::{bail, AnyResult},
+    AppResponse, BankSudo, CosmosRouter, Stargate, StargateFailing, SudoMsg,
+};
+use serde::de::DeserializeOwned;
+
+#[cfg(feature = "tokenfactory")]
+pub use tokenfactory::TokenFactory;
+
+/// Result of a stargate message handled by a [`StargateHandler`]
+#[derive(Clone, Debug, Default)]
+pub struct StargateResponse {
+    pub events: Vec<Event>,
+    pub data: Option<Binary>,
+    /// Bank messages executed on behalf of the message sender
+    pub bank_msgs: Vec<BankMsg>,
+    /// Privileged bank operations, to mint tokens for instance
+    pub bank_sudo_msgs: Vec<BankSudo>,
+}
+
+/// Emulation of a Cosmos SDK module for the stargate messages and queries of the mock environments
+pub trait StargateHandler {
+    /// Type urls of the messages handled
+    fn type_urls(&self) -> Vec<String>;
+
+    /// Paths of the gRPC queries handled
+    fn query_paths(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Executes the protobuf encoded message `value` of type `type_url`, sent by `sender`
+    fn execute(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+        sender: &Addr,
+        type_url: &str,
+        value: Binary,
+    ) -> AnyResult<StargateResponse>;
+
+    /// Answers the protobuf encoded query `data` on `path`.
+    /// Like the stargate queries of wasmd, the response is JSON encoded.
+    fn query(
+        &self,
+        _api: &dyn Api,
+        _storage: &dyn Storage,
+        _block: &BlockInfo,
+        path: &str,
+        _data: Binary,
+    ) -> AnyResult<Binary> {
+        bail!("Unsupported stargate query {path}")
+    }
+}
+
+/// Stargate module of the mock environments.
+/// [`StargateFailing`] (default) rejects the stargate messages and queries, [`MockStargate`] dispatches them to the registered handlers.
+pub trait MockStargateModule: Stargate + 'static {
+    /// Module of a fork of the environment, see [`MockBase::fork`](crate::MockBase::fork)
+    fn fork(&self) -> Self;
+}
+
+impl MockStargateModule for StargateFailing {
+    fn fork(&self) -> Self {
+        StargateFailing
+    }
+}
+
+impl MockStargateModule for MockStargate {
+    fn fork(&self) -> Self {
+        self.clone()
+    }
+}
+
+/// Stargate module of the mock environments, dispatches the messages and queries to the registered handlers
+#[derive(Clone, Default)]
+pub struct MockStargate {
+    handlers: BTreeMap<String, Rc<dyn StargateHandler>>,
+    query_handlers: BTreeMap<String, Rc<dyn StargateHandler>>,
+}
+
+impl MockStargate {
+    /// Stargate module with the built-in handlers
+    pub fn new() -> Self {
+        #[allow(unused_mut)]
+        let mut stargate = Self::default();
+        #[cfg(feature = "tokenfactory")]
+        stargate.add_handler(TokenFactory);
+        stargate
+    }
+
+    /// Registers a handler for its type urls and query paths, replacing the previous handlers of these
+    pub fn add_handler(&mut self, handler: impl StargateHandler + 'static) {
+        let handler: Rc<dyn StargateHandler> = Rc::new(handler);
+        for type_url in handler.type_urls() {
+            self.handlers.insert(type_url, handler.clone());
+        }
+        for path in handler.query_paths() {
+            self.query_handlers.insert(path, handler.clone());
+        }
+    }
+}
+
+impl Stargate for MockStargate {
+    fn execute_stargate<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        type_url: String,
+        value: Binary,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: CustomMsg + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        let Some(handler) = self.handlers.get(&type_url) else {
+            bail!("No stargate handler registered for {type_url} on the mock environment");
+        };
+        let response = handler.execute(api, storage, block, &sender, &type_url, value)?;
+
+        let mut events = response.events;
+        for msg in response.bank_msgs {
+            let bank_response = router.execute(api, storage, block, sender.clone(), msg.into())?;
+            events.extend(bank_response.events);
+        }
+        for msg in response.bank_sudo_msgs {
+            let bank_response = router.sudo(api, storage, block, SudoMsg::Bank(msg))?;
+            events.extend(bank_response.events);
+        }
+        Ok(AppResponse {
+            events,
+            data: response.data,
+        })
+    }
+
+    fn query_stargate(
+        &self,
+        api: &dyn Api,
+        storage: &dyn Storage,
+        _querier: &dyn Querier,
+        block: &BlockInfo,
+        path: String,
+        data: Binary,
+    ) -> AnyResult<Binary> {
+        let Some(handler) = self.query_handlers.get(&path) else {
+            bail!("No stargate query handler registered for {path} on the mock environment");
+        };
+        handler.query(api, storage, block, &path, data)
+    }
+}
+
+#[cfg(feature = "tokenfactory")]
+mod tokenfactory {
+    use cosmwasm_std::{
+        coins, to_json_binary, Addr, Api, BankMsg, Binary, BlockInfo, Event, Order, StdError,
+        Storage,
+    };
+    use cw_multi_test::{
+        error::{bail, AnyResult},
+        BankSudo,
+    };
+    use osmosis_std::types::{
+        cosmos::base::v1beta1::Coin as ProtoCoin,
+        osmosis::tokenfactory::v1beta1::{
+            DenomAuthorityMetadata, MsgBurn, MsgChangeAdmin, MsgCreateDenom,
+            MsgCreateDenomResponse, MsgMint, QueryDenomAuthorityMetadataRequest,
+            QueryDenomAuthorityMetadataResponse, QueryDenomsFromCreatorRequest,
+            QueryDenomsFromCreatorResponse,
+        },
+    };
+    use prost::Message;
+
+    use super::{StargateHandler, StargateResponse};
+
+    const DENOM_AUTHORITY_METADATA_PATH: &str =
+        "/osmosis.tokenfactory.v1beta1.Query/DenomAuthorityMetadata";
+    const DENOMS_FROM_CREATOR_PATH: &str = "/osmosis.tokenfactory.v1beta1.Query/DenomsFromCreator";
+    /// Storage prefix of the denom admins
+    const ADMIN_PREFIX: &[u8] = b"cw-orch-tokenfactory-admin/";
+
+    /// Emulation of the Osmosis tokenfactory module.
+    /// Denoms are created as `factory/{creator}/{subdenom}`, the creation fee is not charged.
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct TokenFactory;
+
+    fn admin_key(denom: &str) -> Vec<u8> {
+        [ADMIN_PREFIX, denom.as_bytes()].concat()
+    }
+
+    fn admin(storage: &dyn Storage, denom: &str) -> AnyResult<String> {
+        match storage.get(&admin_key(denom)) {
+            Some(admin) => Ok(String::from_utf8(admin)?),
+            None => bail!("denom {denom} does not exist"),
+        }
+    }
+
+    fn ensure_admin(storage: &dyn Storage, denom: &str, sender: &str) -> AnyResult<()> {
+        if admin(storage, denom)? != sender {
+            bail!("unauthorized account: {sender} is not the admin of {denom}");
+        }
+        Ok(())
+    }
+
+    fn ensure_signer(sender: &Addr, msg_sender: &str) -> AnyResult<()> {
+        if sender.as_str() != msg_sender {
+            bail!("the message sender {msg_sender} is not the signer {sender}");
+        }
+        Ok(())
+    }
+
+    fn proto_coin(coin: Option<ProtoCoin>) -> AnyResult<(String, u128)> {
+        let Some(coin) = coin else {
+            bail!("missing amount");
+        };
+        let amount = coin
+            .amount
+            .parse()
+            .map_err(|e| StdError::generic_err(format!("invalid amount {}: {e}", coin.amount)))?;
+        Ok((coin.denom, amount))
+    }
+
+    impl StargateHandler for TokenFactory {
+        fn type_urls(&self) -> Vec<String> {
+            vec![
+                MsgCreateDenom::TYPE_URL.to_string(),
+                MsgMint::TYPE_URL.to_string(),
+                MsgBurn::TYPE_URL.to_string(),
+                MsgChangeAdmin::TYPE_URL.to_string(),
+            ]
+        }
+
+        fn query_paths(&self) -> Vec<String> {
+            vec![
+                DENOM_AUTHORITY_METADATA_PATH.to_string(),
+                DENOMS_FROM_CREATOR_PATH.to_string(),
+            ]
+        }
+
+        fn execute(
+            &self,
+            api: &dyn Api,
+            storage: &mut dyn Storage,
+            _block: &BlockInfo,
+            sender: &Addr,
+            type_url: &str,
+            value: Binary,
+        ) -> AnyResult<StargateResponse> {
+            match type_url {
+                MsgCreateDenom::TYPE_URL => {
+                    let msg = MsgCreateDenom::decode(value.as_slice())?;
+                    ensure_signer(sender, &msg.sender)?;
+                    if msg.subdenom.is_empty() || msg.subdenom.contains('/') {
+                        bail!("invalid subdenom {}", msg.subdenom);
+                    }
+                    let denom = format!("factory/{}/{}", msg.sender, msg.subdenom);
+                    if storage.get(&admin_key(&denom)).is_some() {
+                        bail!("denom {denom} already exists");
+                    }
+                    storage.set(&admin_key(&denom), msg.sender.as_bytes());
+
+                    Ok(StargateResponse {
+                        events: vec![Event::new("create_denom")
+                            .add_attribute("creator", &msg.sender)
+                            .add_attribute("new_token_denom", &denom)],
+                        data: Some(
+                            MsgCreateDenomResponse {
+                                new_token_denom: denom,
+                            }
+                            .encode_to_vec()
+                            .into(),
+                        ),
+                        ..Default::default()
+                    })
+                }
+                MsgMint::TYPE_URL => {
+                    let msg = MsgMint::decode(value.as_slice())?;
+                    ensure_signer(sender, &msg.sender)?;
+                    let (denom, amount) = proto_coin(msg.amount)?;
+                    ensure_admin(storage, &denom, &msg.sender)?;
+                    let mint_to_address = if msg.mint_to_address.is_empty() {
+                        msg.sender
+                    } else {
+                        api.addr_validate(&msg.mint_to_address)?.to_string()
+                    };
+
+                    Ok(StargateResponse {
+                        events: vec![Event::new("tf_mint")
+                            .add_attribute("mint_to_address", &mint_to_address)
+                            .add_attribute("amount", format!("{amount}{denom}"))],
+                        bank_sudo_msgs: vec![BankSudo::Mint {
+                            to_address: mint_to_address,
+                            amount: coins(amount, denom),
+                        }],
+                        ..Default::default()
+                    })
+                }
+                MsgBurn::TYPE_URL => {
+                    let msg = MsgBurn::decode(value.as_slice())?;
+                    ensure_signer(sender, &msg.sender)?;
+                    let (denom, amount) = proto_coin(msg.amount)?;
+                    ensure_admin(storage, &denom, &msg.sender)?;
+                    if !msg.burn_from_address.is_empty() && msg.burn_from_address != msg.sender {
+                        bail!("burning from another address than the admin is not supported");
+                    }
+
+                    Ok(StargateResponse {
+                        events: vec![Event::new("tf_burn")
+                            .add_attribute("burn_from_address", &msg.sender)
+                            .add_attribute("amount", format!("{amount}{denom}"))],
+                        bank_msgs: vec![BankMsg::Burn {
+                            amount: coins(amount, denom),
+                        }],
+                        ..Default::default()
+                    })
+                }
+                MsgChangeAdmin::TYPE_URL => {
+                    let msg = MsgChangeAdmin::decode(value.as_slice())?;
+                    ensure_signer(sender, &msg.sender)?;
+                    ensure_admin(storage, &msg.denom, &msg.sender)?;
+                    let new_admin = api.addr_validate(&msg.new_admin)?;
+                    storage.set(&admin_key(&msg.denom), new_admin.as_bytes());
+
+                    Ok(StargateResponse {
+                        events: vec![Event::new("change_admin")
+                            .add_attribute("denom", &msg.denom)
+                            .add_attribute("new_admin", new_admin)],
+                        ..Default::default()
+                    })
+                }
+                _ => bail!("Unsupported tokenfactory message {type_url}"),
+            }
+        }
+
+        fn query(
+            &self,
+            _api: &dyn Api,
+            storage: &dyn Storage,
+            _block: &BlockInfo,
+            path: &str,
+            data: Binary,
+        ) -> AnyResult<Binary> {
+            match path {
+                DENOM_AUTHORITY_METADATA_PATH => {
+                    let request = QueryDenomAuthorityMetadataRequest::decode(data.as_slice())?;
+                    let admin = admin(storage, &request.denom)?;
+                    Ok(to_json_binary(&QueryDenomAuthorityMetadataResponse {
+                        authority_metadata: Some(DenomAuthorityMetadata { admin }),
+                    })?)
+                }
+                DENOMS_FROM_CREATOR_PATH => {
+                    let request = QueryDenomsFromCreatorRequest::decode(data.as_slice())?;
+                    let prefix = admin_key(&format!("factory/{}/", request.creator));
+                    let denoms = storage
+                        .range_keys(Some(&prefix), None, Order::Ascending)
+                        .take_while(|key| key.starts_with(&prefix))
+                        .map(|key| String::from_utf8(key[ADMIN_PREFIX.len()..].to_vec()))
+                        .collect::<Result<_, _>>()?;
+                    Ok(to_json_binary(&QueryDenomsFromCreatorResponse { denoms })?)
+                }
+                _ => bail!("Unsupported tokenfactory query {path}"),
+            }
+        }
+    }
+}
+
+// The tests send the messages with `Stargate::commit_any`
+#[cfg(all(test, feature = "stargate"))]
+mod test {
+    use cosmwasm_std::{coins, Addr, Api, Binary, BlockInfo, Storage};
+    use cw_multi_test::{error::AnyResult, BankSudo};
+    use cw_orch_core::environment::{BankQuerier, DefaultQueriers, TxHandler};
+    use cw_orch_traits::Stargate;
+
+    use super::{StargateHandler, StargateResponse};
+    use crate::Mock;
+
+    const FAUCET_TYPE_URL: &str = "/test.faucet.MsgDrip";
+
+    /// Mints the amount encoded in the message to the sender
+    struct Faucet;
+
+    impl StargateHandler for Faucet {
+        fn type_urls(&self) -> Vec<String> {
+            vec![FAUCET_TYPE_URL.to_string()]
+        }
+
+        fn execute(
+            &self,
+            _api: &dyn Api,
+            _storage: &mut dyn Storage,
+            _block: &BlockInfo,
+            sender: &Addr,
+            _type_url: &str,
+            value: Binary,
+        ) -> AnyResult<StargateResponse> {
+            let amount = u128::from_be_bytes(value.as_slice().try_into()?);
+            Ok(StargateResponse {
+                bank_sudo_msgs: vec![BankSudo::Mint {
+                    to_address: sender.to_string(),
+                    amount: coins(amount, "ufaucet"),
+                }],
+                ..Default::default()
+            })
+        }
+    }
+
+    fn drip(amount: u128) -> prost_types::Any {
+        prost_types::Any {
+            type_url: FAUCET_TYPE_URL.to_string(),
+            value: amount.to_be_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn stargate_handler() -> anyhow::Result<()> {
+        let chain = Mock::new_with_stargate("sender");
+        chain.commit_any(vec![drip(10)], None).unwrap_err();
+
+        chain.add_stargate_handler(Faucet);
+        chain.commit_any(vec![drip(10), drip(5)], None)?;
+        let balance = chain
+            .bank_querier()
+            .balance(&chain.sender_addr(), Some("ufaucet".to_string()))?;
+        assert_eq!(balance, coins(15, "ufaucet"));
+        Ok(())
+    }
+
+    #[cfg(feature = "tokenfactory")]
+    #[test]
+    fn tokenfactory() -> anyhow::Result<()> {
+        use osmosis_std::types::{
+            cosmos::base::v1beta1::Coin,
+            osmosis::tokenfactory::v1beta1::{
+                MsgBurn, MsgChangeAdmin, MsgCreateDenom, MsgMint, TokenfactoryQuerier,
+            },
+        };
+
+        let chain = Mock::new_with_stargate("sender");
+        let sender = chain.sender_addr().to_string();
+        let receiver = chain.addr_make("receiver").to_string();
+        let denom = format!("factory/{sender}/token");
+        let coin = |amount: u128| Coin {
+            denom: denom.clone(),
+            amount: amount.to_string(),
+        };
+
+        chain.commit_any(
+            vec![MsgCreateDenom {
+                sender: sender.clone(),
+                subdenom: "token".to_string(),
+            }
+            .to_any()
+            .into()],
+            None,
+        )?;
+        chain.commit_any(
+            vec![
+                MsgMint {
+                    sender: sender.clone(),
+                    mint_to_address: sender.clone(),
+                    amount: Some(coin(100)),
+                }
+                .to_any()
+                .into(),
+                MsgBurn {
+                    sender: sender.clone(),
+                    burn_from_address: sender.clone(),
+                    amount: Some(coin(40)),
+                }
+                .to_any()
+                .into(),
+            ],
+            None,
+        )?;
+        let balance = chain
+            .bank_querier()
+            .balance(&chain.sender_addr(), Some(denom.clone()))?;
+        assert_eq!(balance, coins(60, &denom));
+
+        {
+            let app = chain.app.borrow();
+            let querier = app.wrap();
+            let tokenfactory = TokenfactoryQuerier::new(&querier);
+            let metadata = tokenfactory.denom_authority_metadata(denom.clone())?;
+            assert_eq!(metadata.authority_metadata.unwrap().admin, sender);
+            let denoms = tokenfactory.denoms_from_creator(sender.clone())?;
+            assert_eq!(denoms.denoms, vec![denom.clone()]);
+        }
+
+        chain.commit_any(
+            vec![MsgChangeAdmin {
+                sender: sender.clone(),
+                denom: denom.clone(),
+                new_admin: receiver,
+            }
+            .to_any()
+            .into()],
+            None,
+        )?;
+        chain
+            .commit_any(
+                vec![MsgMint {
+                    sender,
+                    mint_to_address: String::new(),
+                    amount: Some(coin(1)),
+                }
+                .to_any()
+                .into()],
+                None,
+            )
+            .unwrap_err();
+        Ok(())
+    }
+}
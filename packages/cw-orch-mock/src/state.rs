@@ -1,9 +1,10 @@
 use cosmwasm_std::{testing::mock_env, Addr};
 use cw_orch_core::{environment::StateInterface, CwEnvError};
+use serde::{Deserialize, Serialize};
 
 use std::collections::HashMap;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 /// Mock state for testing, stores addresses and code-ids.
 pub struct MockState {
     /// Deployed contract code ids
@@ -1 +1,3 @@
 pub mod networks;
+#[cfg(feature = "chain-registry")]
+pub mod registry;
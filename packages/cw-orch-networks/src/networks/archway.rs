@@ -1,10 +1,11 @@
-use crate::networks::{ChainInfo, ChainKind, NetworkInfo};
+use crate::networks::{ChainInfo, ChainKind, KeyAlgorithm, NetworkInfo};
 
 // ANCHOR: archway
 pub const ARCHWAY_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "archway",
     pub_address_prefix: "archway",
     coin_type: 118u32,
+    key_algorithm: KeyAlgorithm::Secp256k1,
 };
 
 /// Archway Docs: <https://docs.archway.io/resources/networks>
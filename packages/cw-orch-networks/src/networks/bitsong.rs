@@ -1,10 +1,11 @@
-use cw_orch_core::environment::{ChainInfo, ChainKind, NetworkInfo};
+use cw_orch_core::environment::{ChainInfo, ChainKind, KeyAlgorithm, NetworkInfo};
 
 // ANCHOR: bitsong
 pub const BITSONG_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "bitsong",
     pub_address_prefix: "bitsong",
     coin_type: 639u32,
+    key_algorithm: KeyAlgorithm::Secp256k1,
 };
 
 pub const BITSONG_2B: ChainInfo = ChainInfo {
@@ -1,10 +1,11 @@
-use cw_orch_core::environment::{ChainInfo, ChainKind, NetworkInfo};
+use cw_orch_core::environment::{ChainInfo, ChainKind, KeyAlgorithm, NetworkInfo};
 
 // ANCHOR: cosmos
 pub const COSMOS_HUB_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "cosmoshub",
     pub_address_prefix: "cosmos",
     coin_type: 118,
+    key_algorithm: KeyAlgorithm::Secp256k1,
 };
 
 pub const COSMOS_HUB_TESTNET: ChainInfo = ICS_TESTNET;
@@ -1,10 +1,11 @@
-use crate::networks::{ChainInfo, ChainKind, NetworkInfo};
+use crate::networks::{ChainInfo, ChainKind, KeyAlgorithm, NetworkInfo};
 
 // ANCHOR: injective
 pub const INJECTIVE_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "injective",
     pub_address_prefix: "inj",
     coin_type: 60u32,
+    key_algorithm: KeyAlgorithm::INJECTIVE,
 };
 
 /// <https://docs.injective.network/develop/public-endpoints/#mainnet>
@@ -1,4 +1,4 @@
-use cw_orch_core::environment::{ChainInfo, ChainKind, NetworkInfo};
+use cw_orch_core::environment::{ChainInfo, ChainKind, KeyAlgorithm, NetworkInfo};
 
 // https://notional.ventures/resources/endpoints#juno
 
@@ -7,6 +7,7 @@ pub const JUNO_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "juno",
     pub_address_prefix: "juno",
     coin_type: 118u32,
+    key_algorithm: KeyAlgorithm::Secp256k1,
 };
 
 pub const UNI_6: ChainInfo = ChainInfo {
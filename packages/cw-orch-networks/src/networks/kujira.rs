@@ -1,10 +1,11 @@
-use crate::networks::{ChainInfo, ChainKind, NetworkInfo};
+use crate::networks::{ChainInfo, ChainKind, KeyAlgorithm, NetworkInfo};
 
 // ANCHOR: kujira
 pub const KUJIRA_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "kujira",
     pub_address_prefix: "kujira",
     coin_type: 118u32,
+    key_algorithm: KeyAlgorithm::Secp256k1,
 };
 
 pub const HARPOON_4: ChainInfo = ChainInfo {
@@ -1,10 +1,11 @@
-use cw_orch_core::environment::{ChainInfo, ChainKind, NetworkInfo};
+use cw_orch_core::environment::{ChainInfo, ChainKind, KeyAlgorithm, NetworkInfo};
 
 // ANCHOR: landslide
 pub const LANDSLIDE_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "landslide",
     pub_address_prefix: "wasm",
     coin_type: 118u32,
+    key_algorithm: KeyAlgorithm::Secp256k1,
 };
 
 pub const LOCAL_LANDSLIDE: ChainInfo = ChainInfo {
@@ -1,10 +1,11 @@
-use crate::networks::{ChainInfo, ChainKind, NetworkInfo};
+use crate::networks::{ChainInfo, ChainKind, KeyAlgorithm, NetworkInfo};
 
 // ANCHOR: migaloo
 pub const MIGALOO_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "migaloo-1",
     pub_address_prefix: "migaloo",
     coin_type: 118u32,
+    key_algorithm: KeyAlgorithm::Secp256k1,
 };
 
 pub const LOCAL_MIGALOO: ChainInfo = ChainInfo {
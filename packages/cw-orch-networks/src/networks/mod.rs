@@ -25,7 +25,7 @@ use crate::networks::union::{UNION_TESTNET_8, UNION_TESTNET_9};
 pub use archway::{ARCHWAY_1, CONSTANTINE_3};
 pub use bitsong::BITSONG_2B;
 pub use cosmos::COSMOS_HUB_TESTNET;
-pub use cw_orch_core::environment::{ChainInfo, ChainKind, NetworkInfo};
+pub use cw_orch_core::environment::{ChainInfo, ChainKind, KeyAlgorithm, NetworkInfo};
 pub use doravota::{VOTA_ASH, VOTA_TESTNET};
 pub use injective::{INJECTIVE_1, INJECTIVE_888};
 pub use juno::{JUNO_1, LOCAL_JUNO, UNI_6};
@@ -1,10 +1,11 @@
-use crate::networks::{ChainInfo, ChainKind, NetworkInfo};
+use crate::networks::{ChainInfo, ChainKind, KeyAlgorithm, NetworkInfo};
 
 // ANCHOR: neutron
 pub const NEUTRON_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "neutron",
     pub_address_prefix: "neutron",
     coin_type: 118u32,
+    key_algorithm: KeyAlgorithm::Secp256k1,
 };
 
 /// <https://github.com/cosmos/chain-registry/blob/master/testnets/neutrontestnet/chain.json>
@@ -1,10 +1,11 @@
-use cw_orch_core::environment::{ChainInfo, ChainKind, NetworkInfo};
+use cw_orch_core::environment::{ChainInfo, ChainKind, KeyAlgorithm, NetworkInfo};
 
 // ANCHOR: nibiru
 pub const NIBIRU_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "nibiru",
     pub_address_prefix: "nibi",
     coin_type: 118u32,
+    key_algorithm: KeyAlgorithm::Secp256k1,
 };
 
 pub const NIBIRU_ITN_2: ChainInfo = ChainInfo {
@@ -1,10 +1,11 @@
-use cw_orch_core::environment::{ChainInfo, ChainKind, NetworkInfo};
+use cw_orch_core::environment::{ChainInfo, ChainKind, KeyAlgorithm, NetworkInfo};
 
 // ANCHOR: osmosis
 pub const OSMO_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "osmosis",
     pub_address_prefix: "osmo",
     coin_type: 118u32,
+    key_algorithm: KeyAlgorithm::Secp256k1,
 };
 
 pub const OSMOSIS_1: ChainInfo = ChainInfo {
@@ -1,10 +1,11 @@
-use cw_orch_core::environment::{ChainInfo, ChainKind, NetworkInfo};
+use cw_orch_core::environment::{ChainInfo, ChainKind, KeyAlgorithm, NetworkInfo};
 
 // ANCHOR: rollkit
 pub const ROLLKIT_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "rollkit",
     pub_address_prefix: "wasm",
     coin_type: 118u32,
+    key_algorithm: KeyAlgorithm::Secp256k1,
 };
 
 pub const LOCAL_ROLLKIT: ChainInfo = ChainInfo {
@@ -1,10 +1,11 @@
-use cw_orch_core::environment::{ChainInfo, ChainKind, NetworkInfo};
+use cw_orch_core::environment::{ChainInfo, ChainKind, KeyAlgorithm, NetworkInfo};
 
 // ANCHOR: terra
 pub const TERRA_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "terra2",
     pub_address_prefix: "terra",
     coin_type: 330u32,
+    key_algorithm: KeyAlgorithm::Secp256k1,
 };
 
 /// Terra testnet network.
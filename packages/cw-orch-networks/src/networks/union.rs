@@ -1,10 +1,11 @@
-use cw_orch_core::environment::{ChainInfo, ChainKind, NetworkInfo};
+use cw_orch_core::environment::{ChainInfo, ChainKind, KeyAlgorithm, NetworkInfo};
 
 // ANCHOR: union
 pub const UNION_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "union",
     pub_address_prefix: "union",
     coin_type: 118,
+    key_algorithm: KeyAlgorithm::Secp256k1,
 };
 
 pub const UNION_TESTNET: ChainInfo = UNION_TESTNET_9;
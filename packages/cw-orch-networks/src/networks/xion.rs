@@ -1,10 +1,11 @@
-use cw_orch_core::environment::{ChainInfo, ChainKind, NetworkInfo};
+use cw_orch_core::environment::{ChainInfo, ChainKind, KeyAlgorithm, NetworkInfo};
 
 // ANCHOR: xion
 pub const XION_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "xion",
     pub_address_prefix: "xion",
     coin_type: 118u32,
+    key_algorithm: KeyAlgorithm::Secp256k1,
 };
 
 pub const XION_TESTNET_1: ChainInfo = ChainInfo {
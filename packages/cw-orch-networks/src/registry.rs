@@ -0,0 +1,219 @@
+//! # Chain registry
+//! Loads [`ChainInfoOwned`] from a local checkout of the [cosmos chain-registry](https://github.com/cosmos/chain-registry)
+//! or from a vendored snapshot with the same layout.
+//!
+//! Chains are looked up by their registry name (the directory name, ex. `osmosis`).
+//! Mainnets are searched first, then the `testnets` directory.
+//!
+//! ## Example
+//! ```rust,no_run
+//! use cw_orch_networks::registry::ChainRegistry;
+//! let osmosis = ChainRegistry::new("./chain-registry").chain_info("osmosis").unwrap();
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use cw_orch_core::{
+    environment::{ChainInfoOwned, KeyAlgorithmOwned, NetworkInfoOwned},
+    CoreEnvVars, CwEnvError,
+};
+use ibc_chain_registry::{asset_list::AssetList, chain::ChainData};
+use serde::de::DeserializeOwned;
+
+pub use cw_orch_core::env::CHAIN_REGISTRY_ENV_NAME;
+
+const CHAIN_FILE: &str = "chain.json";
+const ASSET_LIST_FILE: &str = "assetlist.json";
+const TESTNETS_DIR: &str = "testnets";
+const ETH_SECP256K1: &str = "ethsecp256k1";
+
+/// Local chain-registry directory
+#[derive(Clone, Debug)]
+pub struct ChainRegistry {
+    root: PathBuf,
+}
+
+impl ChainRegistry {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Chain registry located at the `CW_ORCH_CHAIN_REGISTRY` env variable
+    pub fn env() -> Result<Self, CwEnvError> {
+        CoreEnvVars::chain_registry()
+            .map(Self::new)
+            .ok_or_else(|| CwEnvError::EnvVarNotPresentNamed(CHAIN_REGISTRY_ENV_NAME.to_string()))
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Directory of a chain in the registry
+    pub fn chain_dir(&self, chain_name: &str) -> Result<PathBuf, CwEnvError> {
+        [
+            self.root.join(chain_name),
+            self.root.join(TESTNETS_DIR).join(chain_name),
+        ]
+        .into_iter()
+        .find(|dir| dir.join(CHAIN_FILE).is_file())
+        .ok_or_else(|| {
+            CwEnvError::StdErr(format!(
+                "Chain {chain_name} not found in the chain registry at {}",
+                self.root.display()
+            ))
+        })
+    }
+
+    /// Content of the `chain.json` file of a chain
+    pub fn chain_data(&self, chain_name: &str) -> Result<ChainData, CwEnvError> {
+        read_json(&self.chain_dir(chain_name)?.join(CHAIN_FILE))
+    }
+
+    /// Content of the `assetlist.json` file of a chain, if the chain has one
+    pub fn asset_list(&self, chain_name: &str) -> Result<Option<AssetList>, CwEnvError> {
+        let path = self.chain_dir(chain_name)?.join(ASSET_LIST_FILE);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        read_json(&path).map(Some)
+    }
+
+    /// Chain info of a chain of the registry.
+    /// The gas denom is the first fee token of the chain, or the first asset of the chain when no fee tokens are registered.
+    /// The gas price is left unset (NaN) when the registry has no gas price for that denom.
+    pub fn chain_info(&self, chain_name: &str) -> Result<ChainInfoOwned, CwEnvError> {
+        let chain = self.chain_data(chain_name)?;
+        let asset_list = self.asset_list(chain_name)?;
+        chain_data_conversion(chain, asset_list)
+    }
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> Result<T, CwEnvError> {
+    let content = std::fs::read_to_string(path)?;
+    serde_json::from_str(&content).map_err(|e| {
+        CwEnvError::StdErr(format!(
+            "Invalid chain registry file {}: {e}",
+            path.display()
+        ))
+    })
+}
+
+/// Chain info of the chain-registry data of a chain, also used for the chains of a starship deployment.
+/// The gas price is left unset (NaN) when no gas price is registered for the gas denom.
+pub fn chain_data_conversion(
+    chain: ChainData,
+    asset_list: Option<AssetList>,
+) -> Result<ChainInfoOwned, CwEnvError> {
+    let (gas_denom, gas_price) = match chain.fees.fee_tokens.first() {
+        Some(fee_token) => (
+            fee_token.denom.clone(),
+            [
+                fee_token.average_gas_price,
+                fee_token.low_gas_price,
+                fee_token.fixed_min_gas_price,
+            ]
+            .into_iter()
+            .find(|price| *price > 0.0)
+            .unwrap_or(f64::NAN),
+        ),
+        None => asset_list
+            .and_then(|asset_list| asset_list.assets.into_iter().next())
+            .map(|asset| (asset.base, f64::NAN))
+            .ok_or_else(|| {
+                CwEnvError::StdErr(format!(
+                    "No fee token or asset registered for chain {}",
+                    chain.chain_name
+                ))
+            })?,
+    };
+
+    let key_algorithm = if chain.key_algos.iter().any(|algo| algo == ETH_SECP256K1) {
+        KeyAlgorithmOwned::eth_secp256k1(&chain.bech32_prefix)
+    } else {
+        KeyAlgorithmOwned::from_coin_type(chain.slip44, &chain.bech32_prefix)
+    };
+
+    Ok(ChainInfoOwned {
+        chain_id: chain.chain_id.to_string(),
+        gas_denom,
+        gas_price,
+        grpc_urls: chain
+            .apis
+            .grpc
+            .into_iter()
+            .map(|grpc| grpc_url(grpc.address))
+            .collect(),
+        lcd_url: chain.apis.rest.into_iter().next().map(|rest| rest.address),
+        fcd_url: None,
+        network_info: NetworkInfoOwned {
+            chain_name: chain.chain_name,
+            pub_address_prefix: chain.bech32_prefix,
+            coin_type: chain.slip44,
+            key_algorithm,
+        },
+        kind: chain.network_type.into(),
+    })
+}
+
+/// The registry lists most gRPC endpoints without scheme, port 443 is served over TLS
+fn grpc_url(address: String) -> String {
+    if address.contains("://") {
+        address
+    } else if address.ends_with(":443") {
+        format!("https://{address}")
+    } else {
+        format!("http://{address}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cw_orch_core::environment::{ChainKind, KeyAlgorithm};
+
+    use super::*;
+
+    fn registry() -> ChainRegistry {
+        ChainRegistry::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/chain-registry"))
+    }
+
+    #[test]
+    fn mainnet_chain_info() {
+        let osmosis = registry().chain_info("osmosis").unwrap();
+        assert_eq!(osmosis.chain_id, "osmosis-1");
+        assert_eq!(osmosis.gas_denom, "uosmo");
+        assert_eq!(osmosis.gas_price, 0.025);
+        assert_eq!(
+            osmosis.grpc_urls,
+            vec![
+                "https://grpc.osmosis.zone:443".to_string(),
+                "http://osmosis-grpc.polkachu.com:12590".to_string()
+            ]
+        );
+        assert_eq!(osmosis.lcd_url.as_deref(), Some("https://lcd.osmosis.zone"));
+        assert_eq!(osmosis.network_info.pub_address_prefix, "osmo");
+        assert_eq!(osmosis.network_info.coin_type, 118);
+        assert_eq!(osmosis.kind, ChainKind::Mainnet);
+    }
+
+    #[test]
+    fn testnet_chain_info() {
+        let injective = registry().chain_info("injectivetestnet").unwrap();
+        assert_eq!(injective.chain_id, "injective-888");
+        // No fee tokens registered, the first asset is used
+        assert_eq!(injective.gas_denom, "inj");
+        assert!(injective.gas_price.is_nan());
+        assert_eq!(injective.network_info.coin_type, 60);
+        assert_eq!(
+            injective.network_info.key_algorithm,
+            KeyAlgorithmOwned::from(KeyAlgorithm::INJECTIVE)
+        );
+        assert_eq!(injective.kind, ChainKind::Testnet);
+    }
+
+    #[test]
+    fn unknown_chain() {
+        let err = registry().chain_info("unknown").unwrap_err();
+        assert!(err.to_string().contains("Chain unknown not found"));
+    }
+}
@@ -4,7 +4,9 @@ use cosmwasm_std::{coin, Addr, BankMsg, Coins};
 
 use cw_orch_core::contract::interface_traits::Uploadable;
 use cw_orch_core::contract::WasmPath;
-use cw_orch_core::environment::{BankQuerier, BankSetter, ChainInfo, DefaultQueriers, NetworkInfo};
+use cw_orch_core::environment::{
+    BankQuerier, BankSetter, ChainInfo, DefaultQueriers, KeyAlgorithm, NetworkInfo,
+};
 
 use cosmwasm_std::{Binary, Coin, Uint128};
 use cw_orch_core::CwEnvError;
@@ -39,6 +41,7 @@ pub const MOCK_CHAIN_INFO: ChainInfo = ChainInfo {
         chain_name: "neutron",
         pub_address_prefix: "neutron",
         coin_type: 118u32,
+        key_algorithm: KeyAlgorithm::Secp256k1,
     },
     kind: cw_orch_core::environment::ChainKind::Local,
 };
@@ -0,0 +1,12 @@
+use cosmwasm_std::VoteOption;
+use cw_orch_core::environment::TxHandler;
+
+/// Governance actions of the sender on cw-orch environments
+pub trait Voter: TxHandler {
+    /// Vote `option` on the proposal `proposal_id`
+    fn vote(
+        &self,
+        proposal_id: u64,
+        option: VoteOption,
+    ) -> Result<<Self as TxHandler>::Response, <Self as TxHandler>::Error>;
+}
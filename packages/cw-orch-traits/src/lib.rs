@@ -1,7 +1,13 @@
 use cw_orch_core::environment::CwEnv;
 
+#[cfg(feature = "gov")]
+pub mod gov;
+pub mod staking;
 pub mod stargate;
 
+#[cfg(feature = "gov")]
+pub use gov::Voter;
+pub use staking::Delegator;
 pub use stargate::Stargate;
 
 pub trait FullNode: CwEnv + Stargate {}
@@ -0,0 +1,44 @@
+use cosmwasm_std::{Addr, Coin};
+use cw_orch_core::environment::TxHandler;
+
+/// Staking and distribution actions of the sender on cw-orch environments
+pub trait Delegator: TxHandler {
+    /// Delegate `amount` to `validator`
+    fn delegate(
+        &self,
+        validator: &str,
+        amount: &Coin,
+    ) -> Result<<Self as TxHandler>::Response, <Self as TxHandler>::Error>;
+
+    /// Undelegate `amount` from `validator`
+    fn undelegate(
+        &self,
+        validator: &str,
+        amount: &Coin,
+    ) -> Result<<Self as TxHandler>::Response, <Self as TxHandler>::Error>;
+
+    /// Move `amount` of the delegation from `src_validator` to `dst_validator`
+    fn redelegate(
+        &self,
+        src_validator: &str,
+        dst_validator: &str,
+        amount: &Coin,
+    ) -> Result<<Self as TxHandler>::Response, <Self as TxHandler>::Error>;
+
+    /// Withdraw the rewards of the delegation to `validator`
+    fn withdraw_rewards(
+        &self,
+        validator: &str,
+    ) -> Result<<Self as TxHandler>::Response, <Self as TxHandler>::Error>;
+
+    /// Withdraw the rewards of the delegations to all validators, in a single transaction
+    fn withdraw_all_rewards(
+        &self,
+    ) -> Result<<Self as TxHandler>::Response, <Self as TxHandler>::Error>;
+
+    /// Set the address the staking rewards are withdrawn to
+    fn set_withdraw_address(
+        &self,
+        address: &Addr,
+    ) -> Result<<Self as TxHandler>::Response, <Self as TxHandler>::Error>;
+}
@@ -7,10 +7,10 @@ pub const STARSHIP_CONFIG_ENV_NAME: &str = "CW_ORCH_STARSHIP_CONFIG_PATH";
 pub mod client;
 
 use crate::client::StarshipClient;
-use cw_orch_core::environment::{ChainInfoOwned, ChainState, NetworkInfoOwned};
+use cw_orch_core::environment::ChainState;
 use cw_orch_core::CwEnvError;
 use cw_orch_daemon::{Daemon, DaemonBuilder, RUNTIME};
-use ibc_chain_registry::chain::ChainData;
+use cw_orch_networks::registry::chain_data_conversion;
 use std::collections::HashMap;
 use tokio::runtime::Handle;
 
@@ -52,7 +52,8 @@ impl Starship {
                     .unwrap()
             });
 
-            let mut daemon_builder = DaemonBuilder::new(chain_data_conversion(chain.clone()));
+            let mut daemon_builder =
+                DaemonBuilder::new(chain_data_conversion(chain.clone(), None)?);
             let mut daemon_builder = daemon_builder
                 .mnemonic(mnemonic)
                 .load_network(false)
@@ -99,20 +100,3 @@ fn try_to_read_starship_config() -> Result<yaml_rust2::Yaml, CwEnvError> {
     }
     Ok(yaml_docs.pop().unwrap())
 }
-
-fn chain_data_conversion(chain: ChainData) -> ChainInfoOwned {
-    ChainInfoOwned {
-        chain_id: chain.chain_id.to_string(),
-        gas_denom: chain.fees.fee_tokens[0].denom.clone(),
-        gas_price: chain.fees.fee_tokens[0].average_gas_price,
-        grpc_urls: chain.apis.grpc.into_iter().map(|g| g.address).collect(),
-        lcd_url: Some(chain.apis.rest.into_iter().map(|l| l.address).collect()),
-        fcd_url: None,
-        network_info: NetworkInfoOwned {
-            chain_name: chain.chain_name,
-            pub_address_prefix: chain.bech32_prefix,
-            coin_type: chain.slip44,
-        },
-        kind: chain.network_type.into(),
-    }
-}
@@ -1,7 +1,7 @@
 #![recursion_limit = "128"]
 
-use syn::{Expr, Token};
 use syn::{__private::TokenStream2, parse_macro_input, Fields, GenericArgument, Item, Path};
+use syn::{Expr, Token};
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
@@ -42,13 +42,13 @@ impl Parse for InterfaceInput {
         let kw_id: Option<kw::id> = input.parse().map_err(|_| {
             syn::Error::new(
                 input.span(),
-                "The 5th argument of the macro should be of the format `id=my_contract_id`",
+                "The last argument of the macro should be of the format `id=my_contract_id`",
             )
         })?;
         let eq_token: Option<Token![=]> = input.parse().map_err(|_| {
             syn::Error::new(
                 input.span(),
-                "The 5th argument of the macro should be of the format `id=my_contract_id`",
+                "The last argument of the macro should be of the format `id=my_contract_id`",
             )
         })?;
         let default_id: Option<Expr> = input.parse().ok();
@@ -79,6 +79,8 @@ fn get_generics_from_path(p: &Path) -> Punctuated<GenericArgument, Comma> {
 /**
 Procedural macro to generate a cw-orchestrator interface
 
+An optional fifth type sets the `SudoMsg` of the contract, used by `CwOrchSudo` and `#[derive(SudoFns)]`.
+
 ## Example
 
 ```ignore
@@ -150,8 +152,8 @@ pub fn interface(attrs: TokenStream, input: TokenStream) -> TokenStream {
     let types_in_order = attributes.expressions;
     let default_id = attributes.default_id;
 
-    if types_in_order.len() != 4 {
-        panic!("Expected four endpoint types (InstantiateMsg, ExecuteMsg, QueryMsg, MigrateMsg). Use cosmwasm_std::Empty if not implemented.")
+    if types_in_order.len() != 4 && types_in_order.len() != 5 {
+        panic!("Expected four endpoint types (InstantiateMsg, ExecuteMsg, QueryMsg, MigrateMsg) and an optional SudoMsg. Use cosmwasm_std::Empty if not implemented.")
     }
 
     let Item::Struct(cw_orch_struct) = &mut item else {
@@ -165,6 +167,7 @@ pub fn interface(attrs: TokenStream, input: TokenStream) -> TokenStream {
     let exec = types_in_order[1].clone();
     let query = types_in_order[2].clone();
     let migrate = types_in_order[3].clone();
+    let sudo = types_in_order.get(4).cloned();
 
     // We create all generics for all types
     let all_generics: Punctuated<GenericArgument, Comma> = types_in_order
@@ -202,6 +205,14 @@ pub fn interface(attrs: TokenStream, input: TokenStream) -> TokenStream {
     };
 
     let name = cw_orch_struct.ident.clone();
+    let sudo_impl = sudo.map(|sudo| {
+        quote!(
+            #[cfg(not(target_arch = "wasm32"))]
+            impl<Chain, #all_generics> ::cw_orch::core::contract::interface_traits::SudoableContract for #name<Chain, #all_generics> #all_debug_serialize {
+                type SudoMsg = #sudo;
+            }
+        )
+    });
     let default_num = if let Some(id_expr) = default_id {
         quote!(
             impl <Chain, #all_generics> #name<Chain, #all_generics> {
@@ -268,6 +279,8 @@ pub fn interface(attrs: TokenStream, input: TokenStream) -> TokenStream {
         impl<Chain, #all_generics> ::cw_orch::core::contract::interface_traits::MigratableContract for #name<Chain, #all_generics> #all_debug_serialize {
             type MigrateMsg = #migrate;
         }
+
+        #sudo_impl
     );
     struct_def.into()
 }
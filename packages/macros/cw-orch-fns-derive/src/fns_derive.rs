@@ -31,6 +31,14 @@ pub fn fns_derive(msg_type: MsgType, sync_type: SyncType, mut input: ItemEnum) -
             None,
             quote!(::cw_orch::core::environment::TxHandler),
         ),
+        MsgType::Sudo => (
+            quote!(CwOrchSudo),
+            quote!(sudo),
+            quote!(SudoMsg),
+            quote!(CwOrchSudoMsgType),
+            None,
+            quote!(::cw_orch::core::environment::SudoHandler),
+        ),
         MsgType::Query => (
             match sync_type {
                 SyncType::Sync => quote!(CwOrchQuery),
@@ -94,14 +102,14 @@ pub fn fns_derive(msg_type: MsgType, sync_type: SyncType, mut input: ItemEnum) -
                     (quote!(),quote!(&[]))
                 }
             }
-            MsgType::Query => {
+            MsgType::Query | MsgType::Sudo => {
                 (quote!(), quote!())
             }
         };
 
 
         let response = match msg_type{
-            MsgType::Execute => quote!(::cw_orch::core::environment::TxResponse<Chain>),
+            MsgType::Execute | MsgType::Sudo => quote!(::cw_orch::core::environment::TxResponse<Chain>),
             MsgType::Query => parse_query_type(&variant)
         };
 
@@ -7,6 +7,7 @@ use syn::{
 pub enum MsgType {
     Execute,
     Query,
+    Sudo,
 }
 
 pub enum SyncType {
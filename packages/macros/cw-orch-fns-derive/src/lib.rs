@@ -40,3 +40,16 @@ pub fn cw_orch_query(input: TokenStream) -> TokenStream {
     };
     tokens.into()
 }
+
+/// Available attributes are :
+///
+/// fn_name - Modify the generated function name
+/// disable_fields_sorting - By default the fields are sorted on named variants. Disabled this behavior
+/// into - The field can be indicated in the generated function with a type that implements `Into` the field type
+///
+/// The generated functions are only available on environments that implement `SudoHandler`.
+#[proc_macro_derive(SudoFns, attributes(cw_orch))]
+pub fn cw_orch_sudo(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as ItemEnum);
+    fns_derive::fns_derive(MsgType::Sudo, SyncType::Sync, ast).into()
+}